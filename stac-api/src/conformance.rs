@@ -1,11 +1,86 @@
+//! Conformance classes, which declare the capabilities of a STAC API.
+
 use serde::{Deserialize, Serialize};
 
+/// The STAC API - Core conformance class.
+pub const CORE_URI: &str = "https://api.stacspec.org/v1.0.0/core";
+
+/// The STAC API - Features conformance class.
+pub const FEATURES_URI: &str = "https://api.stacspec.org/v1.0.0/ogcapi-features";
+
+/// The STAC API - Collections conformance class.
+pub const COLLECTIONS_URI: &str = "https://api.stacspec.org/v1.0.0/collections";
+
+/// The STAC API - Item Search conformance class.
+pub const ITEM_SEARCH_URI: &str = "https://api.stacspec.org/v1.0.0/item-search";
+
+/// The OGC API - Features - Part 1 core conformance class.
+pub const OGC_FEATURES_CORE_URI: &str =
+    "http://www.opengis.net/spec/ogcapi-features-1/1.0/conf/core";
+
+/// The OGC API - Features - Part 1 GeoJSON conformance class.
+pub const OGC_FEATURES_GEOJSON_URI: &str =
+    "http://www.opengis.net/spec/ogcapi-features-1/1.0/conf/geojson";
+
 /// To support "generic" clients that want to access multiple OGC API Features
 /// implementations - and not "just" a specific API / server, the server has to
 /// declare the conformance classes it implements and conforms to.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Conformance {
     /// The conformance classes it implements and conforms to.
-    #[serde(rename = "conformsTo")]
+    #[serde(rename = "conformsTo", default)]
     pub conforms_to: Vec<String>,
 }
+
+impl Conformance {
+    /// Returns true if this declares a conformance class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac_api::{conformance, Conformance};
+    /// let conformance = Conformance {
+    ///     conforms_to: vec![conformance::CORE_URI.to_string()],
+    /// };
+    /// assert!(conformance.conforms_to(conformance::CORE_URI));
+    /// assert!(!conformance.conforms_to(conformance::ITEM_SEARCH_URI));
+    /// ```
+    pub fn conforms_to(&self, class: &str) -> bool {
+        self.conforms_to.iter().any(|c| c == class)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Conformance;
+    use crate::Root;
+    use stac::Catalog;
+
+    #[test]
+    fn missing_conforms_to() {
+        let conformance: Conformance = serde_json::from_str("{}").unwrap();
+        assert!(conformance.conforms_to.is_empty());
+    }
+
+    #[test]
+    fn root_from_catalog() {
+        let mut catalog = Catalog::new("an-id", "a description");
+        let _ = catalog.additional_fields.insert(
+            "conformsTo".to_string(),
+            vec![super::CORE_URI, super::ITEM_SEARCH_URI].into(),
+        );
+        let root = Root::try_from(catalog).unwrap();
+        assert!(root.conforms_to(super::ITEM_SEARCH_URI));
+        assert!(!root.catalog.additional_fields.contains_key("conformsTo"));
+        let catalog = Catalog::from(root);
+        assert_eq!(catalog.additional_fields["conformsTo"][0], super::CORE_URI);
+    }
+
+    #[test]
+    fn root_from_catalog_without_conforms_to() {
+        let root = Root::try_from(Catalog::new("an-id", "a description")).unwrap();
+        assert!(root.conformance.conforms_to.is_empty());
+        let catalog = Catalog::from(root);
+        assert!(!catalog.additional_fields.contains_key("conformsTo"));
+    }
+}
@@ -14,6 +14,10 @@ pub enum Error {
     #[error("cannot convert cql2-json to strings")]
     CannotConvertCql2JsonToString(Map<String, Value>),
 
+    /// Returned when a filter references properties that aren't queryable.
+    #[error("properties are not queryable: {}", .0.join(", "))]
+    NotQueryable(Vec<String>),
+
     /// [serde_json::Error]
     #[error(transparent)]
     SerdeJson(#[from] serde_json::Error),
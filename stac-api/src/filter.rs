@@ -14,6 +14,121 @@ pub enum Filter {
     Cql2Json(Map<String, Value>),
 }
 
+/// Words in `cql2-text` that look like property names but aren't.
+const CQL2_TEXT_KEYWORDS: [&str; 10] = [
+    "AND", "OR", "NOT", "LIKE", "BETWEEN", "IN", "IS", "NULL", "TRUE", "FALSE",
+];
+
+impl Filter {
+    /// Returns the names of the properties referenced by this filter.
+    ///
+    /// Names are returned once each, in the order they first appear. For
+    /// `cql2-json` these are the `{"property": ...}` objects. `cql2-text` isn't
+    /// fully parsed: any identifier that isn't a keyword, a function name, or
+    /// inside a string literal is taken to be a property.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac_api::Filter;
+    /// let filter = Filter::Cql2Text("collection = 'landsat' AND eo:cloud_cover < 10".to_string());
+    /// assert_eq!(filter.property_names(), vec!["collection", "eo:cloud_cover"]);
+    /// ```
+    pub fn property_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        match self {
+            Filter::Cql2Text(text) => cql2_text_property_names(text, &mut names),
+            Filter::Cql2Json(map) => {
+                for value in map.values() {
+                    cql2_json_property_names(value, &mut names);
+                }
+            }
+        }
+        names
+    }
+}
+
+fn push_unique(names: &mut Vec<String>, name: String) {
+    if !names.contains(&name) {
+        names.push(name);
+    }
+}
+
+fn cql2_json_property_names(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(name)) = object.get("property") {
+                push_unique(names, name.clone());
+            } else {
+                for value in object.values() {
+                    cql2_json_property_names(value, names);
+                }
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                cql2_json_property_names(value, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn cql2_text_property_names(text: &str, names: &mut Vec<String>) {
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' || c == '"' {
+            // Quotes are escaped by doubling them.
+            let mut literal = String::new();
+            loop {
+                match chars.next() {
+                    Some(next) if next == c => {
+                        if chars.peek() == Some(&c) {
+                            literal.push(c);
+                            let _ = chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    Some(next) => literal.push(next),
+                    None => break,
+                }
+            }
+            if c == '"' {
+                push_unique(names, literal);
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let mut identifier = c.to_string();
+            while let Some(&next) = chars.peek() {
+                if next.is_alphanumeric() || matches!(next, '_' | ':' | '.') {
+                    identifier.push(next);
+                    let _ = chars.next();
+                } else {
+                    break;
+                }
+            }
+            while chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+                let _ = chars.next();
+            }
+            let is_function = chars.peek() == Some(&'(');
+            let is_keyword = CQL2_TEXT_KEYWORDS
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(&identifier));
+            if !is_function && !is_keyword {
+                push_unique(names, identifier);
+            }
+        } else if c.is_ascii_digit() {
+            while chars
+                .peek()
+                .map(|c| c.is_alphanumeric() || *c == '.')
+                .unwrap_or(false)
+            {
+                let _ = chars.next();
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Filter;
@@ -53,4 +168,42 @@ mod tests {
         assert_eq!(value["filter-lang"], "cql2-text");
         assert!(value.get("filter").is_some());
     }
+
+    #[test]
+    fn property_names_json() {
+        let filter = Filter::Cql2Json(
+            json!({
+                "op": "and",
+                "args": [
+                    {"op": "=", "args": [{"property": "id"}, "an-id"]},
+                    {"op": "s_intersects", "args": [{"property": "geometry"}, {"type": "Point", "coordinates": [0, 0]}]},
+                    {"op": "=", "args": [{"property": "id"}, "another-id"]}
+                ]
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        );
+        assert_eq!(filter.property_names(), vec!["id", "geometry"]);
+    }
+
+    #[test]
+    fn property_names_text() {
+        let filter = Filter::Cql2Text(
+            "S_INTERSECTS(geometry, POINT(1.5 2)) and \"eo:cloud_cover\" BETWEEN 0 AND 1e2 \
+             AND title LIKE 'It''s AND x' AND datetime > TIMESTAMP('2020-01-01T00:00:00Z') \
+             AND platform IS NOT NULL"
+                .to_string(),
+        );
+        assert_eq!(
+            filter.property_names(),
+            vec![
+                "geometry",
+                "eo:cloud_cover",
+                "title",
+                "datetime",
+                "platform"
+            ]
+        );
+    }
 }
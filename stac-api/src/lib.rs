@@ -84,12 +84,13 @@
 
 mod builder;
 mod collections;
-mod conformance;
+pub mod conformance;
 mod error;
 mod fields;
 mod filter;
 mod item_collection;
 mod items;
+mod queryables;
 mod root;
 mod search;
 mod sort;
@@ -103,6 +104,7 @@ pub use {
     filter::Filter,
     item_collection::{Context, ItemCollection},
     items::{GetItems, Items},
+    queryables::Queryables,
     root::Root,
     search::Search,
     sort::Sortby,
@@ -0,0 +1,215 @@
+use crate::{Error, Filter, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The queryable properties of a STAC API, as returned by `/queryables`.
+///
+/// Queryables are a JSON Schema describing which properties can be used in a
+/// [Filter]. Each entry in `properties` is itself a schema for that property.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Queryables {
+    /// The JSON Schema dialect, e.g. `https://json-schema.org/draft/2019-09/schema`.
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// The url of this queryables document.
+    #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The JSON Schema type, which should be `"object"`.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// A human-readable title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// A human-readable description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The queryable properties, keyed by name, with a schema for each.
+    #[serde(default)]
+    pub properties: Map<String, Value>,
+
+    /// Whether properties that aren't listed in `properties` can be queried.
+    #[serde(
+        rename = "additionalProperties",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub additional_properties: Option<bool>,
+
+    /// Additional fields.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl Queryables {
+    /// Returns true if a property can be used in a filter.
+    ///
+    /// A property is queryable if it is listed in `properties`, or if
+    /// `additionalProperties` isn't `false`. Per JSON Schema, a missing
+    /// `additionalProperties` allows any property.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac_api::Queryables;
+    /// let queryables: Queryables = serde_json::from_value(serde_json::json!({
+    ///     "type": "object",
+    ///     "properties": {"eo:cloud_cover": {"type": "number"}},
+    ///     "additionalProperties": false
+    /// }))
+    /// .unwrap();
+    /// assert!(queryables.is_queryable("eo:cloud_cover"));
+    /// assert!(!queryables.is_queryable("platform"));
+    /// ```
+    pub fn is_queryable(&self, property: &str) -> bool {
+        self.additional_properties.unwrap_or(true) || self.properties.contains_key(property)
+    }
+
+    /// Checks that every property referenced by a filter is queryable.
+    ///
+    /// Returns [Error::NotQueryable] with the offending property names if any
+    /// aren't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac_api::{Filter, Queryables};
+    /// let queryables: Queryables = serde_json::from_value(serde_json::json!({
+    ///     "type": "object",
+    ///     "properties": {"eo:cloud_cover": {"type": "number"}},
+    ///     "additionalProperties": false
+    /// }))
+    /// .unwrap();
+    /// let filter = Filter::Cql2Text("eo:cloud_cover < 10".to_string());
+    /// queryables.validate_filter(&filter).unwrap();
+    /// let filter = Filter::Cql2Text("platform = 'landsat-8'".to_string());
+    /// assert!(queryables.validate_filter(&filter).is_err());
+    /// ```
+    pub fn validate_filter(&self, filter: &Filter) -> Result<()> {
+        let properties: Vec<_> = filter
+            .property_names()
+            .into_iter()
+            .filter(|property| !self.is_queryable(property))
+            .collect();
+        if properties.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::NotQueryable(properties))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Queryables;
+    use crate::{Error, Filter};
+    use serde_json::json;
+
+    fn queryables() -> Queryables {
+        serde_json::from_value(json!({
+            "$schema" : "https://json-schema.org/draft/2019-09/schema",
+            "$id" : "https://stac-api.example.com/queryables",
+            "type" : "object",
+            "title" : "Queryables for Example STAC API",
+            "description" : "Queryable names for the example STAC API Item Search filter.",
+            "properties" : {
+                "id" : {
+                    "description" : "ID",
+                    "$ref": "https://schemas.stacspec.org/v1.0.0/item-spec/json-schema/item.json#/id"
+                },
+                "collection" : {
+                    "description" : "Collection",
+                    "$ref": "https://schemas.stacspec.org/v1.0.0/item-spec/json-schema/item.json#/collection"
+                },
+                "eo:cloud_cover" : {
+                    "description" : "Cloud Cover",
+                    "$ref": "https://stac-extensions.github.io/eo/v1.0.0/schema.json#/definitions/fields/properties/eo:cloud_cover"
+                }
+            },
+            "additionalProperties": false
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn deserialize() {
+        let queryables = queryables();
+        assert_eq!(queryables.r#type.as_deref(), Some("object"));
+        assert_eq!(queryables.properties.len(), 3);
+        assert_eq!(queryables.additional_properties, Some(false));
+        let value = serde_json::to_value(&queryables).unwrap();
+        assert_eq!(value["$id"], "https://stac-api.example.com/queryables");
+        assert_eq!(value["additionalProperties"], false);
+    }
+
+    #[test]
+    fn is_queryable() {
+        let mut queryables = queryables();
+        assert!(queryables.is_queryable("id"));
+        assert!(!queryables.is_queryable("platform"));
+        queryables.additional_properties = Some(true);
+        assert!(queryables.is_queryable("platform"));
+    }
+
+    #[test]
+    fn is_queryable_without_additional_properties() {
+        let queryables: Queryables = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {"id": {"type": "string"}}
+        }))
+        .unwrap();
+        assert!(queryables.additional_properties.is_none());
+        assert!(queryables.is_queryable("id"));
+        assert!(queryables.is_queryable("platform"));
+        queryables
+            .validate_filter(&Filter::Cql2Text("platform = 'landsat-8'".to_string()))
+            .unwrap();
+    }
+
+    #[test]
+    fn validate_cql2_json() {
+        let queryables = queryables();
+        let filter = Filter::Cql2Json(
+            json!({
+                "op": "and",
+                "args": [
+                    {"op": "=", "args": [{"property": "collection"}, "landsat8_l1tp"]},
+                    {"op": "<", "args": [{"property": "eo:cloud_cover"}, 10]}
+                ]
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        );
+        queryables.validate_filter(&filter).unwrap();
+        let filter = Filter::Cql2Json(
+            json!({
+                "op": "=",
+                "args": [{"property": "platform"}, "landsat-8"]
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        );
+        match queryables.validate_filter(&filter).unwrap_err() {
+            Error::NotQueryable(properties) => assert_eq!(properties, vec!["platform"]),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn validate_cql2_text() {
+        let queryables = queryables();
+        let filter = Filter::Cql2Text(
+            "collection = 'landsat8_l1tp' AND \"eo:cloud_cover\" < 10 AND platform IN ('a', 'b')"
+                .to_string(),
+        );
+        match queryables.validate_filter(&filter).unwrap_err() {
+            Error::NotQueryable(properties) => assert_eq!(properties, vec!["platform"]),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Conformance;
+use crate::{Conformance, Error, Result};
 use serde::{Deserialize, Serialize};
 use stac::Catalog;
 
@@ -60,3 +60,58 @@ pub struct Root {
     #[serde(flatten)]
     pub conformance: Conformance,
 }
+
+impl Root {
+    /// Returns true if this API declares a conformance class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Catalog;
+    /// use stac_api::{conformance, Conformance, Root};
+    /// let root = Root {
+    ///     catalog: Catalog::new("an-id", "a description"),
+    ///     conformance: Conformance {
+    ///         conforms_to: vec![conformance::CORE_URI.to_string()],
+    ///     },
+    /// };
+    /// assert!(root.conforms_to(conformance::CORE_URI));
+    /// ```
+    pub fn conforms_to(&self, class: &str) -> bool {
+        self.conformance.conforms_to(class)
+    }
+}
+
+impl TryFrom<Catalog> for Root {
+    type Error = Error;
+
+    /// Creates a landing page from a catalog, moving its `conformsTo` field out of its additional fields.
+    fn try_from(mut catalog: Catalog) -> Result<Root> {
+        let conformance = if let Some(conforms_to) = catalog.additional_fields.remove("conformsTo")
+        {
+            Conformance {
+                conforms_to: serde_json::from_value(conforms_to)?,
+            }
+        } else {
+            Conformance::default()
+        };
+        Ok(Root {
+            catalog,
+            conformance,
+        })
+    }
+}
+
+impl From<Root> for Catalog {
+    /// Converts a landing page to a catalog, keeping `conformsTo` in its additional fields.
+    fn from(root: Root) -> Catalog {
+        let mut catalog = root.catalog;
+        if !root.conformance.conforms_to.is_empty() {
+            let _ = catalog.additional_fields.insert(
+                "conformsTo".to_string(),
+                root.conformance.conforms_to.into(),
+            );
+        }
+        catalog
+    }
+}
@@ -59,7 +59,7 @@ impl Client {
             .request::<(), V>(Method::GET, url.clone(), None, None)
             .await?
         {
-            value.set_href(url);
+            value.set_href(url.as_str());
             Ok(Some(value))
         } else {
             Ok(None)
@@ -4,14 +4,26 @@ use crate::{Error, Result};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Serialize;
+use sha2::{Digest, Sha256, Sha512};
 use stac::{Asset, Assets, Collection, Href, Item, Link, Links, Value};
-use std::path::{Path, PathBuf};
-use tokio::{fs::File, io::AsyncWriteExt, sync::mpsc::Sender, task::JoinSet};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::mpsc::Sender,
+    task::JoinSet,
+};
 use url::Url;
 
 const DEFAULT_FILE_NAME: &str = "download.json";
 const DEFAULT_WRITE_STAC: bool = true;
 const DEFAULT_CREATE_DIRECTORY: bool = true;
+const DEFAULT_ASSET_FILE_NAME: &str = "asset";
+const SHA2_256: u8 = 0x12;
+const SHA2_512: u8 = 0x13;
 
 /// Downloads all assets from a [Item](stac::Item) or [Collection](stac::Collection).
 ///
@@ -62,6 +74,144 @@ pub trait Download: Assets + Links + Href + Serialize + Clone {
     {
         Downloader::new(self)?.download(directory).await
     }
+
+    /// Downloads assets, concurrently, to a directory on the local filesystem.
+    ///
+    /// Unlike [Download::download], this doesn't modify or write the object
+    /// itself. If `roles` is provided, only assets with at least one of those
+    /// roles are downloaded. Each asset is downloaded as in
+    /// [DownloadAsset::download], with this object's href as the base href,
+    /// except that each file is named after its asset key (with the extension
+    /// of the href) so that assets with the same file name, e.g.
+    /// `B01/data.tif` and `B02/data.tif`, don't overwrite each other. Returns
+    /// the paths of the downloaded files, by asset key.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use stac::Item;
+    /// use stac_async::Download;
+    ///
+    /// let item: Item = stac::read("data/simple-item.json").unwrap();
+    /// # tokio_test::block_on(async {
+    /// let paths = item.download_assets(Some(&["data"]), "outdir").await.unwrap();
+    /// # })
+    /// ```
+    async fn download_assets(
+        &self,
+        roles: Option<&[&str]>,
+        directory: impl AsRef<Path>,
+    ) -> Result<HashMap<String, PathBuf>> {
+        let directory = directory.as_ref();
+        tokio::fs::create_dir_all(directory).await?;
+        let client = Client::new();
+        let client = &client;
+        let base_href = self.href();
+        let downloads = self
+            .assets()
+            .iter()
+            .filter(|(_, asset)| {
+                roles
+                    .map(|roles| roles.iter().any(|role| asset.has_role(role)))
+                    .unwrap_or(true)
+            })
+            .map(|(key, asset)| async move {
+                download_asset(client, Some(key), asset, base_href, directory)
+                    .await
+                    .map(|path| (key.clone(), path))
+            });
+        futures_util::future::try_join_all(downloads)
+            .await
+            .map(|paths| paths.into_iter().collect())
+    }
+
+    /// Fetches the bytes of this object's thumbnail, e.g. for a preview image.
+    ///
+    /// The thumbnail is the asset with the `thumbnail` role. If more than one
+    /// asset has that role, the one with the first key (in sorted order) is
+    /// used. Its href is resolved against this object's href, and urls are
+    /// fetched with the client while local files are read. Returns
+    /// [Error::NoThumbnail] if no asset has the `thumbnail` role.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use stac::Item;
+    /// use stac_async::{Client, Download};
+    ///
+    /// let item: Item = stac::read("data/simple-item.json").unwrap();
+    /// # tokio_test::block_on(async {
+    /// let bytes = item.thumbnail_bytes(&Client::new()).await.unwrap();
+    /// # })
+    /// ```
+    async fn thumbnail_bytes(&self, client: &crate::Client) -> Result<Vec<u8>> {
+        let asset = self
+            .assets()
+            .iter()
+            .filter(|(_, asset)| asset.has_role(stac::roles::THUMBNAIL))
+            .min_by_key(|(key, _)| *key)
+            .map(|(_, asset)| asset)
+            .ok_or(Error::NoThumbnail)?;
+        let href = absolute_href(asset, self.href())?;
+        if let Some(url) = stac::href_to_url(&href) {
+            let response = client
+                .0
+                .get(url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())?;
+            Ok(response.bytes().await?.to_vec())
+        } else {
+            tokio::fs::read(href).await.map_err(Error::from)
+        }
+    }
+}
+
+/// Downloads a single [Asset].
+#[async_trait(?Send)]
+pub trait DownloadAsset {
+    /// Downloads this asset into a directory, and returns the path of the downloaded file.
+    ///
+    /// The asset's href is resolved against `base_href`, which is usually the
+    /// href of the item or collection that owns the asset. Urls are streamed
+    /// to disk and local files are copied. The file is named after the last
+    /// segment of the href, and is written to a `.part` file that's renamed
+    /// into place once the download is complete, so a failed download doesn't
+    /// leave a file behind. If the asset has a `file:checksum` from the [file
+    /// extension](https://github.com/stac-extensions/file) that's a SHA2-256
+    /// or SHA2-512 multihash, the downloaded file is verified against it and
+    /// removed if it doesn't match. Other hash functions aren't verified.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use stac::Item;
+    /// use stac_async::DownloadAsset;
+    ///
+    /// let item: Item = stac::read("data/simple-item.json").unwrap();
+    /// # tokio_test::block_on(async {
+    /// let path = item.assets["thumbnail"]
+    ///     .download(Some("data/simple-item.json"), "outdir")
+    ///     .await
+    ///     .unwrap();
+    /// # })
+    /// ```
+    async fn download(
+        &self,
+        base_href: Option<&str>,
+        directory: impl AsRef<Path>,
+    ) -> Result<PathBuf>;
+}
+
+#[async_trait(?Send)]
+impl DownloadAsset for Asset {
+    async fn download(
+        &self,
+        base_href: Option<&str>,
+        directory: impl AsRef<Path>,
+    ) -> Result<PathBuf> {
+        download_asset(&Client::new(), None, self, base_href, directory.as_ref()).await
+    }
 }
 
 /// A customizable download structure.
@@ -320,16 +470,381 @@ impl AssetDownloader {
     }
 }
 
+/// Resolves an asset's href against the href of the object that holds it, as in [Link::absolute_href].
+fn absolute_href(asset: &Asset, base_href: Option<&str>) -> Result<String> {
+    if let Some(base_href) = base_href {
+        Link::new(&asset.href, "")
+            .absolute_href(base_href)
+            .map_err(Error::from)
+    } else {
+        Ok(asset.href.clone())
+    }
+}
+
+async fn download_asset(
+    client: &Client,
+    key: Option<&str>,
+    asset: &Asset,
+    base_href: Option<&str>,
+    directory: &Path,
+) -> Result<PathBuf> {
+    let href = absolute_href(asset, base_href)?;
+    let url = stac::href_to_url(&href);
+    let file_name = if let Some(url) = url.as_ref() {
+        url.path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .map(|segment| segment.to_string())
+    } else {
+        Path::new(&href)
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().into_owned())
+    }
+    .filter(|file_name| !file_name.is_empty())
+    .unwrap_or_else(|| DEFAULT_ASSET_FILE_NAME.to_string());
+    let file_name = if let Some(key) = key {
+        key_file_name(key, &file_name)
+    } else {
+        file_name
+    };
+    let mut checksum = Checksum::new(asset)?;
+    let source = if let Some(url) = url {
+        Source::Response(
+            client
+                .get(url)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())?,
+        )
+    } else {
+        Source::File(File::open(&href).await?)
+    };
+    let path = directory.join(&file_name);
+    let part = directory.join(format!("{}.part", file_name));
+    let result = match source.write(&part, &mut checksum).await {
+        Ok(()) => checksum.verify(&href),
+        Err(err) => Err(err),
+    };
+    if let Err(err) = result {
+        let _ = tokio::fs::remove_file(&part).await;
+        Err(err)
+    } else {
+        tokio::fs::rename(&part, &path).await?;
+        Ok(path)
+    }
+}
+
+/// Names a downloaded file after its asset key, keeping the extension of the href's file name.
+fn key_file_name(key: &str, file_name: &str) -> String {
+    let key: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match Path::new(file_name).extension() {
+        Some(extension) => format!("{}.{}", key, extension.to_string_lossy()),
+        None => key,
+    }
+}
+
+/// Where an asset is downloaded from.
+enum Source {
+    Response(reqwest::Response),
+    File(File),
+}
+
+impl Source {
+    async fn write(self, path: &Path, checksum: &mut Checksum) -> Result<()> {
+        let mut file = File::create(path).await?;
+        match self {
+            Source::Response(mut response) => {
+                while let Some(chunk) = response.chunk().await? {
+                    checksum.update(&chunk);
+                    file.write_all(&chunk).await?;
+                }
+            }
+            Source::File(mut source) => {
+                let mut buffer = vec![0; 8192];
+                loop {
+                    let n = source.read(&mut buffer).await?;
+                    if n == 0 {
+                        break;
+                    }
+                    checksum.update(&buffer[..n]);
+                    file.write_all(&buffer[..n]).await?;
+                }
+            }
+        }
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Verifies a download against an asset's `file:checksum`.
+enum Checksum {
+    None,
+    Sha256(Sha256, String),
+    Sha512(Sha512, String),
+}
+
+impl Checksum {
+    fn new(asset: &Asset) -> Result<Checksum> {
+        let expected = if let Some(expected) = asset
+            .additional_fields
+            .get("file:checksum")
+            .and_then(|value| value.as_str())
+        {
+            expected.to_lowercase()
+        } else {
+            return Ok(Checksum::None);
+        };
+        let invalid = || Error::InvalidChecksum(expected.clone());
+        if expected.len() < 4 || !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let code = u8::from_str_radix(&expected[0..2], 16).map_err(|_| invalid())?;
+        match code {
+            SHA2_256 => Ok(Checksum::Sha256(Sha256::new(), expected)),
+            SHA2_512 => Ok(Checksum::Sha512(Sha512::new(), expected)),
+            _ => Ok(Checksum::None),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Checksum::None => {}
+            Checksum::Sha256(hasher, _) => hasher.update(bytes),
+            Checksum::Sha512(hasher, _) => hasher.update(bytes),
+        }
+    }
+
+    fn verify(self, href: &str) -> Result<()> {
+        let (code, digest, expected) = match self {
+            Checksum::None => return Ok(()),
+            Checksum::Sha256(hasher, expected) => (SHA2_256, hasher.finalize().to_vec(), expected),
+            Checksum::Sha512(hasher, expected) => (SHA2_512, hasher.finalize().to_vec(), expected),
+        };
+        let actual = multihash_hex(code, &digest);
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(Error::ChecksumMismatch {
+                href: href.to_string(),
+                expected,
+                actual,
+            })
+        }
+    }
+}
+
+fn multihash_hex(code: u8, digest: &[u8]) -> String {
+    [code, digest.len() as u8]
+        .iter()
+        .chain(digest)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
 impl Download for Item {}
 impl Download for Collection {}
 
 #[cfg(test)]
 mod tests {
-    use super::Download;
-    use mockito::Server;
+    use super::{Download, DownloadAsset};
+    use mockito::{Matcher, Server};
+    use sha2::{Digest, Sha256};
     use stac::{Asset, Href, Item, Link, Links};
     use tempdir::TempDir;
 
+    fn sha256_multihash(bytes: &[u8]) -> String {
+        super::multihash_hex(super::SHA2_256, &Sha256::digest(bytes))
+    }
+
+    #[tokio::test]
+    async fn download_asset() {
+        let mut server = Server::new_async().await;
+        let body = "fake geotiff, sorry!";
+        let mock = server
+            .mock("GET", "/assets/asset.tif")
+            .with_body(body)
+            .create_async()
+            .await;
+        let mut asset = Asset::new("./assets/asset.tif");
+        let _ = asset.additional_fields.insert(
+            "file:checksum".to_string(),
+            sha256_multihash(body.as_bytes()).into(),
+        );
+        let temp_dir = TempDir::new("download_asset").unwrap();
+        let path = asset
+            .download(
+                Some(&format!("{}/item.json", server.url())),
+                temp_dir.path(),
+            )
+            .await
+            .unwrap();
+        mock.assert_async().await;
+        assert_eq!(path, temp_dir.path().join("asset.tif"));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn download_asset_checksum_mismatch() {
+        let mut asset = Asset::new("./simple-item.json");
+        let _ = asset.additional_fields.insert(
+            "file:checksum".to_string(),
+            sha256_multihash(b"not it").into(),
+        );
+        let temp_dir = TempDir::new("download_asset_checksum_mismatch").unwrap();
+        let err = asset
+            .download(Some("data/catalog.json"), temp_dir.path())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::ChecksumMismatch { .. }));
+        assert!(!temp_dir.path().join("simple-item.json").exists());
+    }
+
+    #[tokio::test]
+    async fn download_asset_not_found() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/asset.tif")
+            .with_status(404)
+            .create_async()
+            .await;
+        let temp_dir = TempDir::new("download_asset_not_found").unwrap();
+        let _ = Asset::new(format!("{}/asset.tif", server.url()))
+            .download(None, temp_dir.path())
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+
+        let _ = Asset::new("./not-a-file.tif")
+            .download(Some("data/catalog.json"), temp_dir.path())
+            .await
+            .unwrap_err();
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn download_asset_unsupported_checksum() {
+        let mut asset = Asset::new("./simple-item.json");
+        let _ = asset
+            .additional_fields
+            .insert("file:checksum".to_string(), "d50120aa".into());
+        let temp_dir = TempDir::new("download_asset_unsupported_checksum").unwrap();
+        let path = asset
+            .download(Some("data/catalog.json"), temp_dir.path())
+            .await
+            .unwrap();
+        assert!(path.exists());
+    }
+
+    #[tokio::test]
+    async fn thumbnail_bytes() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/thumbnails/a.jpg")
+            .with_body("fake jpeg")
+            .create_async()
+            .await;
+        let client = crate::Client::new();
+        let mut item = Item::new("an-id");
+        item.set_href(&format!("{}/items/an-id.json", server.url()));
+        let mut thumbnail = Asset::new("../thumbnails/a.jpg");
+        thumbnail.add_role("thumbnail");
+        let _ = item.assets.insert("b".to_string(), thumbnail.clone());
+        thumbnail.href = "../thumbnails/b.jpg".to_string();
+        let _ = item.assets.insert("c".to_string(), thumbnail);
+        let _ = item
+            .assets
+            .insert("a".to_string(), Asset::new("../data/a.tif"));
+        assert_eq!(item.thumbnail_bytes(&client).await.unwrap(), b"fake jpeg");
+        mock.assert_async().await;
+
+        let mock = server
+            .mock("GET", "/thumbnails/a.jpg")
+            .match_query(Matcher::UrlEncoded("token".to_string(), "abc".to_string()))
+            .with_body("signed jpeg")
+            .create_async()
+            .await;
+        item.set_href(&format!("{}/items/an-id.json?token=abc", server.url()));
+        assert_eq!(item.thumbnail_bytes(&client).await.unwrap(), b"signed jpeg");
+        mock.assert_async().await;
+
+        let mut thumbnail = Asset::new("./simple-item.json");
+        thumbnail.add_role("thumbnail");
+        let mut item = Item::new("an-id");
+        let _ = item.assets.insert("thumbnail".to_string(), thumbnail);
+        item.set_href("data/simple-item.json");
+        assert_eq!(
+            item.thumbnail_bytes(&client).await.unwrap(),
+            std::fs::read("data/simple-item.json").unwrap()
+        );
+
+        let _ = item.assets.remove("thumbnail");
+        assert!(matches!(
+            item.thumbnail_bytes(&client).await.unwrap_err(),
+            crate::Error::NoThumbnail
+        ));
+    }
+
+    #[tokio::test]
+    async fn download_assets() {
+        let mut item = Item::new("an-id");
+        let mut data = Asset::new("./simple-item.json");
+        data.add_role("data");
+        let _ = item.assets.insert("data".to_string(), data);
+        let mut thumbnail = Asset::new("./collection.json");
+        thumbnail.add_role("thumbnail");
+        let _ = item.assets.insert("thumbnail".to_string(), thumbnail);
+        item.set_href("data/simple-item.json");
+        let temp_dir = TempDir::new("download_assets").unwrap();
+        let paths = item
+            .download_assets(Some(&["data"]), temp_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths["data"], temp_dir.path().join("data.json"));
+        let paths = item.download_assets(None, temp_dir.path()).await.unwrap();
+        assert_eq!(paths.len(), 2);
+        assert!(paths["thumbnail"].exists());
+    }
+
+    #[tokio::test]
+    async fn download_assets_same_file_name() {
+        let mut server = Server::new_async().await;
+        let b01 = server
+            .mock("GET", "/B01/data.tif")
+            .with_body("B01")
+            .create_async()
+            .await;
+        let b02 = server
+            .mock("GET", "/B02/data.tif")
+            .with_body("B02")
+            .create_async()
+            .await;
+        let mut item = Item::new("an-id");
+        let _ = item
+            .assets
+            .insert("B01".to_string(), Asset::new("./B01/data.tif"));
+        let _ = item
+            .assets
+            .insert("B02".to_string(), Asset::new("./B02/data.tif"));
+        item.set_href(&format!("{}/item.json", server.url()));
+        let temp_dir = TempDir::new("download_assets_same_file_name").unwrap();
+        let paths = item.download_assets(None, temp_dir.path()).await.unwrap();
+        b01.assert_async().await;
+        b02.assert_async().await;
+        assert_eq!(paths["B01"], temp_dir.path().join("B01.tif"));
+        assert_eq!(std::fs::read_to_string(&paths["B01"]).unwrap(), "B01");
+        assert_eq!(std::fs::read_to_string(&paths["B02"]).unwrap(), "B02");
+    }
+
     #[tokio::test]
     async fn download() {
         let mut server = Server::new_async().await;
@@ -8,6 +8,23 @@ pub enum Error {
     #[error("cannot download")]
     CannotDownload(Value),
 
+    /// A downloaded asset's checksum doesn't match its `file:checksum`.
+    #[error("checksum mismatch for {href}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// The href of the asset.
+        href: String,
+
+        /// The expected checksum, from `file:checksum`.
+        expected: String,
+
+        /// The checksum of the downloaded file.
+        actual: String,
+    },
+
+    /// An asset's `file:checksum` isn't a hex-encoded multihash.
+    #[error("invalid file:checksum: {0}")]
+    InvalidChecksum(String),
+
     /// [reqwest::header::InvalidHeaderName]
     #[error(transparent)]
     InvalidHeaderName(#[from] reqwest::header::InvalidHeaderName),
@@ -32,6 +49,10 @@ pub enum Error {
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
 
+    /// An item or collection doesn't have an asset with the `thumbnail` role.
+    #[error("no asset with the thumbnail role")]
+    NoThumbnail,
+
     /// The endpoint was not found.
     #[error("not found: {0}")]
     NotFound(Url),
@@ -54,7 +75,71 @@ pub enum Error {
     #[error(transparent)]
     StacApi(#[from] stac_api::Error),
 
+    /// Returned when a STAC object fails validation, with every validation error.
+    #[cfg(feature = "jsonschema")]
+    #[error("validation errors: {}", .0.iter().map(|error| error.to_string()).collect::<Vec<_>>().join("; "))]
+    Validation(Vec<stac::Error>),
+
     /// [url::ParseError]
     #[error(transparent)]
     UrlParse(#[from] url::ParseError),
 }
+
+impl Error {
+    /// Returns true if this error means that a file or url was not found.
+    ///
+    /// This is a missing file, an HTTP 404 or 410, or a wrapped [stac::Error] for
+    /// which [stac::Error::is_not_found] is true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// let error = stac_async::read::<stac::Item>("not-a-file.json").await.unwrap_err();
+    /// assert!(error.is_not_found());
+    /// # })
+    /// ```
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::Io(err) => err.kind() == std::io::ErrorKind::NotFound,
+            Error::NotFound(_) => true,
+            Error::Reqwest(err) => matches!(
+                err.status(),
+                Some(reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE)
+            ),
+            Error::Stac(err) => err.is_not_found(),
+            _ => false,
+        }
+    }
+
+    /// Returns true if this error might go away if the operation is retried.
+    ///
+    /// This is a timeout, an interrupted operation, an HTTP 5xx, or a wrapped
+    /// [stac::Error] for which [stac::Error::is_transient] is true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # tokio_test::block_on(async {
+    /// let error = stac_async::read::<stac::Item>("not-a-file.json").await.unwrap_err();
+    /// assert!(!error.is_transient());
+    /// # })
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Io(err) => matches!(
+                err.kind(),
+                std::io::ErrorKind::TimedOut | std::io::ErrorKind::Interrupted
+            ),
+            Error::Reqwest(err) => {
+                err.is_timeout()
+                    || err
+                        .status()
+                        .map(|status| status.is_server_error())
+                        .unwrap_or(false)
+            }
+            Error::Stac(err) => err.is_transient(),
+            _ => false,
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{Error, Result};
+use reqwest::header::ACCEPT;
 use serde::{de::DeserializeOwned, Serialize};
-use stac::Href;
+use stac::{Href, Links};
 use std::path::Path;
 use url::Url;
 
+const ACCEPT_JSON: &str = "application/json, application/geo+json";
+
 /// Reads a STAC value from an href.
 ///
 /// The href can be a url or a filesystem path.
@@ -21,7 +24,39 @@ where
 {
     let href = href.to_string();
     let mut value: T = read_json(&href).await?;
-    value.set_href(href);
+    value.set_href(&href);
+    Ok(value)
+}
+
+/// Reads a STAC value from an href, using a cache.
+///
+/// This is the asynchronous version of [stac::read_cached]. If the href's JSON
+/// is in the cache, it's used instead of reading the href again. Otherwise the
+/// href is read and its JSON is cached. Cached JSON can be stale, see
+/// [stac::ReadCache].
+///
+/// # Examples
+///
+/// ```
+/// let cache = stac::ReadCache::default();
+/// # tokio_test::block_on(async {
+/// let item: stac::Item = stac_async::read_cached("data/simple-item.json", &cache).await.unwrap();
+/// # })
+/// ```
+pub async fn read_cached<T>(href: impl ToString, cache: &stac::ReadCache) -> Result<T>
+where
+    T: DeserializeOwned + Href,
+{
+    let href = href.to_string();
+    let json = if let Some(json) = cache.get(&href) {
+        json
+    } else {
+        let json: serde_json::Value = read_json(&href).await?;
+        cache.insert(&href, json.clone());
+        json
+    };
+    let mut value: T = serde_json::from_value(json)?;
+    value.set_href(&href);
     Ok(value)
 }
 
@@ -47,6 +82,42 @@ where
     }
 }
 
+/// Removes child and item links whose targets no longer exist.
+///
+/// This is the asynchronous version of [stac::Links::prune_dead_links]. Each
+/// child and item link is resolved against `base_href` and its target is
+/// checked for existence with [stac::exists], without being read. Links to
+/// missing targets are removed and their hrefs are returned.
+///
+/// # Examples
+///
+/// ```
+/// let mut catalog: stac::Catalog = stac::read("data/catalog.json").unwrap();
+/// # tokio_test::block_on(async {
+/// let removed = stac_async::prune_dead_links(&mut catalog, "data/catalog.json").await.unwrap();
+/// assert!(removed.is_empty());
+/// # })
+/// ```
+pub async fn prune_dead_links<L>(links: &mut L, base_href: &str) -> Result<Vec<String>>
+where
+    L: Links,
+{
+    let mut removed = Vec::new();
+    for link in links
+        .links()
+        .iter()
+        .filter(|link| link.is_child() || link.is_item())
+    {
+        if !exists(&link.absolute_href(base_href)?).await? {
+            removed.push(link.href.clone());
+        }
+    }
+    links
+        .links_mut()
+        .retain(|link| !((link.is_child() || link.is_item()) && removed.contains(&link.href)));
+    Ok(removed)
+}
+
 /// Writes any serializable value to a path.
 ///
 /// # Examples
@@ -62,12 +133,49 @@ pub async fn write_json_to_path(path: impl AsRef<Path>, value: impl Serialize) -
     tokio::fs::write(path, string).await.map_err(Error::from)
 }
 
+/// Checks that an href exists with [stac::exists], on a blocking thread.
+async fn exists(href: &str) -> Result<bool> {
+    let href = href.to_string();
+    tokio::task::spawn_blocking(move || stac::exists(&href))
+        .await?
+        .map_err(Error::from)
+}
+
+/// Reads JSON from a url, asking for JSON via the `Accept` header.
+///
+/// If the response doesn't say it's JSON and its body can't be parsed as JSON,
+/// the request is retried with `?f=json`, as in [stac::read_json].
 async fn read_json_from_url<T>(url: Url) -> Result<T>
 where
     T: DeserializeOwned,
 {
-    let response = reqwest::get(url).await?;
-    response.json().await.map_err(Error::from)
+    let client = reqwest::Client::new();
+    let response = client
+        .get(url.clone())
+        .header(ACCEPT, ACCEPT_JSON)
+        .send()
+        .await?;
+    if stac::is_json_response(response.headers()) || url.query_pairs().any(|(key, _)| key == "f") {
+        return response.json().await.map_err(Error::from);
+    }
+    let bytes = response.bytes().await?;
+    match serde_json::from_slice(&bytes) {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            let mut fallback_url = url;
+            let _ = fallback_url.query_pairs_mut().append_pair("f", "json");
+            let fallback = client
+                .get(fallback_url)
+                .header(ACCEPT, ACCEPT_JSON)
+                .send()
+                .await?;
+            if stac::is_json_response(fallback.headers()) {
+                fallback.json().await.map_err(Error::from)
+            } else {
+                Err(Error::from(err))
+            }
+        }
+    }
 }
 
 async fn read_json_from_path<T>(path: impl AsRef<Path>) -> Result<T>
@@ -80,7 +188,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use stac::{Href, Item};
+    use stac::{Catalog, Href, Item, Link};
 
     #[tokio::test]
     async fn read_filesystem() {
@@ -88,6 +196,94 @@ mod tests {
         assert!(item.href().unwrap().ends_with("data/simple-item.json"));
     }
 
+    #[tokio::test]
+    async fn read_cached() {
+        let cache = stac::ReadCache::new(16);
+        let a: Item = super::read_cached("data/simple-item.json", &cache)
+            .await
+            .unwrap();
+        assert_eq!(cache.len(), 1);
+        let b: Item = super::read_cached("./data/simple-item.json", &cache)
+            .await
+            .unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(a.id, b.id);
+    }
+
+    #[tokio::test]
+    async fn prune_dead_links() {
+        let mut catalog: Catalog = super::read("data/catalog.json").await.unwrap();
+        let count = catalog.links.len();
+        catalog
+            .links
+            .push(Link::child("./not-a-child/catalog.json"));
+        catalog.links.push(Link::item("./not-an-item.json"));
+        let removed = super::prune_dead_links(&mut catalog, "data/catalog.json")
+            .await
+            .unwrap();
+        assert_eq!(
+            removed,
+            ["./not-a-child/catalog.json", "./not-an-item.json"]
+        );
+        assert_eq!(catalog.links.len(), count);
+    }
+
+    #[tokio::test]
+    async fn read_format_fallback() {
+        use mockito::{Matcher, Server};
+        use stac::Collection;
+
+        let mut server = Server::new_async().await;
+        let html = server
+            .mock("GET", "/collections/an-id")
+            .match_query(Matcher::Missing)
+            .match_header("accept", super::ACCEPT_JSON)
+            .with_header("content-type", "text/html")
+            .with_body("<html></html>")
+            .create_async()
+            .await;
+        let json = server
+            .mock("GET", "/collections/an-id")
+            .match_query(Matcher::UrlEncoded("f".into(), "json".into()))
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&Collection::new("an-id", "a description")).unwrap())
+            .create_async()
+            .await;
+        let collection: Collection = super::read(format!("{}/collections/an-id", server.url()))
+            .await
+            .unwrap();
+        assert_eq!(collection.id, "an-id");
+        html.assert_async().await;
+        json.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn read_json_body_without_json_content_type() {
+        use mockito::{Matcher, Server};
+        use stac::Collection;
+
+        let mut server = Server::new_async().await;
+        let plain = server
+            .mock("GET", "/collections/an-id")
+            .match_query(Matcher::Missing)
+            .with_header("content-type", "text/plain")
+            .with_body(serde_json::to_string(&Collection::new("an-id", "a description")).unwrap())
+            .create_async()
+            .await;
+        let fallback = server
+            .mock("GET", "/collections/an-id")
+            .match_query(Matcher::UrlEncoded("f".into(), "json".into()))
+            .expect(0)
+            .create_async()
+            .await;
+        let collection: Collection = super::read(format!("{}/collections/an-id", server.url()))
+            .await
+            .unwrap();
+        assert_eq!(collection.id, "an-id");
+        plain.assert_async().await;
+        fallback.assert_async().await;
+    }
+
     #[tokio::test]
     async fn read_network() {
         let href = "https://raw.githubusercontent.com/radiantearth/stac-spec/v1.0.0/examples/simple-item.json";
@@ -69,13 +69,17 @@ mod client;
 pub mod download;
 mod error;
 mod io;
+#[cfg(feature = "jsonschema")]
+pub mod validate;
 
+#[cfg(feature = "jsonschema")]
+pub use validate::{validate, ValidateAsync};
 pub use {
     api_client::ApiClient,
     client::Client,
-    download::{download, Download, Downloader},
+    download::{download, Download, DownloadAsset, Downloader},
     error::Error,
-    io::{read, read_json, write_json_to_path},
+    io::{prune_dead_links, read, read_cached, read_json, write_json_to_path},
 };
 
 /// Crate-specific result type.
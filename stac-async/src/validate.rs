@@ -0,0 +1,263 @@
+//! Validate STAC objects, fetching extension schemas concurrently.
+//!
+//! [stac::Validate] fetches each extension schema one after another. The
+//! [ValidateAsync] trait fetches all of the schemas an object needs at the same
+//! time and then validates. Fetched schemas are compiled once and shared by
+//! every call in the process, so a schema document isn't fetched again once
+//! it's been fetched. Requires the `jsonschema` feature.
+
+use crate::{Error, Result};
+use async_trait::async_trait;
+use futures_util::future;
+use stac::{Catalog, Collection, Extensions, Item, ItemCollection, Validator, Value};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError},
+};
+
+/// The most schema documents that are kept, so extensions that use another
+/// fragment of an already-fetched document don't fetch it again.
+const MAX_DOCUMENTS: usize = 32;
+
+/// The schemas shared by every validation.
+static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+/// A validator, with its compiled extension schemas, and the most recently fetched schema documents.
+#[derive(Debug)]
+struct Cache {
+    validator: Validator,
+    documents: HashMap<String, Arc<serde_json::Value>>,
+}
+
+/// Validates STAC objects, fetching all of their extension schemas concurrently.
+#[async_trait]
+pub trait ValidateAsync {
+    /// Validates this STAC object.
+    ///
+    /// Extension schemas that haven't been fetched yet are fetched at the same
+    /// time, then the object is validated. The schemas are cached for later
+    /// validations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Catalog;
+    /// use stac_async::ValidateAsync;
+    /// let catalog = Catalog::new("an-id", "a description");
+    /// # tokio_test::block_on(async {
+    /// catalog.validate_async().await.unwrap();
+    /// # })
+    /// ```
+    async fn validate_async(&self) -> Result<()>;
+}
+
+/// Validates a STAC value, fetching all of its extension schemas concurrently.
+///
+/// See [ValidateAsync]. The shared cache is only locked to look up and add
+/// compiled schemas, so validations don't wait on each other.
+///
+/// # Examples
+///
+/// ```
+/// # tokio_test::block_on(async {
+/// let value: stac::Value = stac_async::read("data/catalog.json").await.unwrap();
+/// stac_async::validate(value).await.unwrap();
+/// # })
+/// ```
+pub async fn validate(value: Value) -> Result<()> {
+    let extensions = extensions(&value);
+    let (mut validator, mut documents) = {
+        let cache = lock()?;
+        let documents: HashMap<String, Arc<serde_json::Value>> = extensions
+            .iter()
+            .filter(|extension| !cache.validator.has_extension_schema(extension))
+            .filter_map(|extension| {
+                let href = schema_href(extension);
+                cache
+                    .documents
+                    .get(href)
+                    .map(|document| (href.to_string(), Arc::clone(document)))
+            })
+            .collect();
+        (cache.validator.clone(), documents)
+    };
+    let missing: Vec<String> = extensions
+        .iter()
+        .filter(|extension| !validator.has_extension_schema(extension))
+        .cloned()
+        .collect();
+    let hrefs: Vec<String> = missing
+        .iter()
+        .map(|extension| schema_href(extension))
+        .filter(|href| !documents.contains_key(*href))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let fetched = future::try_join_all(
+        hrefs
+            .iter()
+            .map(|href| crate::read_json::<serde_json::Value>(href)),
+    )
+    .await?;
+    let fetched: Vec<(String, Arc<serde_json::Value>)> = hrefs
+        .into_iter()
+        .zip(fetched.into_iter().map(Arc::new))
+        .collect();
+    documents.extend(fetched.iter().cloned());
+
+    // Compiling and validating can resolve remote `$ref`s, e.g. the GeoJSON
+    // schemas that the item schema uses, with a blocking client, which can't
+    // run on an async task.
+    tokio::task::spawn_blocking(move || {
+        for extension in &missing {
+            let document = serde_json::Value::clone(&documents[schema_href(extension)]);
+            validator.add_extension_schema(extension, document)?;
+        }
+        if !missing.is_empty() {
+            let mut cache = lock()?;
+            cache.validator.merge(&validator);
+            if cache.documents.len() + fetched.len() > MAX_DOCUMENTS {
+                cache.documents.clear();
+            }
+            cache.documents.extend(fetched);
+        }
+        validator.validate_value(value).map_err(Error::Validation)
+    })
+    .await?
+}
+
+#[async_trait]
+impl ValidateAsync for Item {
+    async fn validate_async(&self) -> Result<()> {
+        validate(Value::Item(self.clone())).await
+    }
+}
+
+#[async_trait]
+impl ValidateAsync for Catalog {
+    async fn validate_async(&self) -> Result<()> {
+        validate(Value::Catalog(self.clone())).await
+    }
+}
+
+#[async_trait]
+impl ValidateAsync for Collection {
+    async fn validate_async(&self) -> Result<()> {
+        validate(Value::Collection(self.clone())).await
+    }
+}
+
+#[async_trait]
+impl ValidateAsync for ItemCollection {
+    async fn validate_async(&self) -> Result<()> {
+        validate(Value::ItemCollection(self.clone())).await
+    }
+}
+
+#[async_trait]
+impl ValidateAsync for Value {
+    async fn validate_async(&self) -> Result<()> {
+        validate(self.clone()).await
+    }
+}
+
+/// Locks the shared cache, creating it if it doesn't exist yet.
+fn lock() -> Result<MutexGuard<'static, Cache>> {
+    if CACHE.get().is_none() {
+        let cache = Cache {
+            validator: Validator::new()?,
+            documents: HashMap::new(),
+        };
+        // If another thread got there first, its cache is used.
+        let _ = CACHE.set(Mutex::new(cache));
+    }
+    // A panic while holding the lock can leave at most a partially-filled
+    // cache, so keep using it.
+    Ok(CACHE
+        .get()
+        .expect("the cache was just created")
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Returns the extension URIs of a value, and of every item in an item collection.
+fn extensions(value: &Value) -> BTreeSet<String> {
+    let extensions: Vec<&[String]> = match value {
+        Value::Item(item) => item.extensions().into_iter().collect(),
+        Value::Catalog(catalog) => catalog.extensions().into_iter().collect(),
+        Value::Collection(collection) => collection.extensions().into_iter().collect(),
+        Value::ItemCollection(item_collection) => item_collection
+            .items
+            .iter()
+            .filter_map(|item| item.extensions())
+            .collect(),
+    };
+    extensions.into_iter().flatten().cloned().collect()
+}
+
+/// Returns the href of an extension's schema document, without the fragment.
+fn schema_href(extension: &str) -> &str {
+    extension
+        .split_once('#')
+        .map(|(href, _)| href)
+        .unwrap_or(extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidateAsync;
+    use crate::Error;
+    use stac::{Catalog, Item, Value};
+
+    #[tokio::test]
+    async fn valid_item() {
+        let item: Item = crate::read("data/simple-item.json").await.unwrap();
+        item.validate_async().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn valid_catalog() {
+        let catalog: Catalog = crate::read("data/catalog.json").await.unwrap();
+        catalog.validate_async().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn invalid_catalog() {
+        let value = Value::Catalog(Catalog::new("", "a description"));
+        assert!(matches!(
+            value.validate_async().await.unwrap_err(),
+            Error::Validation(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn fetches_each_schema_once() {
+        let mut server = mockito::Server::new_async().await;
+        let schema = std::fs::read_to_string("../stac/examples/extension-schema.json").unwrap();
+        let mock = server
+            .mock("GET", "/schema.json")
+            .with_header("content-type", "application/json")
+            .with_body(schema)
+            .expect(1)
+            .create_async()
+            .await;
+        let href = format!("{}/schema.json", server.url());
+        let mut catalog = Catalog::new("an-id", "a description");
+        let _ = catalog
+            .additional_fields
+            .insert("test:field".to_string(), 42.into());
+        catalog.extensions = Some(vec![format!("{}#/definitions/catalog", href)]);
+        catalog.validate_async().await.unwrap();
+
+        catalog.extensions = Some(vec![
+            format!("{}#/definitions/catalog", href),
+            format!("{}#/definitions/collection", href),
+        ]);
+        assert!(matches!(
+            catalog.validate_async().await.unwrap_err(),
+            Error::Validation(errors) if errors.len() == 1
+        ));
+        mock.assert_async().await;
+    }
+}
@@ -1,4 +1,4 @@
-use stac::{Error, Validate, Value};
+use stac::{Validate, Value};
 
 fn main() {
     let args: Vec<_> = std::env::args().collect();
@@ -11,18 +11,16 @@ fn main() {
         std::process::exit(1)
     }
     let value: Value = stac::read(&args[1]).unwrap();
-    match value.validate() {
-        Ok(()) => println!("OK: {} is valid STAC!", args[1]),
-        Err(errors) => {
-            for error in errors {
-                match error {
-                    Error::ValidationError(e) => {
-                        println!("VALIDATION ERROR at {}: {}", e.instance_path, e);
-                    }
-                    _ => println!("ERROR: {}", error),
-                }
-            }
-            std::process::exit(1)
+    let report = value.validate_with_report();
+    for warning in &report.warnings {
+        println!("WARNING at {}", warning.to_human_string());
+    }
+    if report.is_valid() {
+        println!("OK: {} is valid STAC!", args[1]);
+    } else {
+        for error in &report.errors {
+            println!("VALIDATION ERROR at {}", error.to_human_string());
         }
+        std::process::exit(1)
     }
 }
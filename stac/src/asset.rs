@@ -1,9 +1,14 @@
+use crate::{
+    band::Band, classification::Classification, datacube::Datacube, file::File,
+    processing::Processing, Extensions, Result,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 /// An Asset is an object that contains a URI to data associated with the [Item](crate::Item) that can be downloaded or streamed.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Asset {
     /// URI to the asset object.
     ///
@@ -30,11 +35,62 @@ pub struct Asset {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub roles: Option<Vec<String>>,
 
+    /// Alternate hrefs for this asset, e.g. an `s3://` href alongside an `https://` one.
+    ///
+    /// From the [alternate assets
+    /// extension](https://github.com/stac-extensions/alternate-assets).
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub alternate: HashMap<String, AlternateAsset>,
+
+    /// The bands of the asset, from STAC 1.1.
+    ///
+    /// See the [band module](crate::band) for details.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub bands: Vec<Band>,
+
+    /// A list of extensions the asset implements.
+    ///
+    /// Assets usually rely on their owning object's `stac_extensions`, so this
+    /// is only serialized when set.
+    #[serde(rename = "stac_extensions")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub extensions: Option<Vec<String>>,
+
     /// Additional fields on the asset.
     #[serde(flatten)]
     pub additional_fields: Map<String, Value>,
 }
 
+impl Extensions for Asset {
+    fn extensions(&self) -> Option<&[String]> {
+        self.extensions.as_deref()
+    }
+    fn extensions_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.extensions
+    }
+}
+
+/// An alternate location for an [Asset], from the [alternate assets
+/// extension](https://github.com/stac-extensions/alternate-assets).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AlternateAsset {
+    /// URI to the alternate asset object.
+    pub href: String,
+
+    /// The displayed title for clients and users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// A description of the alternate asset, e.g. how to access it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Additional fields on the alternate asset.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
 /// Trait implemented by anything that has assets.
 ///
 /// As of STAC v1.0.0, this is [Collection](crate::Collection) and [Item](crate::Item).
@@ -64,6 +120,28 @@ pub trait Assets {
     /// item.assets_mut().insert("foo".to_string(), Asset::new("./asset.tif"));
     /// ```
     fn assets_mut(&mut self) -> &mut HashMap<String, Asset>;
+
+    /// Retains only the assets for which the predicate returns true.
+    ///
+    /// The predicate is called with each asset's key and the asset.
+    ///
+    /// # Examples
+    ///
+    /// Keep only the visual assets:
+    ///
+    /// ```
+    /// use stac::{Item, Assets};
+    /// let mut item: Item = stac::read("data/simple-item.json").unwrap();
+    /// item.retain_assets(|_, asset| asset.has_role("visual"));
+    /// assert_eq!(item.assets.len(), 1);
+    /// assert!(item.assets.contains_key("visual"));
+    /// ```
+    fn retain_assets<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &Asset) -> bool,
+    {
+        self.assets_mut().retain(|key, asset| f(key, asset))
+    }
 }
 
 impl Asset {
@@ -83,6 +161,269 @@ impl Asset {
             description: None,
             r#type: None,
             roles: None,
+            alternate: HashMap::new(),
+            bands: Vec::new(),
+            extensions: None,
+            additional_fields: Map::new(),
+        }
+    }
+
+    /// Returns the href of one of this asset's alternates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{AlternateAsset, Asset};
+    /// let mut asset = Asset::new("https://stac-rs.test/asset.tif");
+    /// assert!(asset.alternate_href("s3").is_none());
+    /// let _ = asset.alternate.insert("s3".to_string(), AlternateAsset::new("s3://bucket/asset.tif"));
+    /// assert_eq!(asset.alternate_href("s3").unwrap(), "s3://bucket/asset.tif");
+    /// ```
+    pub fn alternate_href(&self, key: &str) -> Option<&str> {
+        self.alternate
+            .get(key)
+            .map(|alternate| alternate.href.as_str())
+    }
+    /// Returns this asset's [classification](crate::classification) fields, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item: stac::Item = stac::read("examples/classification.json").unwrap();
+    /// let classification = item.assets["qa_pixel"].classification().unwrap().unwrap();
+    /// assert_eq!(classification.bitfields.len(), 2);
+    /// ```
+    pub fn classification(&self) -> Result<Option<Classification>> {
+        Classification::from_fields(&self.additional_fields)
+    }
+
+    /// Sets this asset's [classification](crate::classification) fields.
+    ///
+    /// An empty classification removes the fields. The extension's schema
+    /// should also be added to the owning item or collection, e.g. with
+    /// [Extensions::add_extension](crate::Extensions::add_extension).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{classification::{Class, Classification}, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// let mut classification = Classification::default();
+    /// classification.classes.push(Class::new(1, "water"));
+    /// asset.set_classification(classification).unwrap();
+    /// assert!(asset.additional_fields.contains_key("classification:classes"));
+    /// ```
+    pub fn set_classification(&mut self, classification: Classification) -> Result<()> {
+        classification.into_fields(&mut self.additional_fields)
+    }
+
+    /// Returns this asset's [processing](crate::processing) fields, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item: stac::Item = stac::read("examples/processing.json").unwrap();
+    /// let processing = item.assets["visual"].processing().unwrap().unwrap();
+    /// assert_eq!(processing.software.unwrap()["gdal"], "3.6.0");
+    /// ```
+    pub fn processing(&self) -> Result<Option<Processing>> {
+        Processing::from_fields(&self.additional_fields)
+    }
+
+    /// Sets this asset's [processing](crate::processing) fields.
+    ///
+    /// Empty processing removes the fields. The extension's schema should also
+    /// be added to the owning item or collection, e.g. with
+    /// [Extensions::add_extension](crate::Extensions::add_extension).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{processing::Processing, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// let processing = Processing {
+    ///     level: Some("L2".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// asset.set_processing(processing).unwrap();
+    /// assert_eq!(asset.additional_fields["processing:level"], "L2");
+    /// ```
+    pub fn set_processing(&mut self, processing: Processing) -> Result<()> {
+        processing.into_fields(&mut self.additional_fields)
+    }
+
+    /// Returns this asset's [datacube](crate::datacube) fields, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Asset;
+    /// assert!(Asset::new("an-href").datacube().unwrap().is_none());
+    /// ```
+    pub fn datacube(&self) -> Result<Option<Datacube>> {
+        Datacube::from_fields(&self.additional_fields)
+    }
+
+    /// Sets this asset's [datacube](crate::datacube) fields.
+    ///
+    /// An empty datacube removes the fields. The extension's schema should
+    /// also be added to the owning item or collection, e.g. with
+    /// [Extensions::add_extension](crate::Extensions::add_extension).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{datacube::{Datacube, Dimension}, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// let mut datacube = Datacube::default();
+    /// let _ = datacube.dimensions.insert("time".to_string(), Dimension::new("temporal"));
+    /// asset.set_datacube(datacube).unwrap();
+    /// assert_eq!(asset.additional_fields["cube:dimensions"]["time"]["type"], "temporal");
+    /// ```
+    pub fn set_datacube(&mut self, datacube: Datacube) -> Result<()> {
+        datacube.into_fields(&mut self.additional_fields)
+    }
+
+    /// Returns this asset's [file](crate::file) fields, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item: stac::Item = stac::read("examples/file.json").unwrap();
+    /// let file = item.assets["data"].file().unwrap().unwrap();
+    /// assert_eq!(file.header_size.unwrap(), 16384);
+    /// ```
+    pub fn file(&self) -> Result<Option<File>> {
+        File::from_fields(&self.additional_fields)
+    }
+
+    /// Sets this asset's [file](crate::file) fields.
+    ///
+    /// Empty file fields remove them. The extension's schema should also be
+    /// added to the owning item or collection, e.g. with
+    /// [Extensions::add_extension](crate::Extensions::add_extension).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{file::File, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// let file = File {
+    ///     size: Some(1024),
+    ///     ..Default::default()
+    /// };
+    /// asset.set_file(file).unwrap();
+    /// assert_eq!(asset.additional_fields["file:size"], 1024);
+    /// ```
+    pub fn set_file(&mut self, file: File) -> Result<()> {
+        file.into_fields(&mut self.additional_fields)
+    }
+
+    /// Returns the band at an index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item: stac::Item = stac::read("examples/bands.json").unwrap();
+    /// let asset = &item.assets["data"];
+    /// assert_eq!(asset.band(1).unwrap().name.as_deref(), Some("nir"));
+    /// assert!(asset.band(2).is_none());
+    /// ```
+    pub fn band(&self, index: usize) -> Option<&Band> {
+        self.bands.get(index)
+    }
+
+    /// Returns the first band with a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item: stac::Item = stac::read("examples/bands.json").unwrap();
+    /// let asset = &item.assets["data"];
+    /// assert_eq!(asset.band_by_name("nir").unwrap().unit.as_deref(), Some("reflectance"));
+    /// assert!(asset.band_by_name("swir").is_none());
+    /// ```
+    pub fn band_by_name(&self, name: &str) -> Option<&Band> {
+        self.bands
+            .iter()
+            .find(|band| band.name.as_deref() == Some(name))
+    }
+
+    /// Returns true if this asset has the role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{roles, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// assert!(!asset.has_role(roles::DATA));
+    /// asset.add_role(roles::DATA);
+    /// assert!(asset.has_role(roles::DATA));
+    /// ```
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles
+            .as_ref()
+            .map(|roles| roles.iter().any(|r| r == role))
+            .unwrap_or(false)
+    }
+
+    /// Adds a role to this asset, if it doesn't already have it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{roles, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// asset.add_role(roles::THUMBNAIL);
+    /// asset.add_role(roles::THUMBNAIL);
+    /// assert_eq!(asset.roles.unwrap(), vec!["thumbnail"]);
+    /// ```
+    pub fn add_role(&mut self, role: &str) {
+        if !self.has_role(role) {
+            self.roles
+                .get_or_insert_with(Vec::new)
+                .push(role.to_string());
+        }
+    }
+
+    /// Removes a role from this asset.
+    ///
+    /// If that was the asset's last role, `roles` is set to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{roles, Asset};
+    /// let mut asset = Asset::new("an-href");
+    /// asset.add_role(roles::DATA);
+    /// asset.remove_role(roles::DATA);
+    /// assert!(asset.roles.is_none());
+    /// ```
+    pub fn remove_role(&mut self, role: &str) {
+        if let Some(roles) = self.roles.as_mut() {
+            roles.retain(|r| r != role);
+            if roles.is_empty() {
+                self.roles = None;
+            }
+        }
+    }
+}
+
+impl AlternateAsset {
+    /// Creates a new alternate asset with the provided href.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stac::AlternateAsset;
+    /// let alternate = AlternateAsset::new("s3://bucket/asset.tif");
+    /// assert_eq!(alternate.href, "s3://bucket/asset.tif");
+    /// ```
+    pub fn new(href: impl ToString) -> AlternateAsset {
+        AlternateAsset {
+            href: href.to_string(),
+            title: None,
+            description: None,
             additional_fields: Map::new(),
         }
     }
@@ -90,7 +431,8 @@ impl Asset {
 
 #[cfg(test)]
 mod tests {
-    use super::Asset;
+    use super::{Asset, Assets};
+    use crate::{Extensions, Item};
 
     #[test]
     fn new() {
@@ -100,6 +442,56 @@ mod tests {
         assert!(asset.description.is_none());
         assert!(asset.r#type.is_none());
         assert!(asset.roles.is_none());
+        assert!(asset.extensions.is_none());
+    }
+
+    #[test]
+    fn extensions() {
+        let mut asset = Asset::new("an-href");
+        assert!(asset.extensions().is_none());
+        assert!(serde_json::to_value(&asset)
+            .unwrap()
+            .get("stac_extensions")
+            .is_none());
+        asset.add_extension("an-extension");
+        assert!(asset.has_extension("an-extension"));
+        let value = serde_json::to_value(&asset).unwrap();
+        assert_eq!(value["stac_extensions"][0], "an-extension");
+        let asset: Asset = serde_json::from_value(value).unwrap();
+        assert_eq!(asset.extensions().unwrap(), ["an-extension"]);
+    }
+
+    #[test]
+    fn retain_assets() {
+        let mut item = Item::new("an-id");
+        let _ = item
+            .assets
+            .insert("data".to_string(), Asset::new("./data.tif"));
+        let _ = item
+            .assets
+            .insert("thumbnail".to_string(), Asset::new("./thumbnail.png"));
+        let mut keys = Vec::new();
+        item.retain_assets(|key, asset| {
+            keys.push(key.to_string());
+            asset.href.ends_with(".tif")
+        });
+        keys.sort();
+        assert_eq!(keys, ["data", "thumbnail"]);
+        assert_eq!(item.assets.len(), 1);
+        assert!(item.assets.contains_key("data"));
+    }
+
+    #[test]
+    fn roles() {
+        let mut asset = Asset::new("an-href");
+        asset.roles = Some(vec!["data".to_string(), "overview".to_string()]);
+        asset.add_role(crate::roles::DATA);
+        assert_eq!(asset.roles.as_ref().unwrap().len(), 2);
+        asset.remove_role(crate::roles::DATA);
+        assert_eq!(asset.roles.as_ref().unwrap(), &vec!["overview".to_string()]);
+        assert!(!asset.has_role(crate::roles::DATA));
+        asset.remove_role(crate::roles::CLOUD);
+        assert!(asset.has_role(crate::roles::OVERVIEW));
     }
 
     #[test]
@@ -110,5 +502,73 @@ mod tests {
         assert!(value.get("description").is_none());
         assert!(value.get("type").is_none());
         assert!(value.get("roles").is_none());
+        assert!(value.get("alternate").is_none());
+    }
+
+    #[test]
+    fn alternate_href() {
+        let item: Item = crate::read("examples/alternate-assets.json").unwrap();
+        let asset = &item.assets["B1"];
+        assert!(asset
+            .alternate_href("s3")
+            .unwrap()
+            .starts_with("s3://usgs-landsat/"));
+        assert_eq!(
+            asset.alternate["local"].description.as_deref(),
+            Some("A copy of the asset on the local filesystem")
+        );
+        assert!(asset.alternate_href("not-an-alternate").is_none());
+    }
+
+    #[test]
+    fn bands() {
+        let item: Item = crate::read("examples/bands.json").unwrap();
+        let asset = &item.assets["data"];
+        assert_eq!(asset.bands.len(), 2);
+        let red = asset.band(0).unwrap();
+        let statistics = red.statistics.as_ref().unwrap();
+        assert_eq!(statistics.mean, Some(1234.5));
+        assert_eq!(statistics.count, Some(120560400));
+        assert_eq!(red.histogram.as_ref().unwrap().buckets, [100, 2000, 300, 4]);
+        assert_eq!(red.additional_fields["eo:center_wavelength"], 0.665);
+        let nir = asset.band_by_name("nir").unwrap();
+        assert_eq!(nir.nodata.as_ref().unwrap(), "nan");
+        assert!(nir.statistics.is_none());
+        assert_eq!(asset.additional_fields["data_type"], "uint16");
+        assert!(item.assets["thumbnail"].bands.is_empty());
+    }
+
+    #[test]
+    fn extension_fields() {
+        let item: Item = crate::read("examples/asset-extension-fields.json").unwrap();
+        let asset = &item.assets["data"];
+        for key in [
+            "eo:bands",
+            "raster:bands",
+            "proj:epsg",
+            "proj:shape",
+            "proj:transform",
+            "file:size",
+            "custom:tags",
+        ] {
+            assert!(asset.additional_fields.contains_key(key), "{}", key);
+        }
+        assert_eq!(
+            asset.alternate["s3"].additional_fields["storage:region"],
+            "us-west-2"
+        );
+    }
+
+    mod roundtrip {
+        use crate::tests::roundtrip;
+        use crate::Item;
+
+        roundtrip!(alternate_assets, "examples/alternate-assets.json", Item);
+        roundtrip!(bands, "examples/bands.json", Item);
+        roundtrip!(
+            asset_extension_fields,
+            "examples/asset-extension-fields.json",
+            Item
+        );
     }
 }
@@ -0,0 +1,101 @@
+//! Bands, from the STAC 1.1 [band object](https://github.com/radiantearth/stac-spec/blob/master/commons/common-metadata.md#bands).
+//!
+//! STAC 1.1 consolidates the `eo:bands` and `raster:bands` arrays of STAC 1.0
+//! into a single `bands` array on each asset. Common band fields, including
+//! per-band statistics, are typed; extension fields, e.g.
+//! `eo:center_wavelength`, are kept in each band's additional fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use stac::Item;
+//! let item: Item = stac::read("examples/bands.json").unwrap();
+//! let band = item.assets["data"].band_by_name("red").unwrap();
+//! assert_eq!(band.statistics.as_ref().unwrap().maximum.unwrap(), 10000.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A band of an asset.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Band {
+    /// The name of the band, e.g. `B01` or `red`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// A description of the band.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The value that represents no data, which is a number or one of `"nan"`, `"inf"`, and `"-inf"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nodata: Option<Value>,
+
+    /// The data type of the band's values, e.g. `uint16`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_type: Option<String>,
+
+    /// The unit of the band's values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+
+    /// Statistics of the band's values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistics: Option<Statistics>,
+
+    /// A histogram of the band's values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub histogram: Option<Histogram>,
+
+    /// Additional fields on the band.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// Statistics of a band's values.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Statistics {
+    /// The minimum value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+
+    /// The maximum value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+
+    /// The mean value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean: Option<f64>,
+
+    /// The standard deviation of the values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stddev: Option<f64>,
+
+    /// The number of values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+
+    /// The percentage of values that aren't nodata, from zero to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_percent: Option<f64>,
+}
+
+/// A histogram of a band's values, with equal-width buckets.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Histogram {
+    /// The number of buckets.
+    pub count: u64,
+
+    /// The lower bound of the first bucket.
+    pub min: f64,
+
+    /// The upper bound of the last bucket.
+    pub max: f64,
+
+    /// The number of values in each bucket.
+    pub buckets: Vec<u64>,
+}
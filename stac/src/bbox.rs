@@ -0,0 +1,282 @@
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// A bounding box, either 2D or 3D.
+///
+/// STAC bboxes, e.g. an item's `bbox` and a spatial extent's `bbox`, are
+/// stored as plain vectors so that any JSON round-trips, but a vector doesn't
+/// say whether it has elevation. Use
+/// [Item::bbox_or_geometry_bounds](crate::Item::bbox_or_geometry_bounds) and
+/// [SpatialExtent::bboxes](crate::SpatialExtent::bboxes) to get them as a
+/// `Bbox`, or convert a bbox slice, to work with its bounds by name. Going
+/// the other way, a `Bbox` converts into a vector, e.g. for
+/// [SpatialExtent::from_bbox](crate::SpatialExtent::from_bbox), and borrows
+/// as a slice with `as_ref`, e.g. for
+/// [Extent::intersects_bbox](crate::Extent::intersects_bbox). A 2D bbox is
+/// `[xmin, ymin, xmax, ymax]`, and a 3D bbox is `[xmin, ymin, zmin, xmax,
+/// ymax, zmax]`. A bbox that crosses the antimeridian has `xmin > xmax`.
+///
+/// # Examples
+///
+/// ```
+/// use stac::Bbox;
+/// let bbox = Bbox::try_from(&[-105.0, 40.0, 1500.0, -104.0, 41.0, 1800.0][..]).unwrap();
+/// assert!(bbox.is_3d());
+/// assert_eq!(bbox.zmax(), Some(1800.0));
+/// assert_eq!(Vec::from(bbox.to_2d()), [-105.0, 40.0, -104.0, 41.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum Bbox {
+    /// A 2D bbox, `[xmin, ymin, xmax, ymax]`.
+    TwoDimensional([f64; 4]),
+
+    /// A 3D bbox, `[xmin, ymin, zmin, xmax, ymax, zmax]`.
+    ThreeDimensional([f64; 6]),
+}
+
+impl Bbox {
+    /// Returns true if this bbox has elevation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert!(!Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).is_3d());
+    /// ```
+    pub fn is_3d(&self) -> bool {
+        matches!(self, Bbox::ThreeDimensional(_))
+    }
+
+    /// Returns the minimum x (i.e. the west bound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert_eq!(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).xmin(), -105.0);
+    /// ```
+    pub fn xmin(&self) -> f64 {
+        match self {
+            Bbox::TwoDimensional(bbox) => bbox[0],
+            Bbox::ThreeDimensional(bbox) => bbox[0],
+        }
+    }
+
+    /// Returns the minimum y (i.e. the south bound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert_eq!(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).ymin(), 40.0);
+    /// ```
+    pub fn ymin(&self) -> f64 {
+        match self {
+            Bbox::TwoDimensional(bbox) => bbox[1],
+            Bbox::ThreeDimensional(bbox) => bbox[1],
+        }
+    }
+
+    /// Returns the maximum x (i.e. the east bound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert_eq!(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).xmax(), -104.0);
+    /// ```
+    pub fn xmax(&self) -> f64 {
+        match self {
+            Bbox::TwoDimensional(bbox) => bbox[2],
+            Bbox::ThreeDimensional(bbox) => bbox[3],
+        }
+    }
+
+    /// Returns the maximum y (i.e. the north bound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert_eq!(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).ymax(), 41.0);
+    /// ```
+    pub fn ymax(&self) -> f64 {
+        match self {
+            Bbox::TwoDimensional(bbox) => bbox[3],
+            Bbox::ThreeDimensional(bbox) => bbox[4],
+        }
+    }
+
+    /// Returns the minimum elevation, if this bbox is 3D.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert_eq!(Bbox::ThreeDimensional([-105.0, 40.0, 1500.0, -104.0, 41.0, 1800.0]).zmin(), Some(1500.0));
+    /// assert_eq!(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).zmin(), None);
+    /// ```
+    pub fn zmin(&self) -> Option<f64> {
+        match self {
+            Bbox::TwoDimensional(_) => None,
+            Bbox::ThreeDimensional(bbox) => Some(bbox[2]),
+        }
+    }
+
+    /// Returns the maximum elevation, if this bbox is 3D.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// assert_eq!(Bbox::ThreeDimensional([-105.0, 40.0, 1500.0, -104.0, 41.0, 1800.0]).zmax(), Some(1800.0));
+    /// assert_eq!(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]).zmax(), None);
+    /// ```
+    pub fn zmax(&self) -> Option<f64> {
+        match self {
+            Bbox::TwoDimensional(_) => None,
+            Bbox::ThreeDimensional(bbox) => Some(bbox[5]),
+        }
+    }
+
+    /// Returns this bbox without its elevation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Bbox;
+    /// let bbox = Bbox::ThreeDimensional([-105.0, 40.0, 1500.0, -104.0, 41.0, 1800.0]);
+    /// assert_eq!(bbox.to_2d(), Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]));
+    /// ```
+    pub fn to_2d(&self) -> Bbox {
+        Bbox::TwoDimensional([self.xmin(), self.ymin(), self.xmax(), self.ymax()])
+    }
+}
+
+impl TryFrom<&[f64]> for Bbox {
+    type Error = Error;
+
+    fn try_from(bbox: &[f64]) -> Result<Bbox> {
+        match *bbox {
+            [xmin, ymin, xmax, ymax] => Ok(Bbox::TwoDimensional([xmin, ymin, xmax, ymax])),
+            [xmin, ymin, zmin, xmax, ymax, zmax] => {
+                Ok(Bbox::ThreeDimensional([xmin, ymin, zmin, xmax, ymax, zmax]))
+            }
+            _ => Err(Error::InvalidBbox(bbox.to_vec())),
+        }
+    }
+}
+
+impl TryFrom<Vec<f64>> for Bbox {
+    type Error = Error;
+
+    fn try_from(bbox: Vec<f64>) -> Result<Bbox> {
+        Bbox::try_from(bbox.as_slice())
+    }
+}
+
+impl AsRef<[f64]> for Bbox {
+    fn as_ref(&self) -> &[f64] {
+        match self {
+            Bbox::TwoDimensional(bbox) => bbox,
+            Bbox::ThreeDimensional(bbox) => bbox,
+        }
+    }
+}
+
+impl From<Bbox> for Vec<f64> {
+    fn from(bbox: Bbox) -> Vec<f64> {
+        match bbox {
+            Bbox::TwoDimensional(bbox) => bbox.to_vec(),
+            Bbox::ThreeDimensional(bbox) => bbox.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bbox;
+    use crate::{Error, Extent, Geometry, Item, SpatialExtent};
+
+    #[test]
+    fn try_from() {
+        assert!(!Bbox::try_from(vec![1.0, 2.0, 3.0, 4.0]).unwrap().is_3d());
+        assert!(Bbox::try_from(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+            .unwrap()
+            .is_3d());
+        for bbox in [vec![], vec![1.0, 2.0, 3.0], vec![1.0, 2.0, 3.0, 4.0, 5.0]] {
+            assert!(matches!(
+                Bbox::try_from(bbox.clone()).unwrap_err(),
+                Error::InvalidBbox(b) if b == bbox
+            ));
+        }
+    }
+
+    #[test]
+    fn serde() {
+        let bbox: Bbox = serde_json::from_str("[1, 2, 3, 4, 5, 6]").unwrap();
+        assert_eq!(bbox, Bbox::ThreeDimensional([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+        assert_eq!(
+            serde_json::to_string(&bbox).unwrap(),
+            "[1.0,2.0,3.0,4.0,5.0,6.0]"
+        );
+        let bbox: Bbox = serde_json::from_str("[1, 2, 3, 4]").unwrap();
+        assert!(!bbox.is_3d());
+        assert!(serde_json::from_str::<Bbox>("[1, 2, 3]").is_err());
+    }
+
+    #[test]
+    fn item() {
+        let mut item = Item::new("an-id");
+        assert!(item.bbox_or_geometry_bounds().unwrap().is_none());
+        item.geometry = Some(Geometry::new(geojson::Value::Point(vec![
+            -105.0, 40.0, 1.0,
+        ])));
+        assert_eq!(
+            item.bbox_or_geometry_bounds().unwrap().unwrap(),
+            Bbox::ThreeDimensional([-105.0, 40.0, 1.0, -105.0, 40.0, 1.0])
+        );
+        item.bbox = Some(vec![1.0, 2.0, 3.0]);
+        assert!(matches!(
+            item.bbox_or_geometry_bounds().unwrap_err(),
+            Error::InvalidBbox(_)
+        ));
+        let bbox = Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]);
+        item.set_bbox(bbox);
+        assert_eq!(item.bbox_or_geometry_bounds().unwrap().unwrap(), bbox);
+    }
+
+    #[test]
+    fn extent() {
+        let bbox = Bbox::ThreeDimensional([-105.0, 40.0, 1500.0, -104.0, 41.0, 1800.0]);
+        let mut extent = Extent {
+            spatial: SpatialExtent::from_bbox(bbox),
+            ..Default::default()
+        };
+        assert_eq!(extent.spatial.bboxes().unwrap(), [bbox]);
+        assert!(extent.intersects_bbox(Bbox::TwoDimensional([-104.5, 40.5, -103.0, 42.0]).as_ref()));
+        assert!(!extent.intersects_bbox(Bbox::TwoDimensional([0.0, 0.0, 1.0, 1.0]).as_ref()));
+        extent.spatial.bbox.push(vec![1.0]);
+        assert!(extent.spatial.bboxes().is_err());
+    }
+
+    #[test]
+    fn item_3d() {
+        let mut item = Item::new("an-id");
+        item.geometry = Some(Geometry::new(geojson::Value::LineString(vec![
+            vec![-105.0, 40.0, 1500.0],
+            vec![-104.0, 41.0, 1800.0],
+        ])));
+        let bbox = Bbox::try_from(item.compute_bbox().unwrap()).unwrap();
+        assert!(bbox.is_3d());
+        assert_eq!(bbox.zmin(), Some(1500.0));
+        assert_eq!(bbox.zmax(), Some(1800.0));
+        assert_eq!(bbox.xmax(), -104.0);
+
+        item.bbox = Some(bbox.into());
+        let item: Item = serde_json::from_value(serde_json::to_value(item).unwrap()).unwrap();
+        assert_eq!(item.bbox.unwrap().len(), 6);
+    }
+}
@@ -0,0 +1,228 @@
+use serde_json::Value;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, PoisonError},
+};
+
+/// The default number of entries in a [ReadCache].
+pub const DEFAULT_READ_CACHE_CAPACITY: usize = 256;
+
+/// A bounded, least-recently-used cache of the JSON read from hrefs.
+///
+/// Use a cache with [read_cached](crate::read_cached) to avoid reading the
+/// same object more than once, e.g. the root or parent catalogs of a tree
+/// that's being walked. Entries are keyed by [canonical_key](crate::canonical_key),
+/// so different spellings of the same href share an entry. When the cache is
+/// full, the least recently used entry is evicted.
+///
+/// Entries never expire, so an object that changes after it was read is stale
+/// in the cache until it's evicted. Use a cache for the duration of one task,
+/// e.g. a walk, or [clear](ReadCache::clear) it when the underlying objects
+/// may have changed.
+///
+/// A cache can be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use stac::{Catalog, ReadCache};
+/// let cache = ReadCache::new(16);
+/// let catalog: Catalog = stac::read_cached("data/catalog.json", &cache).unwrap();
+/// assert_eq!(cache.len(), 1);
+/// let catalog: Catalog = stac::read_cached("data/../data/catalog.json", &cache).unwrap();
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ReadCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    values: HashMap<String, Value>,
+    order: VecDeque<String>,
+}
+
+impl ReadCache {
+    /// Creates a new cache that holds up to `capacity` entries.
+    ///
+    /// A cache with a capacity of zero never holds anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// let cache = ReadCache::new(16);
+    /// assert_eq!(cache.capacity(), 16);
+    /// ```
+    pub fn new(capacity: usize) -> ReadCache {
+        ReadCache {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Returns the maximum number of entries in this cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// assert_eq!(ReadCache::default().capacity(), stac::DEFAULT_READ_CACHE_CAPACITY);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a clone of the cached value for an href, if there is one.
+    ///
+    /// This marks the entry as the most recently used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// let cache = ReadCache::new(16);
+    /// cache.insert("data/catalog.json", serde_json::json!({"id": "examples"}));
+    /// assert_eq!(cache.get("./data/catalog.json").unwrap()["id"], "examples");
+    /// assert!(cache.get("data/collection.json").is_none());
+    /// ```
+    pub fn get(&self, href: &str) -> Option<Value> {
+        let key = key(href);
+        let mut entries = self.lock();
+        let value = entries.values.get(&key).cloned()?;
+        entries.touch(key);
+        Some(value)
+    }
+
+    /// Caches the value for an href, evicting the least recently used entry if the cache is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// let cache = ReadCache::new(1);
+    /// cache.insert("a.json", serde_json::json!({"id": "a"}));
+    /// cache.insert("b.json", serde_json::json!({"id": "b"}));
+    /// assert!(cache.get("a.json").is_none());
+    /// assert!(cache.get("b.json").is_some());
+    /// ```
+    pub fn insert(&self, href: &str, value: Value) {
+        if self.capacity == 0 {
+            return;
+        }
+        let key = key(href);
+        let mut entries = self.lock();
+        let _ = entries.values.insert(key.clone(), value);
+        entries.touch(key);
+        while entries.order.len() > self.capacity {
+            if let Some(key) = entries.order.pop_front() {
+                let _ = entries.values.remove(&key);
+            }
+        }
+    }
+
+    /// Returns the number of entries in this cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// assert_eq!(ReadCache::new(16).len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().values.len()
+    }
+
+    /// Returns true if this cache has no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// assert!(ReadCache::new(16).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all entries from this cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadCache;
+    /// let cache = ReadCache::new(16);
+    /// cache.insert("a.json", serde_json::json!({"id": "a"}));
+    /// cache.clear();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn clear(&self) {
+        let mut entries = self.lock();
+        entries.values.clear();
+        entries.order.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        // A panic while holding the lock can't leave the entries inconsistent
+        // in a way that matters, so keep using them.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for ReadCache {
+    fn default() -> ReadCache {
+        ReadCache::new(DEFAULT_READ_CACHE_CAPACITY)
+    }
+}
+
+impl Entries {
+    fn touch(&mut self, key: String) {
+        if let Some(i) = self.order.iter().position(|k| *k == key) {
+            let _ = self.order.remove(i);
+        }
+        self.order.push_back(key);
+    }
+}
+
+fn key(href: &str) -> String {
+    crate::canonical_key("", href)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadCache;
+    use serde_json::json;
+
+    #[test]
+    fn least_recently_used() {
+        let cache = ReadCache::new(2);
+        cache.insert("a.json", json!({"id": "a"}));
+        cache.insert("b.json", json!({"id": "b"}));
+        assert!(cache.get("a.json").is_some());
+        cache.insert("c.json", json!({"id": "c"}));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a.json").is_some());
+        assert!(cache.get("b.json").is_none());
+        assert!(cache.get("c.json").is_some());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let cache = ReadCache::new(0);
+        cache.insert("a.json", json!({"id": "a"}));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn read_cached() {
+        let cache = ReadCache::new(16);
+        let a: crate::Catalog = crate::read_cached("data/catalog.json", &cache).unwrap();
+        cache.insert("data/catalog.json", json!({"type": "Catalog", "stac_version": "1.0.0", "id": "cached", "description": "", "links": []}));
+        let b: crate::Catalog = crate::read_cached("data/catalog.json", &cache).unwrap();
+        assert_eq!(a.id, "examples");
+        assert_eq!(b.id, "cached");
+        assert_eq!(crate::Href::href(&b), Some("data/catalog.json"));
+    }
+}
@@ -1,6 +1,7 @@
-use crate::{Error, Extensions, Href, Link, Links, Result, STAC_VERSION};
+use crate::{media_type, Error, Extensions, Href, Link, Links, Result, STAC_VERSION};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use url::Url;
 
 /// The type field for [Catalogs](Catalog).
 pub const CATALOG_TYPE: &str = "Catalog";
@@ -18,6 +19,7 @@ pub const CATALOG_TYPE: &str = "Catalog";
 /// Their purpose is discovery: to be browsed by people or be crawled by clients
 /// to build a searchable index.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Catalog {
     /// A list of extension identifiers the `Catalog` implements.
     #[serde(rename = "stac_extensions")]
@@ -82,6 +84,38 @@ impl Catalog {
             href: None,
         }
     }
+
+    /// Returns the links a STAC API landing page needs, rooted at `base_url`.
+    ///
+    /// These are `self`, `root`, `conformance`, `data` (the collections
+    /// endpoint), `search`, `service-desc` (an OpenAPI definition at `api`),
+    /// and `service-doc` (its HTML rendering at `api.html`). `base_url` is
+    /// treated as a directory, whether or not it has a trailing slash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Links};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links = Catalog::api_landing_links("http://stac-rs.test/api/v1").unwrap();
+    /// assert_eq!(catalog.link("root").unwrap().href, "http://stac-rs.test/api/v1/");
+    /// assert_eq!(
+    ///     catalog.link("search").unwrap().href,
+    ///     "http://stac-rs.test/api/v1/search"
+    /// );
+    /// ```
+    pub fn api_landing_links(base_url: &str) -> Result<Vec<Link>> {
+        let base = Url::parse(&format!("{}/", base_url.trim_end_matches('/')))?;
+        Ok(vec![
+            Link::self_(&base),
+            Link::root(&base),
+            Link::new(base.join("conformance")?, "conformance").json(),
+            Link::new(base.join("collections")?, "data").json(),
+            Link::new(base.join("search")?, "search").geojson(),
+            Link::new(base.join("api")?, "service-desc").r#type(media_type::OPENAPI.to_string()),
+            Link::new(base.join("api.html")?, "service-doc").r#type(media_type::HTML.to_string()),
+        ])
+    }
 }
 
 impl Href for Catalog {
@@ -89,7 +123,7 @@ impl Href for Catalog {
         self.href.as_deref()
     }
 
-    fn set_href(&mut self, href: impl ToString) {
+    fn set_href(&mut self, href: &str) {
         self.href = Some(href.to_string())
     }
 }
@@ -125,6 +159,9 @@ impl Extensions for Catalog {
     fn extensions(&self) -> Option<&[String]> {
         self.extensions.as_deref()
     }
+    fn extensions_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.extensions
+    }
 }
 
 fn deserialize_type<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
@@ -166,6 +203,28 @@ mod tests {
         assert!(value.get("title").is_none());
     }
 
+    #[test]
+    fn api_landing_links() {
+        let links = Catalog::api_landing_links("http://stac-rs.test/api/v1/").unwrap();
+        let rels: Vec<_> = links.iter().map(|link| link.rel.as_str()).collect();
+        assert_eq!(
+            rels,
+            [
+                "self",
+                "root",
+                "conformance",
+                "data",
+                "search",
+                "service-desc",
+                "service-doc"
+            ]
+        );
+        assert_eq!(links[3].href, "http://stac-rs.test/api/v1/collections");
+        assert_eq!(links[4].r#type.as_deref(), Some("application/geo+json"));
+        assert_eq!(links[5].href, "http://stac-rs.test/api/v1/api");
+        assert!(Catalog::api_landing_links("not a url").is_err());
+    }
+
     mod roundtrip {
         use super::Catalog;
         use crate::tests::roundtrip;
@@ -0,0 +1,264 @@
+//! The [classification extension](https://github.com/stac-extensions/classification).
+//!
+//! Classification describes categorical data, e.g. land cover classes or
+//! bit-packed quality masks, so that the values in a raster can be
+//! interpreted. The fields can be set on an [Asset](crate::Asset) or in an
+//! item's [Properties](crate::Properties).
+//!
+//! # Examples
+//!
+//! ```
+//! use stac::Item;
+//! let item: Item = stac::read("examples/classification.json").unwrap();
+//! let classification = item.assets["land_cover"].classification().unwrap().unwrap();
+//! let class = classification.class(1).unwrap();
+//! assert_eq!(class.name, "water");
+//! ```
+
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const FIELDS: [&str; 2] = ["classification:classes", "classification:bitfields"];
+
+/// The schema URI of the classification extension.
+pub const SCHEMA_URI: &str = "https://stac-extensions.github.io/classification/v1.1.0/schema.json";
+
+/// The classification fields of an asset or an item.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Classification {
+    /// The classes of the values, for categorical data.
+    #[serde(
+        rename = "classification:classes",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    pub classes: Vec<Class>,
+
+    /// The bitfields of the values, for bit-packed data such as quality masks.
+    #[serde(
+        rename = "classification:bitfields",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    pub bitfields: Vec<Bitfield>,
+}
+
+/// One class of a [Classification] or a [Bitfield].
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Class {
+    /// The value of the class.
+    pub value: i64,
+
+    /// A short, machine-friendly name for the class.
+    pub name: String,
+
+    /// A description of the class.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// A suggested display color, as an RGB hex string without a leading `#`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_hint: Option<String>,
+
+    /// If true, this class represents missing data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nodata: Option<bool>,
+
+    /// Additional fields on the class, e.g. `percentage` and `count`.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// A set of bits in a bit-packed value, and the classes of those bits.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Bitfield {
+    /// The offset of the first bit, counting from the least significant bit.
+    pub offset: u32,
+
+    /// The number of bits.
+    pub length: u32,
+
+    /// The classes of the values of these bits.
+    pub classes: Vec<Class>,
+
+    /// A short, machine-friendly name for the bitfield.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// A description of the bitfield.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The roles of the bitfield, e.g. `cloud`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<String>>,
+
+    /// Additional fields on the bitfield.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl Classification {
+    /// Returns the class with the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::classification::{Class, Classification};
+    /// let mut classification = Classification::default();
+    /// classification.classes.push(Class::new(1, "water"));
+    /// assert_eq!(classification.class(1).unwrap().name, "water");
+    /// assert!(classification.class(2).is_none());
+    /// ```
+    pub fn class(&self, value: i64) -> Option<&Class> {
+        self.classes.iter().find(|class| class.value == value)
+    }
+
+    /// Returns true if there are no classes and no bitfields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::classification::Classification;
+    /// assert!(Classification::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() && self.bitfields.is_empty()
+    }
+
+    pub(crate) fn from_fields(fields: &Map<String, Value>) -> Result<Option<Classification>> {
+        crate::extensions::extract_fields(fields, &FIELDS)
+    }
+
+    pub(crate) fn into_fields(self, fields: &mut Map<String, Value>) -> Result<()> {
+        crate::extensions::replace_fields(fields, &FIELDS, self)
+    }
+}
+
+impl Class {
+    /// Creates a new class with a value and a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::classification::Class;
+    /// let class = Class::new(1, "water");
+    /// assert_eq!(class.value, 1);
+    /// ```
+    pub fn new(value: i64, name: impl ToString) -> Class {
+        Class {
+            value,
+            name: name.to_string(),
+            description: None,
+            color_hint: None,
+            nodata: None,
+            additional_fields: Map::new(),
+        }
+    }
+}
+
+impl Bitfield {
+    /// Creates a new bitfield with an offset and a length, and no classes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::classification::Bitfield;
+    /// let bitfield = Bitfield::new(3, 1);
+    /// assert!(bitfield.classes.is_empty());
+    /// ```
+    pub fn new(offset: u32, length: u32) -> Bitfield {
+        Bitfield {
+            offset,
+            length,
+            classes: Vec::new(),
+            name: None,
+            description: None,
+            roles: None,
+            additional_fields: Map::new(),
+        }
+    }
+
+    /// Extracts this bitfield's bits from a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::classification::Bitfield;
+    /// let bitfield = Bitfield::new(3, 2);
+    /// assert_eq!(bitfield.extract(0b0001_1000), 0b11);
+    /// ```
+    pub fn extract(&self, value: u64) -> u64 {
+        let shifted = value.checked_shr(self.offset).unwrap_or(0);
+        if self.length >= 64 {
+            shifted
+        } else {
+            shifted & ((1 << self.length) - 1)
+        }
+    }
+
+    /// Returns the class of this bitfield's bits in a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::classification::{Bitfield, Class};
+    /// let mut bitfield = Bitfield::new(3, 1);
+    /// bitfield.classes.push(Class::new(1, "cloud"));
+    /// assert_eq!(bitfield.class_of(0b1000).unwrap().name, "cloud");
+    /// assert!(bitfield.class_of(0).is_none());
+    /// ```
+    pub fn class_of(&self, value: u64) -> Option<&Class> {
+        let bits = self.extract(value);
+        self.classes
+            .iter()
+            .find(|class| u64::try_from(class.value).ok() == Some(bits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Class, Classification, SCHEMA_URI};
+    use crate::{Asset, Extensions, Item};
+
+    #[test]
+    fn read() {
+        let item: Item = crate::read("examples/classification.json").unwrap();
+        let classification = item.assets["land_cover"].classification().unwrap().unwrap();
+        assert_eq!(classification.classes.len(), 3);
+        assert_eq!(classification.classes[0].nodata, Some(true));
+        assert_eq!(
+            classification.classes[2].additional_fields["percentage"],
+            61.2
+        );
+        let classification = item.assets["qa_pixel"].classification().unwrap().unwrap();
+        let cloud = &classification.bitfields[1];
+        assert_eq!(cloud.offset, 3);
+        assert_eq!(cloud.class_of(0b1000).unwrap().name, "cloud");
+        assert_eq!(cloud.class_of(0b0111).unwrap().name, "not_cloud");
+    }
+
+    #[test]
+    fn none() {
+        assert!(Asset::new("an-href").classification().unwrap().is_none());
+        assert!(Item::new("an-id").classification().unwrap().is_none());
+    }
+
+    #[test]
+    fn set_on_item() {
+        let mut item = Item::new("an-id");
+        let mut classification = Classification::default();
+        classification.classes.push(Class::new(1, "water"));
+        item.set_classification(classification.clone()).unwrap();
+        assert_eq!(item.extensions().unwrap(), [SCHEMA_URI]);
+        assert_eq!(item.classification().unwrap().unwrap(), classification);
+        item.set_classification(Classification::default()).unwrap();
+        assert!(item.classification().unwrap().is_none());
+    }
+
+    crate::tests::roundtrip!(classification, "examples/classification.json", Item);
+}
@@ -0,0 +1,264 @@
+//! Create items from [Cloud Optimized GeoTIFFs](https://www.cogeo.org/).
+
+use crate::{extensions::PROJECTION_EPSG_EXTENSION, media_type, roles, Asset, Item, Result};
+use serde_json::{json, Map};
+use std::{fs::File, io::BufReader, path::Path};
+use tiff::{decoder::Decoder, tags::Tag};
+
+const DATA_ASSET_KEY: &str = "data";
+const GT_MODEL_TYPE_GEO_KEY: u16 = 1024;
+const GEOGRAPHIC_TYPE_GEO_KEY: u16 = 2048;
+const PROJECTED_CS_TYPE_GEO_KEY: u16 = 3072;
+const MODEL_TYPE_GEOGRAPHIC: u16 = 2;
+const USER_DEFINED: u16 = 32767;
+const WGS84: u16 = 4326;
+
+impl Item {
+    /// Creates an item from the header of a Cloud Optimized GeoTIFF (or any GeoTIFF).
+    ///
+    /// The item's id is the file stem and it gets one `data` asset pointing at
+    /// the file. The asset's media type is [media_type::COG] if the image is
+    /// tiled and has overviews, and [media_type::GEOTIFF] otherwise. The `proj:shape`, `proj:transform`, `proj:epsg`, and
+    /// `proj:bbox` properties are set from the GeoTIFF tags, as far as they are
+    /// present, and the item declares the projection extension version that
+    /// has `proj:epsg` (v1.1.0). If the image is in EPSG:4326, its bounds
+    /// become the item's `geometry` and `bbox`. With the `proj` feature, bounds
+    /// in any other supported EPSG code are reprojected to WGS84 first (see
+    /// [Item::bbox_wgs84]); without it, or if there's no projection definition
+    /// for the code, `geometry` and `bbox` are left unset for non-WGS84 images.
+    /// A file without georeferencing is not an error: it produces an item
+    /// without a geometry, with `proj:shape` as its only projection field.
+    /// Requires the `geotiff` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item = stac::Item::from_cog("examples/geotiff-4326.tif").unwrap();
+    /// assert_eq!(item.id, "geotiff-4326");
+    /// assert!(item.geometry.is_some());
+    /// assert_eq!(item.properties.additional_fields["proj:epsg"], 4326);
+    /// ```
+    pub fn from_cog(path: impl AsRef<Path>) -> Result<Item> {
+        let path = path.as_ref();
+        let id = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut item = Item::new(id);
+        let mut decoder = Decoder::new(BufReader::new(File::open(path)?))?;
+        let mut asset = Asset::new(path.to_string_lossy());
+        let is_cloud_optimized =
+            decoder.find_tag(Tag::TileWidth)?.is_some() && decoder.more_images();
+        asset.r#type = Some(
+            if is_cloud_optimized {
+                media_type::COG
+            } else {
+                media_type::GEOTIFF
+            }
+            .to_string(),
+        );
+        asset.add_role(roles::DATA);
+        let _ = item.assets.insert(DATA_ASSET_KEY.to_string(), asset);
+
+        let (width, height) = decoder.dimensions()?;
+        let mut fields = Map::new();
+        let _ = fields.insert("proj:shape".to_string(), json!([height, width]));
+        let epsg = find_epsg(&mut decoder)?;
+        if let Some(epsg) = epsg {
+            let _ = fields.insert("proj:epsg".to_string(), epsg.into());
+        }
+        if let Some(transform) = find_transform(&mut decoder)? {
+            let _ = fields.insert("proj:transform".to_string(), json!(transform));
+            let bbox = bounds(&transform, width, height);
+            let _ = fields.insert("proj:bbox".to_string(), json!(bbox));
+            if let Some(bbox) = wgs84_bbox(&bbox, epsg)? {
+                item.geometry = Some(geojson::Geometry::new(geojson::Value::Polygon(vec![vec![
+                    vec![bbox[0], bbox[1]],
+                    vec![bbox[2], bbox[1]],
+                    vec![bbox[2], bbox[3]],
+                    vec![bbox[0], bbox[3]],
+                    vec![bbox[0], bbox[1]],
+                ]])));
+                item.bbox = Some(bbox);
+            }
+        }
+        item.properties.additional_fields.extend(fields);
+        item.extensions = Some(vec![PROJECTION_EPSG_EXTENSION.to_string()]);
+        Ok(item)
+    }
+}
+
+/// Returns the bounds in WGS84, or `None` if they can't be reprojected.
+#[cfg(feature = "proj")]
+fn wgs84_bbox(bbox: &[f64; 4], epsg: Option<u16>) -> Result<Option<Vec<f64>>> {
+    match epsg {
+        Some(WGS84) => Ok(Some(bbox.to_vec())),
+        Some(epsg) => match crate::proj::reproject(bbox, epsg.into()) {
+            Ok(bbox) => Ok(Some(bbox)),
+            Err(crate::Error::UnsupportedEpsg(_)) => Ok(None),
+            Err(err) => Err(err),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Returns the bounds in WGS84, or `None` if they can't be reprojected.
+#[cfg(not(feature = "proj"))]
+fn wgs84_bbox(bbox: &[f64; 4], epsg: Option<u16>) -> Result<Option<Vec<f64>>> {
+    Ok(Some(bbox.to_vec()).filter(|_| epsg == Some(WGS84)))
+}
+
+fn find_epsg<R>(decoder: &mut Decoder<R>) -> Result<Option<u16>>
+where
+    R: std::io::Read + std::io::Seek,
+{
+    let keys = if let Some(value) = decoder.find_tag(Tag::GeoKeyDirectoryTag)? {
+        value.into_u16_vec()?
+    } else {
+        return Ok(None);
+    };
+    let mut model_type = None;
+    let mut geographic = None;
+    let mut projected = None;
+    // The directory is a four-short header followed by four-short keys, with
+    // the value stored inline when the location (second short) is zero.
+    for key in keys.chunks_exact(4).skip(1) {
+        if key[1] != 0 {
+            continue;
+        }
+        match key[0] {
+            GT_MODEL_TYPE_GEO_KEY => model_type = Some(key[3]),
+            GEOGRAPHIC_TYPE_GEO_KEY => geographic = Some(key[3]),
+            PROJECTED_CS_TYPE_GEO_KEY => projected = Some(key[3]),
+            _ => {}
+        }
+    }
+    let epsg = if model_type == Some(MODEL_TYPE_GEOGRAPHIC) {
+        geographic
+    } else {
+        projected.or(geographic)
+    };
+    Ok(epsg.filter(|&epsg| epsg != USER_DEFINED))
+}
+
+fn find_transform<R>(decoder: &mut Decoder<R>) -> Result<Option<[f64; 6]>>
+where
+    R: std::io::Read + std::io::Seek,
+{
+    if let Some(value) = decoder.find_tag(Tag::ModelTransformationTag)? {
+        let m = value.into_f64_vec()?;
+        if m.len() >= 8 {
+            return Ok(Some([m[0], m[1], m[3], m[4], m[5], m[7]]));
+        }
+    }
+    let scale = decoder.find_tag(Tag::ModelPixelScaleTag)?;
+    let tiepoint = decoder.find_tag(Tag::ModelTiepointTag)?;
+    if let (Some(scale), Some(tiepoint)) = (scale, tiepoint) {
+        let (scale, tiepoint) = (scale.into_f64_vec()?, tiepoint.into_f64_vec()?);
+        if scale.len() >= 2 && tiepoint.len() >= 6 {
+            return Ok(Some([
+                scale[0],
+                0.0,
+                tiepoint[3] - tiepoint[0] * scale[0],
+                0.0,
+                -scale[1],
+                tiepoint[4] + tiepoint[1] * scale[1],
+            ]));
+        }
+    }
+    Ok(None)
+}
+
+fn bounds(transform: &[f64; 6], width: u32, height: u32) -> [f64; 4] {
+    let (width, height) = (f64::from(width), f64::from(height));
+    let corners = [(0.0, 0.0), (width, 0.0), (width, height), (0.0, height)].map(|(col, row)| {
+        (
+            transform[0] * col + transform[1] * row + transform[2],
+            transform[3] * col + transform[4] * row + transform[5],
+        )
+    });
+    corners.iter().fold(
+        [
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ],
+        |[xmin, ymin, xmax, ymax], &(x, y)| [xmin.min(x), ymin.min(y), xmax.max(x), ymax.max(y)],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{media_type, Item};
+    use serde_json::json;
+
+    #[test]
+    fn geographic() {
+        let item = Item::from_cog("examples/geotiff-4326.tif").unwrap();
+        assert_eq!(item.id, "geotiff-4326");
+        assert_eq!(item.bbox.unwrap(), vec![-105.0, 39.0, -104.0, 40.0]);
+        assert!(item.geometry.is_some());
+        let fields = &item.properties.additional_fields;
+        assert_eq!(fields["proj:epsg"], 4326);
+        assert_eq!(fields["proj:shape"], json!([4, 4]));
+        assert_eq!(
+            item.extensions.unwrap(),
+            ["https://stac-extensions.github.io/projection/v1.1.0/schema.json"]
+        );
+        assert_eq!(
+            fields["proj:transform"],
+            json!([0.25, 0.0, -105.0, 0.0, -0.25, 40.0])
+        );
+        let asset = &item.assets["data"];
+        assert_eq!(asset.href, "examples/geotiff-4326.tif");
+        assert_eq!(asset.r#type.as_deref(), Some(media_type::GEOTIFF));
+    }
+
+    #[test]
+    fn cloud_optimized() {
+        let item = Item::from_cog("examples/cog.tif").unwrap();
+        assert_eq!(item.bbox.unwrap(), vec![-105.0, 39.0, -104.0, 40.0]);
+        assert_eq!(
+            item.properties.additional_fields["proj:shape"],
+            json!([32, 32])
+        );
+        assert_eq!(item.assets["data"].r#type.as_deref(), Some(media_type::COG));
+    }
+
+    #[test]
+    fn projected() {
+        let item = Item::from_cog("examples/geotiff-32613.tif").unwrap();
+        if cfg!(feature = "proj") {
+            let bbox = item.bbox.unwrap();
+            assert!((bbox[0] - -105.0).abs() < 1e-3, "{:?}", bbox);
+            assert!((bbox[3] - 39.75).abs() < 1e-2, "{:?}", bbox);
+            assert!(item.geometry.is_some());
+        } else {
+            assert!(item.geometry.is_none());
+            assert!(item.bbox.is_none());
+        }
+        let fields = &item.properties.additional_fields;
+        assert_eq!(fields["proj:epsg"], 32613);
+        assert_eq!(
+            fields["proj:bbox"],
+            json!([500000.0, 4399880.0, 500120.0, 4400000.0])
+        );
+    }
+
+    #[test]
+    fn no_georeferencing() {
+        let item = Item::from_cog("examples/no-georeferencing.tif").unwrap();
+        assert!(item.geometry.is_none());
+        let fields = &item.properties.additional_fields;
+        assert!(fields.get("proj:epsg").is_none());
+        assert!(fields.get("proj:transform").is_none());
+        assert_eq!(fields["proj:shape"], json!([4, 4]));
+        assert!(item.assets.contains_key("data"));
+    }
+
+    #[test]
+    fn not_a_tiff() {
+        assert!(Item::from_cog("examples/invalid-item.json").is_err());
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{Asset, Assets, Error, Extensions, Href, Link, Links, Result, STAC_VERSION};
+use crate::{
+    datacube::{self, Datacube},
+    item::union_longitude_ranges,
+    processing::{self, Processing},
+    Asset, Assets, Bbox, Error, Extensions, Href, Item, Link, Links, Result, STAC_VERSION,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
@@ -20,7 +26,11 @@ const DEFAULT_LICENSE: &str = "proprietary";
 /// A STAC `Collection` is represented in JSON format. Any JSON object that
 /// contains all the required fields is a valid STAC `Collection` and also a valid
 /// STAC `Catalog`.
+///
+/// Like [Item](crate::Item), cloning a `Collection` is a deep copy that shares
+/// no state with the original, including its `additional_fields` and href.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Collection {
     /// A list of extension identifiers the `Collection` implements.
     #[serde(rename = "stac_extensions")]
@@ -97,6 +107,7 @@ pub struct Collection {
 /// data offered by this `Collection`. May also include information about the
 /// final storage provider hosting the data.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Provider {
     /// The name of the organization or the individual.
     pub name: String,
@@ -126,6 +137,7 @@ pub struct Provider {
 
 /// The object describes the spatio-temporal extents of the [Collection](crate::Collection).
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Extent {
     /// Spatial extents covered by the `Collection`.
     pub spatial: SpatialExtent,
@@ -137,15 +149,28 @@ pub struct Extent {
     pub additional_fields: Map<String, Value>,
 }
 
+/// The OGC identifier of WGS 84 longitude/latitude, the default CRS of a [SpatialExtent].
+pub const CRS84: &str = "http://www.opengis.net/def/crs/OGC/1.3/CRS84";
+
 /// The object describes the spatial extents of the Collection.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SpatialExtent {
     /// Potential spatial extents covered by the Collection.
     pub bbox: Vec<Vec<f64>>,
+
+    /// The coordinate reference system of the bboxes, from OGC API - Features.
+    ///
+    /// STAC extents are always [CRS84], but collections from OGC APIs may
+    /// declare another CRS. When this is `None`, the CRS is [CRS84]; see
+    /// [SpatialExtent::crs].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crs: Option<String>,
 }
 
 /// The object describes the temporal extents of the Collection.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TemporalExtent {
     /// Potential temporal extents covered by the Collection.
     pub interval: Vec<[Option<String>; 2]>,
@@ -154,13 +179,20 @@ pub struct TemporalExtent {
 impl Collection {
     /// Creates a new `Collection` with the given `id`.
     ///
+    /// The `license` is set to `"proprietary"` and the `extent` to
+    /// [Extent::default], i.e. the whole globe with an open-ended interval.
+    /// These are placeholders so the collection is valid as soon as it's
+    /// created, and callers should usually set both fields themselves.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use stac::Collection;
+    /// use stac::{Collection, Extent};
     /// let collection = Collection::new("an-id", "a description");
     /// assert_eq!(collection.id, "an-id");
     /// assert_eq!(collection.description, "a description");
+    /// assert_eq!(collection.license, "proprietary");
+    /// assert_eq!(collection.extent, Extent::default());
     /// ```
     pub fn new(id: impl ToString, description: impl ToString) -> Collection {
         Collection {
@@ -183,12 +215,175 @@ impl Collection {
     }
 }
 
+impl Collection {
+    /// Widens this collection's extent, in place, to include an item.
+    ///
+    /// The overall (first) spatial bbox is grown to include the item's bbox,
+    /// or the bbox computed from its geometry if it doesn't have one (see
+    /// [Item::compute_bbox]). Bboxes that cross the antimeridian are kept
+    /// as tight as possible, so the result may cross it too. The overall
+    /// (first) temporal interval is grown to include the item's
+    /// `start_datetime` and `end_datetime`, or its `datetime` if those aren't
+    /// set. Open-ended (null) bounds stay open. Items without a bbox, a
+    /// geometry, or datetimes leave the corresponding extent unchanged.
+    ///
+    /// The extent of a new collection (see [Extent::default]) already covers
+    /// the whole world and all time, so widening it doesn't change it. To
+    /// build an extent from items, start with [Extent::empty]: the first
+    /// item's bbox and interval become the overall bbox and interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Collection, Extent, Item};
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// collection.extent = Extent::empty();
+    /// let mut item = Item::new("an-id");
+    /// item.bbox = Some(vec![0.0, 0.0, 1.0, 1.0]);
+    /// item.properties.datetime = Some("2023-01-01T00:00:00Z".to_string());
+    /// collection.extend_extent_with_item(&item);
+    /// let mut item = Item::new("an-id");
+    /// item.bbox = Some(vec![0.5, 0.5, 2.0, 2.0]);
+    /// item.properties.datetime = Some("2023-01-03T00:00:00Z".to_string());
+    /// collection.extend_extent_with_item(&item);
+    /// assert_eq!(collection.extent.spatial.bbox[0], vec![0.0, 0.0, 2.0, 2.0]);
+    /// assert_eq!(
+    ///     collection.extent.temporal.interval[0][1].as_deref(),
+    ///     Some("2023-01-03T00:00:00Z")
+    /// );
+    /// ```
+    pub fn extend_extent_with_item(&mut self, item: &Item) {
+        if let Some(bbox) = item.bbox.clone().or_else(|| item.compute_bbox()) {
+            self.extent.spatial.extend_with_bbox(&bbox);
+        }
+        let datetime = |key: &str| {
+            item.properties
+                .additional_fields
+                .get(key)
+                .and_then(|value| value.as_str())
+                .or(item.properties.datetime.as_deref())
+        };
+        if let (Some(start), Some(end)) = (datetime("start_datetime"), datetime("end_datetime")) {
+            self.extent.temporal.extend_with_interval(start, end);
+        }
+    }
+
+    /// Merges providers into this collection's providers, combining providers with the same name.
+    ///
+    /// Providers with a new name are appended. When a provider's name is
+    /// already used, the roles of both are combined (without duplicates), a
+    /// missing or empty `description` or `url` is taken from the other
+    /// provider, and additional fields that aren't set yet are added. Providers
+    /// that already share a name in this collection are combined too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Collection, Provider};
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// let mut producer = Provider::new("a-provider");
+    /// producer.roles = Some(vec!["producer".to_string()]);
+    /// collection.providers = Some(vec![producer]);
+    /// let mut host = Provider::new("a-provider");
+    /// host.roles = Some(vec!["host".to_string()]);
+    /// collection.merge_providers(&[host]);
+    /// let providers = collection.providers.unwrap();
+    /// assert_eq!(providers.len(), 1);
+    /// assert_eq!(providers[0].roles.as_ref().unwrap(), &["producer", "host"]);
+    /// ```
+    pub fn merge_providers(&mut self, others: &[Provider]) {
+        let mut providers: Vec<Provider> = Vec::new();
+        for provider in self.providers.take().into_iter().flatten() {
+            merge_provider(&mut providers, &provider);
+        }
+        for provider in others {
+            merge_provider(&mut providers, provider);
+        }
+        if !providers.is_empty() {
+            self.providers = Some(providers);
+        }
+    }
+
+    /// Returns this collection's [processing](crate::processing) fields, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Collection;
+    /// let collection = Collection::new("an-id", "a description");
+    /// assert!(collection.processing().unwrap().is_none());
+    /// ```
+    pub fn processing(&self) -> Result<Option<Processing>> {
+        Processing::from_fields(&self.additional_fields)
+    }
+
+    /// Sets this collection's [processing](crate::processing) fields.
+    ///
+    /// The extension's schema is added to this collection's extensions. Empty
+    /// processing removes the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{processing::Processing, Collection};
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// let processing = Processing {
+    ///     facility: Some("a facility".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// collection.set_processing(processing).unwrap();
+    /// assert!(collection.processing().unwrap().is_some());
+    /// ```
+    pub fn set_processing(&mut self, processing: Processing) -> Result<()> {
+        if !processing.is_empty() {
+            self.add_extension(processing::SCHEMA_URI);
+        }
+        processing.into_fields(&mut self.additional_fields)
+    }
+
+    /// Returns this collection's [datacube](crate::datacube) fields, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Collection;
+    /// let collection: Collection = stac::read("examples/datacube-collection.json").unwrap();
+    /// let datacube = collection.datacube().unwrap().unwrap();
+    /// assert_eq!(datacube.dimensions.len(), 5);
+    /// ```
+    pub fn datacube(&self) -> Result<Option<Datacube>> {
+        Datacube::from_fields(&self.additional_fields)
+    }
+
+    /// Sets this collection's [datacube](crate::datacube) fields.
+    ///
+    /// The extension's schema is added to this collection's extensions. An
+    /// empty datacube removes the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{datacube::{Datacube, Dimension}, Collection};
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// let mut datacube = Datacube::default();
+    /// let _ = datacube.dimensions.insert("time".to_string(), Dimension::new("temporal"));
+    /// collection.set_datacube(datacube).unwrap();
+    /// assert!(collection.datacube().unwrap().is_some());
+    /// ```
+    pub fn set_datacube(&mut self, datacube: Datacube) -> Result<()> {
+        if !datacube.is_empty() {
+            self.add_extension(datacube::SCHEMA_URI);
+        }
+        datacube.into_fields(&mut self.additional_fields)
+    }
+}
+
 impl Href for Collection {
     fn href(&self) -> Option<&str> {
         self.href.as_deref()
     }
 
-    fn set_href(&mut self, href: impl ToString) {
+    fn set_href(&mut self, href: &str) {
         self.href = Some(href.to_string())
     }
 }
@@ -223,10 +418,432 @@ impl Provider {
     }
 }
 
+fn merge_provider(providers: &mut Vec<Provider>, other: &Provider) {
+    let provider = if let Some(provider) = providers.iter_mut().find(|p| p.name == other.name) {
+        provider
+    } else {
+        providers.push(other.clone());
+        return;
+    };
+    if let Some(other_roles) = other.roles.as_ref() {
+        let roles = provider.roles.get_or_insert_with(Vec::new);
+        for role in other_roles {
+            if !roles.contains(role) {
+                roles.push(role.clone());
+            }
+        }
+    }
+    for (field, other_field) in [
+        (&mut provider.description, &other.description),
+        (&mut provider.url, &other.url),
+    ] {
+        if field.as_deref().map(str::is_empty).unwrap_or(true)
+            && other_field
+                .as_deref()
+                .map(|s| !s.is_empty())
+                .unwrap_or(false)
+        {
+            field.clone_from(other_field);
+        }
+    }
+    for (key, value) in &other.additional_fields {
+        let _ = provider
+            .additional_fields
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+}
+
+impl Extent {
+    /// Creates an extent without any bboxes or intervals.
+    ///
+    /// Unlike [Extent::default], which covers the whole world and all time,
+    /// this is the starting point for building an extent from items with
+    /// [Collection::extend_extent_with_item]. An empty extent isn't valid STAC
+    /// until it has been extended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Extent;
+    /// let extent = Extent::empty();
+    /// assert!(extent.spatial.bbox.is_empty());
+    /// assert!(extent.temporal.interval.is_empty());
+    /// ```
+    pub fn empty() -> Extent {
+        Extent {
+            spatial: SpatialExtent::empty(),
+            temporal: TemporalExtent::empty(),
+            additional_fields: Map::new(),
+        }
+    }
+
+    /// Returns true if this extent could contain anything within a bbox.
+    ///
+    /// The bbox can be 2D or 3D, and can cross the antimeridian (i.e. its west
+    /// bound is greater than its east bound). Only the horizontal bounds are
+    /// compared. If the extent has more than one bbox, the first (overall) bbox
+    /// is skipped and the query intersects if it overlaps any of the others.
+    /// When in doubt, e.g. for a malformed bbox, this returns true. Both the
+    /// extent and the bbox are assumed to be in [CRS84]; see
+    /// [SpatialExtent::crs].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Extent;
+    /// let mut extent = Extent::default();
+    /// extent.spatial.bbox = vec![vec![-105.0, 40.0, -104.0, 41.0]];
+    /// assert!(extent.intersects_bbox(&[-104.5, 40.5, -103.0, 42.0]));
+    /// assert!(!extent.intersects_bbox(&[0.0, 0.0, 1.0, 1.0]));
+    /// ```
+    pub fn intersects_bbox(&self, bbox: &[f64]) -> bool {
+        let bboxes = match self.spatial.sub_bboxes() {
+            [] => &self.spatial.bbox[..],
+            sub_bboxes => sub_bboxes,
+        };
+        let query = match horizontal_bounds(bbox) {
+            Some(query) => query,
+            None => return true,
+        };
+        bboxes.is_empty()
+            || bboxes.iter().any(|bbox| {
+                horizontal_bounds(bbox)
+                    .map(|bounds| bounds_intersect(bounds, query))
+                    .unwrap_or(true)
+            })
+    }
+
+    /// Returns true if this extent could contain anything within a datetime interval.
+    ///
+    /// Datetimes are RFC 3339 strings, and `None` is an open bound. If the
+    /// extent has more than one interval, the first (overall) interval is
+    /// skipped and the query intersects if it overlaps any of the others. When
+    /// in doubt, e.g. for an unparseable datetime, this returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Extent;
+    /// let mut extent = Extent::default();
+    /// extent.temporal.interval = vec![[Some("2023-01-01T00:00:00Z".to_string()), None]];
+    /// assert!(extent.intersects_datetime(&[Some("2023-06-01T00:00:00Z".to_string()), None]));
+    /// assert!(!extent.intersects_datetime(&[None, Some("2022-12-31T00:00:00Z".to_string())]));
+    /// ```
+    pub fn intersects_datetime(&self, interval: &[Option<String>; 2]) -> bool {
+        let intervals = if self.temporal.interval.len() > 1 {
+            &self.temporal.interval[1..]
+        } else {
+            &self.temporal.interval[..]
+        };
+        let [start, end] = interval;
+        intervals.is_empty()
+            || intervals.iter().any(|[extent_start, extent_end]| {
+                !is_strictly_before(end.as_deref(), extent_start.as_deref())
+                    && !is_strictly_before(extent_end.as_deref(), start.as_deref())
+            })
+    }
+}
+
+impl SpatialExtent {
+    /// Creates a spatial extent without any bboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::SpatialExtent;
+    /// let extent = SpatialExtent::empty();
+    /// assert!(extent.overall_bbox().is_none());
+    /// ```
+    pub fn empty() -> SpatialExtent {
+        SpatialExtent {
+            bbox: Vec::new(),
+            crs: None,
+        }
+    }
+
+    /// Creates a new spatial extent with a single, overall bbox.
+    ///
+    /// The bbox can be a vector or a [Bbox](crate::Bbox).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Bbox, SpatialExtent};
+    /// let extent = SpatialExtent::from_bbox(vec![-105.0, 40.0, -104.0, 41.0]);
+    /// assert_eq!(extent.overall_bbox().unwrap(), [-105.0, 40.0, -104.0, 41.0]);
+    /// assert!(extent.sub_bboxes().is_empty());
+    /// let extent = SpatialExtent::from_bbox(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]));
+    /// assert_eq!(extent.overall_bbox().unwrap(), [-105.0, 40.0, -104.0, 41.0]);
+    /// ```
+    pub fn from_bbox(bbox: impl Into<Vec<f64>>) -> SpatialExtent {
+        SpatialExtent {
+            bbox: vec![bbox.into()],
+            crs: None,
+        }
+    }
+
+    /// Returns the coordinate reference system of the bboxes.
+    ///
+    /// Per OGC API - Features, this is [CRS84] if the extent doesn't declare a
+    /// CRS. Check it before comparing bboxes from different sources, e.g. with
+    /// [Extent::intersects_bbox], which assumes [CRS84].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{SpatialExtent, CRS84};
+    /// let mut extent = SpatialExtent::default();
+    /// assert_eq!(extent.crs(), CRS84);
+    /// extent.crs = Some("http://www.opengis.net/def/crs/EPSG/0/3857".to_string());
+    /// assert_eq!(extent.crs(), "http://www.opengis.net/def/crs/EPSG/0/3857");
+    /// ```
+    pub fn crs(&self) -> &str {
+        self.crs.as_deref().unwrap_or(CRS84)
+    }
+
+    /// Returns the overall bbox, which is the first bbox in the list.
+    ///
+    /// The spec requires the overall bbox to cover all of the others. Returns
+    /// `None` if there are no bboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::SpatialExtent;
+    /// let extent = SpatialExtent::default();
+    /// assert_eq!(extent.overall_bbox().unwrap(), [-180.0, -90.0, 180.0, 90.0]);
+    /// ```
+    pub fn overall_bbox(&self) -> Option<&[f64]> {
+        self.bbox.first().map(Vec::as_slice)
+    }
+
+    /// Returns every bbox, the overall bbox first, as [Bbox](crate::Bbox)es.
+    ///
+    /// Returns [Error::InvalidBbox] if any bbox doesn't have four or six values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::SpatialExtent;
+    /// let extent = SpatialExtent::default();
+    /// let bboxes = extent.bboxes().unwrap();
+    /// assert_eq!(bboxes[0].xmin(), -180.0);
+    /// ```
+    pub fn bboxes(&self) -> Result<Vec<Bbox>> {
+        self.bbox
+            .iter()
+            .map(|bbox| Bbox::try_from(bbox.as_slice()))
+            .collect()
+    }
+
+    /// Returns the bboxes of the sub-regions, which are every bbox after the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::SpatialExtent;
+    /// let mut extent = SpatialExtent::from_bbox(vec![-10.0, -10.0, 10.0, 10.0]);
+    /// extent.bbox.push(vec![-10.0, -10.0, -5.0, -5.0]);
+    /// assert_eq!(extent.sub_bboxes(), [vec![-10.0, -10.0, -5.0, -5.0]]);
+    /// ```
+    pub fn sub_bboxes(&self) -> &[Vec<f64>] {
+        self.bbox.get(1..).unwrap_or_default()
+    }
+
+    fn extend_with_bbox(&mut self, bbox: &[f64]) {
+        if !matches!(bbox.len(), 4 | 6) {
+            return;
+        }
+        if let Some(overall) = self.bbox.first_mut() {
+            let (overall_bounds, bounds) =
+                match (horizontal_bounds(overall), horizontal_bounds(bbox)) {
+                    (Some(overall_bounds), Some(bounds)) => (overall_bounds, bounds),
+                    _ => return,
+                };
+            let (overall_dims, dims) = (overall.len() / 2, bbox.len() / 2);
+            let mut ranges = longitudes(overall_bounds);
+            ranges.extend(longitudes(bounds));
+            if let Some((west, east)) = union_longitude_ranges(ranges) {
+                overall[0] = west;
+                overall[overall_dims] = east;
+            }
+            overall[1] = overall[1].min(bbox[1]);
+            overall[overall_dims + 1] = overall[overall_dims + 1].max(bbox[dims + 1]);
+            if overall_dims == 3 && dims == 3 {
+                overall[2] = overall[2].min(bbox[2]);
+                overall[5] = overall[5].max(bbox[5]);
+            }
+        } else {
+            self.bbox.push(bbox.to_vec());
+        }
+    }
+}
+
+impl TemporalExtent {
+    /// Creates a temporal extent without any intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::TemporalExtent;
+    /// let extent = TemporalExtent::empty();
+    /// assert!(extent.interval.is_empty());
+    /// ```
+    pub fn empty() -> TemporalExtent {
+        TemporalExtent {
+            interval: Vec::new(),
+        }
+    }
+
+    /// Creates a new temporal extent with a single, overall interval.
+    ///
+    /// A `None` start or end is an open bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use stac::TemporalExtent;
+    /// let start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    /// let extent = TemporalExtent::from_range(Some(start), None);
+    /// assert_eq!(extent.interval, [[Some("2023-01-01T00:00:00Z".to_string()), None]]);
+    /// ```
+    pub fn from_range(start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> TemporalExtent {
+        let format =
+            |datetime: DateTime<Utc>| datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        TemporalExtent {
+            interval: vec![[start.map(format), end.map(format)]],
+        }
+    }
+
+    /// Returns the start and end of the overall interval, which is the first interval in the list.
+    ///
+    /// A `None` is an open bound, as is a `null` in the interval. If there are
+    /// no intervals, both bounds are open. Returns an error if a bound isn't
+    /// an RFC 3339 datetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::TemporalExtent;
+    /// let extent = TemporalExtent {
+    ///     interval: vec![[Some("2023-01-01T00:00:00Z".to_string()), None]],
+    /// };
+    /// let (start, end) = extent.overall_interval().unwrap();
+    /// assert_eq!(start.unwrap().to_rfc3339(), "2023-01-01T00:00:00+00:00");
+    /// assert!(end.is_none());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn overall_interval(&self) -> Result<(Option<DateTime<Utc>>, Option<DateTime<Utc>>)> {
+        let parse = |bound: &Option<String>| -> Result<Option<DateTime<Utc>>> {
+            bound
+                .as_deref()
+                .map(|bound| {
+                    DateTime::parse_from_rfc3339(bound)
+                        .map(|datetime| datetime.with_timezone(&Utc))
+                        .map_err(Error::from)
+                })
+                .transpose()
+        };
+        if let Some([start, end]) = self.interval.first() {
+            Ok((parse(start)?, parse(end)?))
+        } else {
+            Ok((None, None))
+        }
+    }
+
+    fn extend_with_interval(&mut self, start: &str, end: &str) {
+        if let Some([overall_start, overall_end]) = self.interval.first_mut() {
+            if let Some(overall) = overall_start {
+                if is_before(start, overall) {
+                    *overall = start.to_string();
+                }
+            }
+            if let Some(overall) = overall_end {
+                if is_before(overall, end) {
+                    *overall = end.to_string();
+                }
+            }
+        } else {
+            self.interval
+                .push([Some(start.to_string()), Some(end.to_string())]);
+        }
+    }
+}
+
+fn is_before(a: &str, b: &str) -> bool {
+    match (
+        DateTime::parse_from_rfc3339(a),
+        DateTime::parse_from_rfc3339(b),
+    ) {
+        (Ok(a), Ok(b)) => a < b,
+        _ => false,
+    }
+}
+
+fn is_strictly_before(end: Option<&str>, start: Option<&str>) -> bool {
+    if let (Some(end), Some(start)) = (end, start) {
+        is_before(end, start)
+    } else {
+        false
+    }
+}
+
+/// Returns the west, south, east, and north bounds of a 2D or 3D bbox.
+fn horizontal_bounds(bbox: &[f64]) -> Option<[f64; 4]> {
+    match bbox.len() {
+        4 => Some([bbox[0], bbox[1], bbox[2], bbox[3]]),
+        6 => Some([bbox[0], bbox[1], bbox[3], bbox[4]]),
+        _ => None,
+    }
+}
+
+fn bounds_intersect(a: [f64; 4], b: [f64; 4]) -> bool {
+    a[1] <= b[3]
+        && b[1] <= a[3]
+        && longitudes(a).into_iter().any(|(a_west, a_east)| {
+            longitudes(b)
+                .into_iter()
+                .any(|(b_west, b_east)| a_west <= b_east && b_west <= a_east)
+        })
+}
+
+/// Returns true if the horizontal bounds of `outer` contain those of `inner`.
+///
+/// Malformed bboxes are assumed to be contained.
+pub(crate) fn bbox_contains(outer: &[f64], inner: &[f64]) -> bool {
+    let (outer, inner) = match (horizontal_bounds(outer), horizontal_bounds(inner)) {
+        (Some(outer), Some(inner)) => (outer, inner),
+        _ => return true,
+    };
+    outer[1] <= inner[1]
+        && inner[3] <= outer[3]
+        && longitudes(inner)
+            .into_iter()
+            .all(|(inner_west, inner_east)| {
+                longitudes(outer)
+                    .into_iter()
+                    .any(|(outer_west, outer_east)| {
+                        outer_west <= inner_west && inner_east <= outer_east
+                    })
+            })
+}
+
+/// Returns the longitude ranges of bounds, splitting bounds that cross the antimeridian in two.
+fn longitudes([west, _, east, _]: [f64; 4]) -> Vec<(f64, f64)> {
+    if west > east {
+        vec![(west, 180.0), (-180.0, east)]
+    } else {
+        vec![(west, east)]
+    }
+}
+
 impl Default for SpatialExtent {
     fn default() -> SpatialExtent {
         SpatialExtent {
             bbox: vec![vec![-180.0, -90.0, 180.0, 90.0]],
+            crs: None,
         }
     }
 }
@@ -252,6 +869,9 @@ impl Extensions for Collection {
     fn extensions(&self) -> Option<&[String]> {
         self.extensions.as_deref()
     }
+    fn extensions_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.extensions
+    }
 }
 
 impl TryFrom<Collection> for Map<String, Value> {
@@ -311,6 +931,27 @@ mod tests {
             assert!(collection.links.is_empty());
         }
 
+        #[test]
+        fn clone_is_independent() {
+            use crate::Href;
+
+            let mut collection = Collection::new("an-id", "a description");
+            collection.set_href("an-href");
+            let _ = collection
+                .additional_fields
+                .insert("nested".to_string(), serde_json::json!({"a": [1, 2]}));
+            let mut clone = collection.clone();
+            assert_eq!(clone, collection);
+            assert_eq!(clone.href(), Some("an-href"));
+
+            clone.set_href("another-href");
+            clone.additional_fields["nested"]["a"][0] = 42.into();
+            clone.extent.spatial.bbox[0][0] = 0.;
+            assert_eq!(collection.href(), Some("an-href"));
+            assert_eq!(collection.additional_fields["nested"]["a"][0], 1);
+            assert_eq!(collection.extent.spatial.bbox[0][0], -180.);
+        }
+
         #[test]
         fn skip_serializing() {
             let collection = Collection::new("an-id", "a description");
@@ -324,8 +965,215 @@ mod tests {
         }
     }
 
+    mod extend_extent_with_item {
+        use super::{Collection, Extent};
+        use crate::{Geometry, Item};
+
+        fn item(bbox: Vec<f64>, start: &str, end: &str) -> Item {
+            let mut item = Item::new("an-id");
+            item.bbox = Some(bbox);
+            item.properties.datetime = None;
+            let fields = &mut item.properties.additional_fields;
+            let _ = fields.insert("start_datetime".to_string(), start.into());
+            let _ = fields.insert("end_datetime".to_string(), end.into());
+            item
+        }
+
+        #[test]
+        fn widens() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent.spatial.bbox = vec![vec![0.0, 0.0, 1.0, 1.0]];
+            collection.extent.temporal.interval = vec![[
+                Some("2023-01-02T00:00:00Z".to_string()),
+                Some("2023-01-03T00:00:00Z".to_string()),
+            ]];
+            collection.extend_extent_with_item(&item(
+                vec![-1.0, 0.5, 0.5, 2.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-02T12:00:00Z",
+            ));
+            assert_eq!(collection.extent.spatial.bbox[0], vec![-1.0, 0.0, 1.0, 2.0]);
+            assert_eq!(
+                collection.extent.temporal.interval[0],
+                [
+                    Some("2023-01-01T00:00:00Z".to_string()),
+                    Some("2023-01-03T00:00:00Z".to_string())
+                ]
+            );
+        }
+
+        #[test]
+        fn empty() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent = Extent::empty();
+            collection.extend_extent_with_item(&item(
+                vec![0.0, 0.0, 1.0, 1.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-02T00:00:00Z",
+            ));
+            collection.extend_extent_with_item(&item(
+                vec![2.0, -1.0, 3.0, 0.5],
+                "2023-01-03T00:00:00Z",
+                "2023-01-04T00:00:00Z",
+            ));
+            assert_eq!(
+                collection.extent.spatial.bbox,
+                vec![vec![0.0, -1.0, 3.0, 1.0]]
+            );
+            assert_eq!(
+                collection.extent.temporal.interval,
+                vec![[
+                    Some("2023-01-01T00:00:00Z".to_string()),
+                    Some("2023-01-04T00:00:00Z".to_string())
+                ]]
+            );
+        }
+
+        #[test]
+        fn geometry_without_bbox() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent = Extent::empty();
+            let mut item = item(vec![], "2023-01-01T00:00:00Z", "2023-01-02T00:00:00Z");
+            item.bbox = None;
+            item.geometry = Some(Geometry::new(geojson::Value::Point(vec![-105.0, 40.0])));
+            collection.extend_extent_with_item(&item);
+            assert_eq!(
+                collection.extent.spatial.bbox,
+                vec![vec![-105.0, 40.0, -105.0, 40.0]]
+            );
+        }
+
+        #[test]
+        fn antimeridian() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent.spatial.bbox = vec![vec![170.0, 0.0, 175.0, 1.0]];
+            collection.extend_extent_with_item(&item(
+                vec![-175.0, 0.0, -170.0, 1.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-02T00:00:00Z",
+            ));
+            assert_eq!(
+                collection.extent.spatial.bbox[0],
+                vec![170.0, 0.0, -170.0, 1.0]
+            );
+            collection.extend_extent_with_item(&item(
+                vec![178.0, -1.0, -178.0, 2.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-02T00:00:00Z",
+            ));
+            assert_eq!(
+                collection.extent.spatial.bbox[0],
+                vec![170.0, -1.0, -170.0, 2.0]
+            );
+        }
+
+        #[test]
+        fn open_ended() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent.temporal.interval =
+                vec![[Some("2023-01-02T00:00:00Z".to_string()), None]];
+            collection.extend_extent_with_item(&item(
+                vec![0.0, 0.0, 1.0, 1.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-04T00:00:00Z",
+            ));
+            assert_eq!(
+                collection.extent.temporal.interval[0],
+                [Some("2023-01-01T00:00:00Z".to_string()), None]
+            );
+        }
+
+        #[test]
+        fn default_extent() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extend_extent_with_item(&item(
+                vec![0.0, 0.0, 1.0, 1.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-02T00:00:00Z",
+            ));
+            assert_eq!(collection.extent, Extent::default());
+        }
+
+        #[test]
+        fn empty_3d() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent = Extent::empty();
+            collection.extend_extent_with_item(&item(
+                vec![0.0, 0.0, 0.0, 1.0, 1.0, 10.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-04T00:00:00Z",
+            ));
+            assert_eq!(
+                collection.extent.spatial.bbox,
+                vec![vec![0.0, 0.0, 0.0, 1.0, 1.0, 10.0]]
+            );
+            assert_eq!(collection.extent.temporal.interval.len(), 1);
+        }
+
+        #[test]
+        fn mixed_dimensions() {
+            let mut collection = Collection::new("an-id", "a description");
+            collection.extent.spatial.bbox = vec![vec![0.0, 0.0, 1.0, 1.0]];
+            collection.extend_extent_with_item(&item(
+                vec![-1.0, -1.0, 0.0, 2.0, 2.0, 10.0],
+                "2023-01-01T00:00:00Z",
+                "2023-01-04T00:00:00Z",
+            ));
+            assert_eq!(
+                collection.extent.spatial.bbox[0],
+                vec![-1.0, -1.0, 2.0, 2.0]
+            );
+        }
+    }
+
     mod provider {
-        use super::Provider;
+        use super::{Collection, Provider};
+
+        fn provider(name: &str, roles: &[&str]) -> Provider {
+            let mut provider = Provider::new(name);
+            provider.roles = Some(roles.iter().map(|role| role.to_string()).collect());
+            provider
+        }
+
+        #[test]
+        fn merge_providers() {
+            let mut collection = Collection::new("an-id", "a description");
+            let mut producer = provider("USGS", &["producer", "licensor"]);
+            producer.url = Some(String::new());
+            collection.providers = Some(vec![producer, provider("Other", &["processor"])]);
+            let mut host = provider("USGS", &["host", "producer"]);
+            host.description = Some("Hosts the data".to_string());
+            host.url = Some("https://usgs.gov".to_string());
+            collection.merge_providers(&[host, provider("AWS", &["host"])]);
+
+            let providers = collection.providers.unwrap();
+            let names: Vec<_> = providers.iter().map(|p| p.name.as_str()).collect();
+            assert_eq!(names, ["USGS", "Other", "AWS"]);
+            assert_eq!(
+                providers[0].roles.as_ref().unwrap(),
+                &["producer", "licensor", "host"]
+            );
+            assert_eq!(providers[0].description.as_deref(), Some("Hosts the data"));
+            assert_eq!(providers[0].url.as_deref(), Some("https://usgs.gov"));
+        }
+
+        #[test]
+        fn merge_providers_keeps_existing_fields() {
+            let mut collection = Collection::new("an-id", "a description");
+            let mut a = Provider::new("a");
+            a.url = Some("https://a.test".to_string());
+            collection.providers = Some(vec![a.clone(), a.clone()]);
+            a.url = Some("https://b.test".to_string());
+            collection.merge_providers(&[a]);
+            let providers = collection.providers.unwrap();
+            assert_eq!(providers.len(), 1);
+            assert_eq!(providers[0].url.as_deref(), Some("https://a.test"));
+            assert!(providers[0].roles.is_none());
+
+            let mut collection = Collection::new("an-id", "a description");
+            collection.merge_providers(&[]);
+            assert!(collection.providers.is_none());
+        }
 
         #[test]
         fn new() {
@@ -347,6 +1195,121 @@ mod tests {
         }
     }
 
+    mod spatial_extent {
+        use crate::{SpatialExtent, CRS84};
+        use serde_json::json;
+
+        #[test]
+        fn crs() {
+            let extent: SpatialExtent =
+                serde_json::from_value(json!({"bbox": [[0.0, 0.0, 1.0, 1.0]]})).unwrap();
+            assert!(extent.crs.is_none());
+            assert_eq!(extent.crs(), CRS84);
+            assert!(serde_json::to_value(&extent).unwrap().get("crs").is_none());
+
+            let value = json!({
+                "bbox": [[500000.0, 4400000.0, 600000.0, 4500000.0]],
+                "crs": "http://www.opengis.net/def/crs/EPSG/0/32613"
+            });
+            let extent: SpatialExtent = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(extent.crs(), "http://www.opengis.net/def/crs/EPSG/0/32613");
+            assert_eq!(serde_json::to_value(extent).unwrap(), value);
+        }
+
+        #[test]
+        fn from_bbox() {
+            let extent = SpatialExtent::from_bbox(vec![-105.0, 40.0, 0.0, -104.0, 41.0, 10.0]);
+            assert_eq!(extent.bbox.len(), 1);
+            assert_eq!(
+                extent.overall_bbox().unwrap(),
+                [-105.0, 40.0, 0.0, -104.0, 41.0, 10.0]
+            );
+        }
+
+        #[test]
+        fn overall_and_sub_bboxes() {
+            let mut extent = SpatialExtent {
+                bbox: Vec::new(),
+                crs: None,
+            };
+            assert!(extent.overall_bbox().is_none());
+            assert!(extent.sub_bboxes().is_empty());
+            extent.bbox = vec![
+                vec![-10.0, -10.0, 10.0, 10.0],
+                vec![-10.0, -10.0, -5.0, -5.0],
+                vec![5.0, 5.0, 10.0, 10.0],
+            ];
+            assert_eq!(extent.overall_bbox().unwrap(), [-10.0, -10.0, 10.0, 10.0]);
+            assert_eq!(
+                extent.sub_bboxes(),
+                [vec![-10.0, -10.0, -5.0, -5.0], vec![5.0, 5.0, 10.0, 10.0]]
+            );
+        }
+    }
+
+    mod temporal_extent {
+        use crate::TemporalExtent;
+        use chrono::{DateTime, TimeZone, Utc};
+
+        fn datetime(year: i32) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap()
+        }
+
+        #[test]
+        fn fully_open() {
+            let extent = TemporalExtent::from_range(None, None);
+            assert_eq!(extent.interval, [[None, None]]);
+            assert_eq!(extent.overall_interval().unwrap(), (None, None));
+            let extent = TemporalExtent {
+                interval: Vec::new(),
+            };
+            assert_eq!(extent.overall_interval().unwrap(), (None, None));
+        }
+
+        #[test]
+        fn half_open() {
+            let extent = TemporalExtent::from_range(Some(datetime(2020)), None);
+            assert_eq!(
+                extent.overall_interval().unwrap(),
+                (Some(datetime(2020)), None)
+            );
+            let extent = TemporalExtent::from_range(None, Some(datetime(2020)));
+            assert_eq!(
+                extent.overall_interval().unwrap(),
+                (None, Some(datetime(2020)))
+            );
+        }
+
+        #[test]
+        fn closed() {
+            let extent = TemporalExtent::from_range(Some(datetime(2020)), Some(datetime(2021)));
+            assert_eq!(
+                extent.interval,
+                [[
+                    Some("2020-01-01T00:00:00Z".to_string()),
+                    Some("2021-01-01T00:00:00Z".to_string())
+                ]]
+            );
+            assert_eq!(
+                extent.overall_interval().unwrap(),
+                (Some(datetime(2020)), Some(datetime(2021)))
+            );
+        }
+
+        #[test]
+        fn offset_and_invalid() {
+            let mut extent = TemporalExtent {
+                interval: vec![[Some("2019-12-31T19:00:00-05:00".to_string()), None]],
+            };
+            assert_eq!(
+                extent.overall_interval().unwrap(),
+                (Some(datetime(2020)), None)
+            );
+            extent.interval[0][1] = Some("not a datetime".to_string());
+            assert!(extent.overall_interval().is_err());
+        }
+    }
+
     mod extent {
         use super::Extent;
 
@@ -357,11 +1320,62 @@ mod tests {
             assert_eq!(extent.temporal.interval, [[None, None]]);
             assert!(extent.additional_fields.is_empty());
         }
+
+        #[test]
+        fn intersects_bbox() {
+            let mut extent = Extent::default();
+            assert!(extent.intersects_bbox(&[0.0, 0.0, 1.0, 1.0]));
+            extent.spatial.bbox = vec![
+                vec![-10.0, -10.0, 10.0, 10.0],
+                vec![-10.0, -10.0, -5.0, -5.0],
+                vec![5.0, 5.0, 10.0, 10.0],
+            ];
+            assert!(extent.intersects_bbox(&[6.0, 6.0, 7.0, 7.0]));
+            assert!(extent.intersects_bbox(&[-6.0, -6.0, 0.0, 0.0, 0.0, 0.0]));
+            assert!(!extent.intersects_bbox(&[0.0, 0.0, 1.0, 1.0]));
+            assert!(extent.intersects_bbox(&[0.0, 0.0]));
+        }
+
+        #[test]
+        fn intersects_bbox_antimeridian() {
+            let mut extent = Extent::default();
+            extent.spatial.bbox = vec![vec![170.0, 0.0, -170.0, 10.0]];
+            assert!(extent.intersects_bbox(&[175.0, 5.0, 176.0, 6.0]));
+            assert!(extent.intersects_bbox(&[-175.0, 5.0, -174.0, 6.0]));
+            assert!(!extent.intersects_bbox(&[0.0, 5.0, 1.0, 6.0]));
+            assert!(extent.intersects_bbox(&[179.0, 5.0, -179.0, 6.0]));
+        }
+
+        #[test]
+        fn intersects_datetime() {
+            let datetime = |s: &str| Some(s.to_string());
+            let mut extent = Extent::default();
+            assert!(extent.intersects_datetime(&[datetime("2023-01-01T00:00:00Z"), None]));
+            extent.temporal.interval = vec![
+                [datetime("2020-01-01T00:00:00Z"), None],
+                [
+                    datetime("2020-01-01T00:00:00Z"),
+                    datetime("2020-12-31T00:00:00Z"),
+                ],
+                [datetime("2022-01-01T00:00:00Z"), None],
+            ];
+            assert!(extent.intersects_datetime(&[
+                datetime("2020-06-01T00:00:00Z"),
+                datetime("2020-07-01T00:00:00Z")
+            ]));
+            assert!(!extent.intersects_datetime(&[
+                datetime("2021-01-01T00:00:00Z"),
+                datetime("2021-12-31T00:00:00Z")
+            ]));
+            assert!(extent.intersects_datetime(&[datetime("2021-01-01T00:00:00Z"), None]));
+            assert!(extent.intersects_datetime(&[None, datetime("2020-01-01T00:00:00Z")]));
+            assert!(!extent.intersects_datetime(&[None, datetime("2019-01-01T00:00:00Z")]));
+        }
     }
 
     mod roundtrip {
         use super::Collection;
-        use crate::tests::roundtrip;
+        use crate::tests::{roundtrip, roundtrip_bytes};
 
         roundtrip!(collection, "data/collection.json", Collection);
         roundtrip!(
@@ -379,5 +1393,10 @@ mod tests {
             "data/extensions-collection/collection.json",
             Collection
         );
+        roundtrip_bytes!(
+            extra_fields,
+            "examples/extra-fields-collection.json",
+            Collection
+        );
     }
 }
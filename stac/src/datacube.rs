@@ -0,0 +1,251 @@
+//! The [datacube extension](https://github.com/stac-extensions/datacube).
+//!
+//! A datacube describes gridded data, e.g. a Zarr store or a netCDF file, as a
+//! set of named dimensions (`cube:dimensions`) and the variables that are
+//! defined over them (`cube:variables`). The fields can be set in an item's
+//! [Properties](crate::Properties), on a [Collection](crate::Collection), or
+//! on an [Asset](crate::Asset). Dimensions and variables keep their order.
+//!
+//! # Examples
+//!
+//! ```
+//! use stac::Collection;
+//! let collection: Collection = stac::read("examples/datacube-collection.json").unwrap();
+//! let datacube = collection.datacube().unwrap().unwrap();
+//! let names: Vec<_> = datacube.dimensions.keys().collect();
+//! assert_eq!(names, ["x", "y", "time", "pressure", "spectral"]);
+//! assert_eq!(datacube.dimensions["pressure"].unit.as_deref(), Some("hPa"));
+//! ```
+
+use crate::Result;
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+/// The schema URI of the datacube extension.
+pub const SCHEMA_URI: &str = "https://stac-extensions.github.io/datacube/v2.2.0/schema.json";
+
+const FIELDS: [&str; 2] = ["cube:dimensions", "cube:variables"];
+
+/// The datacube fields of an item, a collection, or an asset.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Datacube {
+    /// The dimensions of the datacube, keyed by name, in order.
+    #[serde(
+        rename = "cube:dimensions",
+        default,
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub dimensions: IndexMap<String, Dimension>,
+
+    /// The variables of the datacube, keyed by name, in order.
+    #[serde(rename = "cube:variables", skip_serializing_if = "Option::is_none")]
+    pub variables: Option<IndexMap<String, Variable>>,
+}
+
+/// A dimension of a datacube.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Dimension {
+    /// The type of the dimension: `spatial`, `temporal`, or any other type, e.g. `bands`.
+    pub r#type: String,
+
+    /// The axis of a spatial dimension, `x`, `y`, or `z`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub axis: Option<String>,
+
+    /// A description of the dimension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The lower and upper bounds of the dimension, either of which can be `null`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extent: Option<Vec<Value>>,
+
+    /// The values of the dimension, if they're enumerated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Value>>,
+
+    /// The spacing between values of the dimension.
+    ///
+    /// An explicit `null` (i.e. `Some(Value::Null)`) means the values are irregularly spaced.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub step: Option<Value>,
+
+    /// The reference system of the dimension, e.g. an EPSG code for a spatial dimension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_system: Option<Value>,
+
+    /// The unit of the dimension's values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+
+    /// Additional fields on the dimension.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+/// A variable of a datacube.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Variable {
+    /// The names of the dimensions that the variable is defined over, in order.
+    pub dimensions: Vec<String>,
+
+    /// The type of the variable, `data` or `auxiliary`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// A description of the variable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The lower and upper bounds of the variable's values, either of which can be `null`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extent: Option<Vec<Value>>,
+
+    /// The values of the variable, if they're enumerated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Value>>,
+
+    /// The unit of the variable's values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+
+    /// Additional fields on the variable.
+    #[serde(flatten)]
+    pub additional_fields: Map<String, Value>,
+}
+
+impl Datacube {
+    /// Returns true if there are no dimensions or variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::datacube::Datacube;
+    /// assert!(Datacube::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.dimensions.is_empty() && self.variables.is_none()
+    }
+
+    pub(crate) fn from_fields(fields: &Map<String, Value>) -> Result<Option<Datacube>> {
+        crate::extensions::extract_fields(fields, &FIELDS)
+    }
+
+    pub(crate) fn into_fields(self, fields: &mut Map<String, Value>) -> Result<()> {
+        crate::extensions::replace_fields(fields, &FIELDS, self)
+    }
+}
+
+impl Dimension {
+    /// Creates a new dimension of a type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::datacube::Dimension;
+    /// let dimension = Dimension::new("temporal");
+    /// assert_eq!(dimension.r#type, "temporal");
+    /// ```
+    pub fn new(r#type: impl ToString) -> Dimension {
+        Dimension {
+            r#type: r#type.to_string(),
+            axis: None,
+            description: None,
+            extent: None,
+            values: None,
+            step: None,
+            reference_system: None,
+            unit: None,
+            additional_fields: Map::new(),
+        }
+    }
+}
+
+fn deserialize_some<'de, D>(deserializer: D) -> std::result::Result<Option<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Datacube, Dimension, SCHEMA_URI};
+    use crate::{Asset, Collection, Extensions, Item};
+    use serde_json::Value;
+
+    #[test]
+    fn read() {
+        let collection: Collection = crate::read("examples/datacube-collection.json").unwrap();
+        let datacube = collection.datacube().unwrap().unwrap();
+        let x = &datacube.dimensions["x"];
+        assert_eq!(x.axis.as_deref(), Some("x"));
+        assert_eq!(x.step.as_ref().unwrap(), 0.25);
+        assert_eq!(x.reference_system.as_ref().unwrap(), 4326);
+        assert_eq!(datacube.dimensions["time"].step, Some(Value::Null));
+        assert_eq!(
+            datacube.dimensions["time"].values.as_ref().unwrap().len(),
+            3
+        );
+        assert!(datacube.dimensions["spectral"].step.is_none());
+        let variables = datacube.variables.unwrap();
+        let names: Vec<_> = variables.keys().collect();
+        assert_eq!(names, ["temperature", "crs"]);
+        assert_eq!(
+            variables["temperature"].dimensions,
+            ["time", "y", "x", "pressure"]
+        );
+        assert!(variables["crs"]
+            .additional_fields
+            .contains_key("custom:wkt"));
+    }
+
+    #[test]
+    fn none() {
+        assert!(Asset::new("an-href").datacube().unwrap().is_none());
+        assert!(Item::new("an-id").datacube().unwrap().is_none());
+        assert!(Collection::new("an-id", "a description")
+            .datacube()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn set_preserves_order() {
+        let mut item = Item::new("an-id");
+        let mut datacube = Datacube::default();
+        for (name, r#type) in [("y", "spatial"), ("x", "spatial"), ("band", "bands")] {
+            let _ = datacube
+                .dimensions
+                .insert(name.to_string(), Dimension::new(r#type));
+        }
+        item.set_datacube(datacube.clone()).unwrap();
+        assert!(item.has_extension(SCHEMA_URI));
+        let json = serde_json::to_string(&item).unwrap();
+        let (y, x, band) = (
+            json.find("\"y\"").unwrap(),
+            json.find("\"x\"").unwrap(),
+            json.find("\"band\"").unwrap(),
+        );
+        assert!(y < x && x < band);
+        let item: Item = serde_json::from_str(&json).unwrap();
+        assert_eq!(item.datacube().unwrap().unwrap(), datacube);
+        let mut item = item;
+        item.set_datacube(Datacube::default()).unwrap();
+        assert!(item.datacube().unwrap().is_none());
+    }
+
+    crate::tests::roundtrip!(
+        datacube_collection,
+        "examples/datacube-collection.json",
+        Collection
+    );
+}
@@ -5,10 +5,31 @@ use thiserror::Error;
 /// Error enum for crate-specific errors.
 #[derive(Error, Debug)]
 pub enum Error {
+    /// Returned when an item's bbox doesn't match the bounds of its geometry.
+    #[error("bbox {bbox:?} does not match the bounds of the geometry: {bounds:?}")]
+    BboxMismatch {
+        /// The item's bbox.
+        bbox: Vec<f64>,
+        /// The bounds of the geometry, as `[xmin, ymin, xmax, ymax]`.
+        bounds: Vec<f64>,
+    },
+
+    /// Returned when an item doesn't fit within its collection's extent or summaries.
+    #[error("item does not match collection: {}", .0.join("; "))]
+    CollectionMismatch(Vec<String>),
+
+    /// [chrono::ParseError]
+    #[error(transparent)]
+    ChronoParse(#[from] chrono::ParseError),
+
     /// [std::io::Error]
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
+    /// Returned when a bbox doesn't have four or six values.
+    #[error("invalid bbox, expected 4 or 6 values: {0:?}")]
+    InvalidBbox(Vec<f64>),
+
     /// Returned when the `type` field of a STAC object is not a [String].
     #[error("invalid \"type\" field: {0}")]
     InvalidTypeField(JsonValue),
@@ -30,6 +51,34 @@ pub enum Error {
     #[error("object has no href")]
     MissingHref,
 
+    /// Returned when a field is expected on an object, but it isn't there.
+    #[error("missing field: {0}")]
+    MissingField(String),
+
+    /// Returned when an item doesn't have an asset with the given key.
+    #[error("no asset with key: {0}")]
+    MissingAsset(String),
+
+    /// Returned when a link's `method` isn't a valid HTTP method.
+    #[error("invalid HTTP method: {0}")]
+    InvalidMethod(String),
+
+    /// Returned when a link's href isn't a valid URI template.
+    #[error("invalid URI template: {0}")]
+    InvalidTemplate(String),
+
+    /// Returned when a link's URI template has a placeholder without a value.
+    #[error("no value for URI template parameter: {0}")]
+    MissingTemplateParameter(String),
+
+    /// Returned when a streamed item collection isn't a JSON object with a `features` array.
+    #[error("invalid item collection stream: {0}")]
+    InvalidItemStream(String),
+
+    /// Returned when reading a directory that contains neither a `catalog.json` nor a `collection.json`.
+    #[error("no catalog.json or collection.json in directory: {0}")]
+    NoCatalogInDirectory(String),
+
     /// This value is not an item.
     #[error("value is not an item")]
     NotAnItem(Value),
@@ -42,6 +91,11 @@ pub enum Error {
     #[error("value is not a collection")]
     NotACollection(Value),
 
+    /// [proj4rs::errors::Error]
+    #[cfg(feature = "proj")]
+    #[error(transparent)]
+    Proj(#[from] proj4rs::errors::Error),
+
     /// Returned when trying to read from a url but the `reqwest` feature is not enabled.
     #[error("reqwest is not enabled")]
     ReqwestNotEnabled,
@@ -60,10 +114,25 @@ pub enum Error {
     #[error(transparent)]
     SerdeUrlencodedSer(#[from] serde_urlencoded::ser::Error),
 
+    /// [serde_yaml::Error]
+    #[cfg(feature = "yaml")]
+    #[error(transparent)]
+    SerdeYaml(#[from] serde_yaml::Error),
+
+    /// [tiff::TiffError]
+    #[cfg(feature = "geotiff")]
+    #[error(transparent)]
+    Tiff(#[from] tiff::TiffError),
+
     /// Returned when the `type` field of a STAC object does not equal `"Feature"`, `"Catalog"`, or `"Collection"`.
     #[error("unknown \"type\": {0}")]
     UnknownType(String),
 
+    /// Returned when there's no projection definition for an EPSG code.
+    #[cfg(feature = "proj")]
+    #[error("unsupported EPSG code: {0}")]
+    UnsupportedEpsg(i64),
+
     /// [url::ParseError]
     #[error(transparent)]
     Url(#[from] url::ParseError),
@@ -72,4 +141,220 @@ pub enum Error {
     #[cfg(feature = "jsonschema")]
     #[error(transparent)]
     ValidationError(#[from] jsonschema::ValidationError<'static>),
+
+    /// [zip::result::ZipError]
+    #[cfg(feature = "zip")]
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+}
+
+impl Error {
+    /// Returns true if this error means that a file or url was not found.
+    ///
+    /// This is a missing file (including a missing entry in a zip archive)
+    /// or an HTTP 404 or 410.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let error = stac::read::<stac::Item>("not-a-file.json").unwrap_err();
+    /// assert!(error.is_not_found());
+    /// ```
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::Io(err) => err.kind() == std::io::ErrorKind::NotFound,
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(err) => matches!(
+                err.status(),
+                Some(reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE)
+            ),
+            #[cfg(feature = "zip")]
+            Error::Zip(err) => matches!(err, zip::result::ZipError::FileNotFound),
+            _ => false,
+        }
+    }
+
+    /// Returns a process exit code for this error, for command-line tools.
+    ///
+    /// The codes are stable:
+    ///
+    /// | Code | Meaning |
+    /// | ---- | ------- |
+    /// | 1 | Any other error, e.g. an I/O error or a feature that isn't enabled |
+    /// | 2 | A file, url, or directory entry was [not found](Error::is_not_found) |
+    /// | 3 | A STAC object is invalid, e.g. it failed JSON Schema validation or its bbox doesn't match its geometry |
+    /// | 4 | The input couldn't be parsed or isn't the expected kind of STAC object |
+    /// | 5 | A network request failed |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let error = stac::read::<stac::Item>("not-a-file.json").unwrap_err();
+    /// assert_eq!(error.exit_code(), 2);
+    /// ```
+    pub fn exit_code(&self) -> i32 {
+        if self.is_not_found() {
+            return 2;
+        }
+        match self {
+            Error::NoCatalogInDirectory(_) => 2,
+            Error::BboxMismatch { .. } | Error::CollectionMismatch(_) => 3,
+            #[cfg(feature = "jsonschema")]
+            Error::ValidationError(_) => 3,
+            Error::ChronoParse(_)
+            | Error::InvalidBbox(_)
+            | Error::InvalidTypeField(_)
+            | Error::IncorrectType { .. }
+            | Error::MissingType
+            | Error::MissingField(_)
+            | Error::MissingAsset(_)
+            | Error::InvalidMethod(_)
+            | Error::InvalidItemStream(_)
+            | Error::InvalidTemplate(_)
+            | Error::MissingTemplateParameter(_)
+            | Error::NotAnItem(_)
+            | Error::NotACatalog(_)
+            | Error::NotACollection(_)
+            | Error::SerdeJson(_)
+            | Error::UnknownType(_)
+            | Error::Url(_) => 4,
+            #[cfg(feature = "proj")]
+            Error::UnsupportedEpsg(_) => 4,
+            #[cfg(feature = "geotiff")]
+            Error::Tiff(_) => 4,
+            #[cfg(feature = "yaml")]
+            Error::SerdeYaml(_) => 4,
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(_) => 5,
+            _ => 1,
+        }
+    }
+
+    /// Returns true if this error might go away if the operation is retried.
+    ///
+    /// This is a timeout, an interrupted operation, or an HTTP 5xx.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let error = stac::read::<stac::Item>("not-a-file.json").unwrap_err();
+    /// assert!(!error.is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Io(err) => matches!(
+                err.kind(),
+                std::io::ErrorKind::TimedOut | std::io::ErrorKind::Interrupted
+            ),
+            #[cfg(feature = "reqwest")]
+            Error::Reqwest(err) => {
+                err.is_timeout()
+                    || err
+                        .status()
+                        .map(|status| status.is_server_error())
+                        .unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<Error> for std::io::Error {
+    /// Converts this error into an I/O error.
+    ///
+    /// An [Error::Io] is unwrapped. Other errors are wrapped, with a kind of
+    /// [NotFound](std::io::ErrorKind::NotFound) if the error
+    /// [is not found](Error::is_not_found),
+    /// [InvalidData](std::io::ErrorKind::InvalidData) if the error's
+    /// [exit code](Error::exit_code) is 3 or 4, and
+    /// [Other](std::io::ErrorKind::Other) otherwise.
+    fn from(error: Error) -> std::io::Error {
+        let kind = match error {
+            Error::Io(error) => return error,
+            _ if error.is_not_found() => std::io::ErrorKind::NotFound,
+            _ => match error.exit_code() {
+                3 | 4 => std::io::ErrorKind::InvalidData,
+                _ => std::io::ErrorKind::Other,
+            },
+        };
+        std::io::Error::new(kind, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn is_not_found() {
+        assert!(Error::from(std::io::Error::from(ErrorKind::NotFound)).is_not_found());
+        assert!(!Error::from(std::io::Error::from(ErrorKind::PermissionDenied)).is_not_found());
+        assert!(!Error::MissingHref.is_not_found());
+    }
+
+    #[test]
+    fn exit_code() {
+        assert_eq!(
+            Error::from(std::io::Error::from(ErrorKind::NotFound)).exit_code(),
+            2
+        );
+        assert_eq!(
+            Error::from(std::io::Error::from(ErrorKind::PermissionDenied)).exit_code(),
+            1
+        );
+        assert_eq!(
+            Error::NoCatalogInDirectory("a-directory".to_string()).exit_code(),
+            2
+        );
+        assert_eq!(
+            Error::CollectionMismatch(vec!["a problem".to_string()]).exit_code(),
+            3
+        );
+        assert_eq!(Error::MissingType.exit_code(), 4);
+        assert_eq!(
+            Error::from(serde_json::from_str::<serde_json::Value>("{").unwrap_err()).exit_code(),
+            4
+        );
+        assert_eq!(Error::ReqwestNotEnabled.exit_code(), 1);
+        assert_eq!(Error::MissingHref.exit_code(), 1);
+    }
+
+    #[test]
+    fn into_io_error() {
+        let error = std::io::Error::from(Error::from(std::io::Error::new(
+            ErrorKind::PermissionDenied,
+            "nope",
+        )));
+        assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(error.to_string(), "nope");
+
+        let error = std::io::Error::from(Error::MissingType);
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert!(error
+            .into_inner()
+            .unwrap()
+            .downcast_ref::<Error>()
+            .is_some());
+
+        let error = std::io::Error::from(Error::MissingHref);
+        assert_eq!(error.kind(), ErrorKind::Other);
+    }
+
+    #[test]
+    fn is_transient() {
+        assert!(Error::from(std::io::Error::from(ErrorKind::TimedOut)).is_transient());
+        assert!(!Error::from(std::io::Error::from(ErrorKind::NotFound)).is_transient());
+        assert!(!Error::MissingHref.is_transient());
+    }
+
+    #[test]
+    fn transparent() {
+        use std::error::Error as _;
+
+        let error = Error::from(std::io::Error::new(ErrorKind::NotFound, "a message"));
+        assert_eq!(error.to_string(), "a message");
+        assert!(error.source().is_none());
+        assert!(matches!(error, Error::Io(error) if error.kind() == ErrorKind::NotFound));
+    }
 }
@@ -1,3 +1,208 @@
+use crate::{Error, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+/// The latest known schema URIs for common STAC extensions, keyed by their prefix.
+///
+/// The prefix is everything before the version segment of the URI. Use these
+/// with [Extensions::upgrade_extension] to bring an object up to date.
+///
+/// # Examples
+///
+/// ```
+/// let (prefix, uri) = stac::LATEST_EXTENSIONS
+///     .iter()
+///     .find(|(prefix, _)| prefix.ends_with("/eo/"))
+///     .unwrap();
+/// assert!(uri.starts_with(prefix));
+/// ```
+pub const LATEST_EXTENSIONS: [(&str, &str); 16] = [
+    (
+        "https://stac-extensions.github.io/alternate-assets/",
+        "https://stac-extensions.github.io/alternate-assets/v1.1.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/datacube/",
+        "https://stac-extensions.github.io/datacube/v2.2.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/eo/",
+        "https://stac-extensions.github.io/eo/v1.1.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/file/",
+        "https://stac-extensions.github.io/file/v2.1.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/item-assets/",
+        "https://stac-extensions.github.io/item-assets/v1.0.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/label/",
+        "https://stac-extensions.github.io/label/v1.0.1/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/processing/",
+        "https://stac-extensions.github.io/processing/v1.1.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/projection/",
+        "https://stac-extensions.github.io/projection/v2.0.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/raster/",
+        "https://stac-extensions.github.io/raster/v1.1.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/sar/",
+        "https://stac-extensions.github.io/sar/v1.0.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/sat/",
+        "https://stac-extensions.github.io/sat/v1.0.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/scientific/",
+        "https://stac-extensions.github.io/scientific/v1.0.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/table/",
+        "https://stac-extensions.github.io/table/v1.2.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/timestamps/",
+        "https://stac-extensions.github.io/timestamps/v1.1.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/version/",
+        "https://stac-extensions.github.io/version/v1.2.0/schema.json",
+    ),
+    (
+        "https://stac-extensions.github.io/view/",
+        "https://stac-extensions.github.io/view/v1.0.0/schema.json",
+    ),
+];
+
+/// A problem with an extension field, found by [Extensions::validate_extension_fields].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// A [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) to the field.
+    pub pointer: String,
+
+    /// A human-readable description of the problem.
+    pub message: String,
+}
+
+/// The expected JSON type of a well-known extension field.
+#[derive(Clone, Copy)]
+enum FieldType {
+    Number { minimum: f64, maximum: f64 },
+    NullableInteger,
+    NullableString,
+    NullableObject,
+    Numbers(&'static [usize]),
+    Integers(usize),
+    Objects,
+}
+
+const PROJECTION_PREFIX: &str = "https://stac-extensions.github.io/projection/";
+
+/// The latest projection extension schema URI with `proj:epsg`, which v2.0.0 replaced with `proj:code`.
+pub(crate) const PROJECTION_EPSG_EXTENSION: &str =
+    "https://stac-extensions.github.io/projection/v1.1.0/schema.json";
+const EO_PREFIX: &str = "https://stac-extensions.github.io/eo/";
+const VIEW_PREFIX: &str = "https://stac-extensions.github.io/view/";
+const RASTER_PREFIX: &str = "https://stac-extensions.github.io/raster/";
+const SAR_PREFIX: &str = "https://stac-extensions.github.io/sar/";
+const SCIENTIFIC_PREFIX: &str = "https://stac-extensions.github.io/scientific/";
+
+/// Field name prefixes that identify an extension, and that extension's prefix.
+const FIELD_PREFIXES: [(&str, &str); 6] = [
+    ("eo:", EO_PREFIX),
+    ("proj:", PROJECTION_PREFIX),
+    ("view:", VIEW_PREFIX),
+    ("raster:", RASTER_PREFIX),
+    ("sar:", SAR_PREFIX),
+    ("sci:", SCIENTIFIC_PREFIX),
+];
+
+/// Well-known extension fields and their types, keyed by extension prefix.
+const EXTENSION_FIELDS: [(&str, &str, FieldType); 16] = [
+    (
+        EO_PREFIX,
+        "eo:cloud_cover",
+        FieldType::Number {
+            minimum: 0.,
+            maximum: 100.,
+        },
+    ),
+    (
+        EO_PREFIX,
+        "eo:snow_cover",
+        FieldType::Number {
+            minimum: 0.,
+            maximum: 100.,
+        },
+    ),
+    (EO_PREFIX, "eo:bands", FieldType::Objects),
+    (PROJECTION_PREFIX, "proj:epsg", FieldType::NullableInteger),
+    (PROJECTION_PREFIX, "proj:code", FieldType::NullableString),
+    (PROJECTION_PREFIX, "proj:wkt2", FieldType::NullableString),
+    (
+        PROJECTION_PREFIX,
+        "proj:projjson",
+        FieldType::NullableObject,
+    ),
+    (PROJECTION_PREFIX, "proj:bbox", FieldType::Numbers(&[4, 6])),
+    (PROJECTION_PREFIX, "proj:shape", FieldType::Integers(2)),
+    (
+        PROJECTION_PREFIX,
+        "proj:transform",
+        FieldType::Numbers(&[6, 9]),
+    ),
+    (
+        VIEW_PREFIX,
+        "view:off_nadir",
+        FieldType::Number {
+            minimum: 0.,
+            maximum: 90.,
+        },
+    ),
+    (
+        VIEW_PREFIX,
+        "view:incidence_angle",
+        FieldType::Number {
+            minimum: 0.,
+            maximum: 90.,
+        },
+    ),
+    (
+        VIEW_PREFIX,
+        "view:azimuth",
+        FieldType::Number {
+            minimum: 0.,
+            maximum: 360.,
+        },
+    ),
+    (
+        VIEW_PREFIX,
+        "view:sun_azimuth",
+        FieldType::Number {
+            minimum: 0.,
+            maximum: 360.,
+        },
+    ),
+    (
+        VIEW_PREFIX,
+        "view:sun_elevation",
+        FieldType::Number {
+            minimum: -90.,
+            maximum: 90.,
+        },
+    ),
+    (RASTER_PREFIX, "raster:bands", FieldType::Objects),
+];
+
 /// A trait for objects that may have STAC extensions.
 pub trait Extensions {
     /// Returns a reference to this object's extensions.
@@ -10,4 +215,531 @@ pub trait Extensions {
     /// assert!(item.extensions().is_none());
     /// ```
     fn extensions(&self) -> Option<&[String]>;
+
+    /// Returns a mutable reference to this object's extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// *item.extensions_mut() = Some(vec!["an-extension".to_string()]);
+    /// ```
+    fn extensions_mut(&mut self) -> &mut Option<Vec<String>>;
+
+    /// Returns true if this object has an extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// assert!(!item.has_extension("an-extension"));
+    /// item.add_extension("an-extension");
+    /// assert!(item.has_extension("an-extension"));
+    /// ```
+    fn has_extension(&self, uri: &str) -> bool {
+        self.extensions()
+            .map(|extensions| extensions.iter().any(|extension| extension == uri))
+            .unwrap_or(false)
+    }
+
+    /// Adds an extension to this object, if it doesn't already have it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.add_extension("an-extension");
+    /// item.add_extension("an-extension");
+    /// assert_eq!(item.extensions().unwrap().len(), 1);
+    /// ```
+    fn add_extension(&mut self, uri: impl ToString)
+    where
+        Self: Sized,
+    {
+        let uri = uri.to_string();
+        if !self.has_extension(&uri) {
+            self.extensions_mut().get_or_insert_with(Vec::new).push(uri);
+        }
+    }
+
+    /// Swaps the version of every extension URI that starts with `extension_prefix`.
+    ///
+    /// The prefix is everything before the version segment, e.g.
+    /// `https://stac-extensions.github.io/eo/`, and `new_version` replaces
+    /// that segment, e.g. `v1.1.0`. Returns true if any extension was upgraded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.extensions = Some(vec!["https://stac-extensions.github.io/eo/v1.0.0/schema.json".to_string()]);
+    /// assert!(item.upgrade_extension("https://stac-extensions.github.io/eo/", "v1.1.0"));
+    /// assert_eq!(
+    ///     item.extensions.unwrap()[0],
+    ///     "https://stac-extensions.github.io/eo/v1.1.0/schema.json"
+    /// );
+    /// ```
+    fn upgrade_extension(&mut self, extension_prefix: &str, new_version: &str) -> bool {
+        let extension_prefix = if extension_prefix.ends_with('/') {
+            extension_prefix.to_string()
+        } else {
+            format!("{}/", extension_prefix)
+        };
+        let mut upgraded = false;
+        for extension in self.extensions_mut().iter_mut().flatten() {
+            if let Some((version, rest)) = extension
+                .strip_prefix(&extension_prefix)
+                .and_then(|suffix| suffix.split_once('/'))
+            {
+                if version != new_version {
+                    *extension = format!("{}{}/{}", extension_prefix, new_version, rest);
+                    upgraded = true;
+                }
+            }
+        }
+        upgraded
+    }
+
+    /// Upgrades an extension, as in [Extensions::upgrade_extension], and then
+    /// migrates this object's fields with `migrate`.
+    ///
+    /// `migrate` is only called if an extension was upgraded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.extensions = Some(vec!["https://stac-extensions.github.io/projection/v1.1.0/schema.json".to_string()]);
+    /// let _ = item.properties.additional_fields.insert("proj:epsg".to_string(), 4326.into());
+    /// let upgraded = item.upgrade_extension_with("https://stac-extensions.github.io/projection/", "v2.0.0", |item| {
+    ///     if let Some(epsg) = item.properties.additional_fields.remove("proj:epsg") {
+    ///         let _ = item.properties.additional_fields.insert("proj:code".to_string(), format!("EPSG:{}", epsg).into());
+    ///     }
+    /// });
+    /// assert!(upgraded);
+    /// assert_eq!(item.properties.additional_fields["proj:code"], "EPSG:4326");
+    /// ```
+    fn upgrade_extension_with<F>(
+        &mut self,
+        extension_prefix: &str,
+        new_version: &str,
+        migrate: F,
+    ) -> bool
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self),
+    {
+        if self.upgrade_extension(extension_prefix, new_version) {
+            migrate(self);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Declares the extensions whose fields this object uses but doesn't declare.
+    ///
+    /// Fields are found at the top level, in item properties, and in each
+    /// asset. The eo (`eo:`), projection (`proj:`), view (`view:`), raster
+    /// (`raster:`), sar (`sar:`), and scientific (`sci:`) extensions are
+    /// recognized, and the [latest](LATEST_EXTENSIONS) schema URI is added for
+    /// each one that's used, unless some version of it is already declared.
+    /// The one exception is `proj:epsg`, which projection v2.0.0 replaced with
+    /// `proj:code`: if `proj:epsg` is used and `proj:code` isn't, projection
+    /// v1.1.0 is declared instead, so the object stays valid. Returns the
+    /// added schema URIs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert("eo:cloud_cover".to_string(), 12.into());
+    /// let _ = item.properties.additional_fields.insert("proj:epsg".to_string(), 4326.into());
+    /// assert_eq!(
+    ///     item.infer_extensions(),
+    ///     [
+    ///         "https://stac-extensions.github.io/eo/v1.1.0/schema.json",
+    ///         "https://stac-extensions.github.io/projection/v1.1.0/schema.json",
+    ///     ]
+    /// );
+    /// assert!(item.infer_extensions().is_empty());
+    /// ```
+    fn infer_extensions(&mut self) -> Vec<String>
+    where
+        Self: Serialize + Sized,
+    {
+        let value = match serde_json::to_value(&*self) {
+            Ok(Value::Object(object)) => object,
+            _ => return Vec::new(),
+        };
+        let mut objects = vec![&value];
+        if let Some(Value::Object(properties)) = value.get("properties") {
+            objects.push(properties);
+        }
+        if let Some(Value::Object(assets)) = value.get("assets") {
+            objects.extend(assets.values().filter_map(Value::as_object));
+        }
+        let uses = |field: &str| {
+            objects
+                .iter()
+                .any(|object| object.keys().any(|key| key.starts_with(field)))
+        };
+        let mut added = Vec::new();
+        for (field_prefix, prefix) in FIELD_PREFIXES {
+            let is_declared = self
+                .extensions()
+                .unwrap_or_default()
+                .iter()
+                .any(|extension| extension.starts_with(prefix));
+            if uses(field_prefix) && !is_declared {
+                let uri = if prefix == PROJECTION_PREFIX && uses("proj:epsg") && !uses("proj:code")
+                {
+                    Some(PROJECTION_EPSG_EXTENSION)
+                } else {
+                    LATEST_EXTENSIONS
+                        .iter()
+                        .find(|(p, _)| *p == prefix)
+                        .map(|(_, uri)| *uri)
+                };
+                if let Some(uri) = uri {
+                    self.add_extension(uri);
+                    added.push(uri.to_string());
+                }
+            }
+        }
+        added
+    }
+
+    /// Checks the types of well-known fields from this object's extensions.
+    ///
+    /// This is a lightweight alternative to full validation (which requires
+    /// the `jsonschema` feature). Only the eo, projection, view, and raster
+    /// extensions are recognized, and only if this object declares them. Fields
+    /// are checked at the top level, in item properties, and in each asset,
+    /// e.g. that `proj:epsg` is an integer and `eo:cloud_cover` is a number
+    /// between zero and 100. Returns an empty vector if no problems are found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Extensions, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.add_extension("https://stac-extensions.github.io/eo/v1.1.0/schema.json");
+    /// let _ = item.properties.additional_fields.insert("eo:cloud_cover".to_string(), 120.into());
+    /// let lints = item.validate_extension_fields();
+    /// assert_eq!(lints[0].pointer, "/properties/eo:cloud_cover");
+    /// ```
+    fn validate_extension_fields(&self) -> Vec<Lint>
+    where
+        Self: Serialize,
+    {
+        let fields: Vec<_> = EXTENSION_FIELDS
+            .iter()
+            .filter(|(prefix, _, _)| {
+                self.extensions()
+                    .unwrap_or_default()
+                    .iter()
+                    .any(|extension| extension.starts_with(prefix))
+            })
+            .collect();
+        let mut lints = Vec::new();
+        if fields.is_empty() {
+            return lints;
+        }
+        let value = match serde_json::to_value(self) {
+            Ok(Value::Object(object)) => object,
+            _ => return lints,
+        };
+        let mut objects = vec![(String::new(), &value)];
+        if let Some(Value::Object(properties)) = value.get("properties") {
+            objects.push(("/properties".to_string(), properties));
+        }
+        if let Some(Value::Object(assets)) = value.get("assets") {
+            for (key, asset) in assets {
+                if let Value::Object(asset) = asset {
+                    objects.push((format!("/assets/{}", escape_pointer(key)), asset));
+                }
+            }
+        }
+        for (pointer, object) in objects {
+            lint_fields(&pointer, object, &fields, &mut lints);
+        }
+        lints
+    }
+}
+
+fn lint_fields(
+    pointer: &str,
+    object: &Map<String, Value>,
+    fields: &[&(&str, &str, FieldType)],
+    lints: &mut Vec<Lint>,
+) {
+    for (_, name, field_type) in fields {
+        if let Some(value) = object.get(*name) {
+            if let Some(message) = check_field(value, *field_type) {
+                lints.push(Lint {
+                    pointer: format!("{}/{}", pointer, escape_pointer(name)),
+                    message: format!("{} {}", name, message),
+                });
+            }
+        }
+    }
+}
+
+fn check_field(value: &Value, field_type: FieldType) -> Option<String> {
+    match field_type {
+        FieldType::Number { minimum, maximum } => match value.as_f64() {
+            Some(number) if number < minimum || number > maximum => Some(format!(
+                "must be between {} and {}, got {}",
+                minimum, maximum, number
+            )),
+            Some(_) => None,
+            None => Some("must be a number".to_string()),
+        },
+        FieldType::NullableInteger => (!value.is_null() && !value.is_i64() && !value.is_u64())
+            .then(|| "must be an integer or null".to_string()),
+        FieldType::NullableString => {
+            (!value.is_null() && !value.is_string()).then(|| "must be a string or null".to_string())
+        }
+        FieldType::NullableObject => (!value.is_null() && !value.is_object())
+            .then(|| "must be an object or null".to_string()),
+        FieldType::Numbers(lengths) => match value.as_array() {
+            Some(array) if lengths.contains(&array.len()) && array.iter().all(Value::is_number) => {
+                None
+            }
+            _ => Some(format!(
+                "must be an array of {} numbers",
+                lengths
+                    .iter()
+                    .map(|length| length.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            )),
+        },
+        FieldType::Integers(length) => match value.as_array() {
+            Some(array)
+                if array.len() == length
+                    && array.iter().all(|value| value.is_i64() || value.is_u64()) =>
+            {
+                None
+            }
+            _ => Some(format!("must be an array of {} integers", length)),
+        },
+        FieldType::Objects => match value.as_array() {
+            Some(array) if array.iter().all(Value::is_object) => None,
+            _ => Some("must be an array of objects".to_string()),
+        },
+    }
+}
+
+/// Deserializes an extension's fields, e.g. `file:size`, from an object's additional fields.
+///
+/// Returns `None` if none of the `keys` are set.
+pub(crate) fn extract_fields<T: DeserializeOwned>(
+    fields: &Map<String, Value>,
+    keys: &[&str],
+) -> Result<Option<T>> {
+    let mut map = Map::new();
+    for key in keys {
+        if let Some(value) = fields.get(*key) {
+            let _ = map.insert(key.to_string(), value.clone());
+        }
+    }
+    if map.is_empty() {
+        Ok(None)
+    } else {
+        serde_json::from_value(Value::Object(map))
+            .map(Some)
+            .map_err(Error::from)
+    }
+}
+
+/// Replaces an extension's fields in an object's additional fields with those of `value`.
+///
+/// All of the `keys` are removed first, so fields that `value` doesn't set
+/// are cleared. Other fields, including unknown fields with the same prefix,
+/// are kept.
+pub(crate) fn replace_fields<T: Serialize>(
+    fields: &mut Map<String, Value>,
+    keys: &[&str],
+    value: T,
+) -> Result<()> {
+    for key in keys {
+        let _ = fields.remove(*key);
+    }
+    if let Value::Object(map) = serde_json::to_value(value)? {
+        fields.extend(map);
+    }
+    Ok(())
+}
+
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Extensions, LATEST_EXTENSIONS};
+    use crate::{Asset, Catalog, Collection, Item};
+    use serde_json::json;
+
+    #[test]
+    fn upgrade_extension() {
+        let mut item = Item::new("an-id");
+        item.extensions = Some(vec![
+            "https://stac-extensions.github.io/eo/v1.0.0/schema.json".to_string(),
+            "https://stac-extensions.github.io/projection/v1.0.0/schema.json".to_string(),
+        ]);
+        assert!(item.upgrade_extension("https://stac-extensions.github.io/eo", "v1.1.0"));
+        let extensions = item.extensions.as_ref().unwrap();
+        assert_eq!(
+            extensions[0],
+            "https://stac-extensions.github.io/eo/v1.1.0/schema.json"
+        );
+        assert_eq!(
+            extensions[1],
+            "https://stac-extensions.github.io/projection/v1.0.0/schema.json"
+        );
+        assert!(!item.upgrade_extension("https://stac-extensions.github.io/eo/", "v1.1.0"));
+    }
+
+    #[test]
+    fn upgrade_extension_without_extensions() {
+        let mut catalog = Catalog::new("an-id", "a description");
+        assert!(!catalog.upgrade_extension("https://stac-extensions.github.io/eo/", "v1.1.0"));
+        assert!(catalog.extensions.is_none());
+    }
+
+    #[test]
+    fn upgrade_extension_with_skips_migration() {
+        let mut item = Item::new("an-id");
+        let mut called = false;
+        assert!(!item.upgrade_extension_with(
+            "https://stac-extensions.github.io/eo/",
+            "v1.1.0",
+            |_| called = true
+        ));
+        assert!(!called);
+    }
+
+    #[test]
+    fn latest_extensions() {
+        for (prefix, uri) in LATEST_EXTENSIONS {
+            assert!(prefix.ends_with('/'));
+            let version = uri.strip_prefix(prefix).unwrap().split('/').next().unwrap();
+            assert!(version.starts_with('v'));
+        }
+    }
+
+    #[test]
+    fn infer_extensions() {
+        let mut item: Item = crate::read("examples/asset-extension-fields.json").unwrap();
+        item.extensions = Some(vec![
+            "https://stac-extensions.github.io/projection/v1.0.0/schema.json".to_string(),
+        ]);
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("sci:doi".to_string(), "10.5061/dryad.s2v81.2".into());
+        let added = item.infer_extensions();
+        assert_eq!(
+            added,
+            [
+                "https://stac-extensions.github.io/eo/v1.1.0/schema.json",
+                "https://stac-extensions.github.io/raster/v1.1.0/schema.json",
+                "https://stac-extensions.github.io/scientific/v1.0.0/schema.json",
+            ]
+        );
+        assert_eq!(item.extensions.as_ref().unwrap().len(), 4);
+
+        let mut collection = Collection::new("an-id", "a description");
+        let _ = collection
+            .additional_fields
+            .insert("sar:instrument_mode".to_string(), "IW".into());
+        assert_eq!(
+            collection.infer_extensions(),
+            ["https://stac-extensions.github.io/sar/v1.0.0/schema.json"]
+        );
+    }
+
+    #[test]
+    fn infer_projection_version() {
+        let mut item = Item::new("an-id");
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("proj:epsg".to_string(), json!(4326));
+        assert_eq!(
+            item.clone().infer_extensions(),
+            ["https://stac-extensions.github.io/projection/v1.1.0/schema.json"]
+        );
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("proj:code".to_string(), json!("EPSG:4326"));
+        assert_eq!(
+            item.clone().infer_extensions(),
+            ["https://stac-extensions.github.io/projection/v2.0.0/schema.json"]
+        );
+        let _ = item.properties.additional_fields.remove("proj:epsg");
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("proj:shape".to_string(), json!([4, 4]));
+        assert_eq!(
+            item.infer_extensions(),
+            ["https://stac-extensions.github.io/projection/v2.0.0/schema.json"]
+        );
+    }
+
+    #[test]
+    fn validate_extension_fields() {
+        let mut item = Item::new("an-id");
+        let fields = &mut item.properties.additional_fields;
+        let _ = fields.insert("eo:cloud_cover".to_string(), json!(101));
+        let _ = fields.insert("proj:epsg".to_string(), json!("4326"));
+        let _ = fields.insert("view:off_nadir".to_string(), json!(5.0));
+        assert!(item.validate_extension_fields().is_empty());
+
+        item.add_extension("https://stac-extensions.github.io/eo/v1.1.0/schema.json");
+        item.add_extension("https://stac-extensions.github.io/projection/v1.1.0/schema.json");
+        item.add_extension("https://stac-extensions.github.io/view/v1.0.0/schema.json");
+        let mut asset = Asset::new("an-href");
+        let _ = asset
+            .additional_fields
+            .insert("proj:shape".to_string(), json!([1.5, 2]));
+        let _ = item.assets.insert("a/b".to_string(), asset);
+        let lints = item.validate_extension_fields();
+        let pointers: Vec<_> = lints.iter().map(|lint| lint.pointer.as_str()).collect();
+        assert_eq!(
+            pointers,
+            vec![
+                "/properties/eo:cloud_cover",
+                "/properties/proj:epsg",
+                "/assets/a~1b/proj:shape"
+            ]
+        );
+        assert_eq!(
+            lints[0].message,
+            "eo:cloud_cover must be between 0 and 100, got 101"
+        );
+    }
+
+    #[test]
+    fn validate_extension_fields_collection() {
+        let mut collection = Collection::new("an-id", "a description");
+        collection.add_extension("https://stac-extensions.github.io/raster/v1.1.0/schema.json");
+        let _ = collection
+            .additional_fields
+            .insert("raster:bands".to_string(), json!([{}, 1]));
+        let lints = collection.validate_extension_fields();
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].pointer, "/raster:bands");
+    }
 }
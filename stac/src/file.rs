@@ -0,0 +1,139 @@
+//! The [file extension](https://github.com/stac-extensions/file).
+//!
+//! The file extension describes the files behind assets and links: their
+//! size, checksum, byte order, and the size of their header. Cloud-native
+//! readers can use `file:header_size` to fetch a file's header with a single
+//! range request, e.g. with [Item::read_asset_header](crate::Item::read_asset_header).
+//! Other `file:` fields are left as-is in the asset's additional fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use stac::Item;
+//! let item: Item = stac::read("examples/file.json").unwrap();
+//! let file = item.assets["data"].file().unwrap().unwrap();
+//! assert_eq!(file.header_size.unwrap(), 16384);
+//! assert_eq!(file.values.unwrap()[1].summary, "Clouds");
+//! ```
+
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The schema URI of the file extension.
+pub const SCHEMA_URI: &str = "https://stac-extensions.github.io/file/v2.1.0/schema.json";
+
+const FIELDS: [&str; 6] = [
+    "file:byte_order",
+    "file:checksum",
+    "file:header_size",
+    "file:size",
+    "file:values",
+    "file:local_path",
+];
+
+/// The file fields of an asset.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct File {
+    /// The byte order of integer values in the file, `big-endian` or `little-endian`.
+    #[serde(rename = "file:byte_order", skip_serializing_if = "Option::is_none")]
+    pub byte_order: Option<String>,
+
+    /// The [multihash](https://github.com/multiformats/multihash) of the file, hex-encoded.
+    #[serde(rename = "file:checksum", skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// The size of the file's header, in bytes.
+    #[serde(rename = "file:header_size", skip_serializing_if = "Option::is_none")]
+    pub header_size: Option<u64>,
+
+    /// The size of the file, in bytes.
+    #[serde(rename = "file:size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Summaries of specific values in the file, e.g. nodata or classes.
+    #[serde(rename = "file:values", skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<MappingObject>>,
+
+    /// A relative local path for the file, used when it's downloaded.
+    #[serde(rename = "file:local_path", skip_serializing_if = "Option::is_none")]
+    pub local_path: Option<String>,
+}
+
+/// A summary of one or more values in a file.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MappingObject {
+    /// The values that are summarized.
+    pub values: Vec<Value>,
+
+    /// A short description of the values.
+    pub summary: String,
+}
+
+impl File {
+    /// Returns true if none of the fields are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::file::File;
+    /// assert!(File::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.byte_order.is_none()
+            && self.checksum.is_none()
+            && self.header_size.is_none()
+            && self.size.is_none()
+            && self.values.is_none()
+            && self.local_path.is_none()
+    }
+
+    pub(crate) fn from_fields(fields: &Map<String, Value>) -> Result<Option<File>> {
+        crate::extensions::extract_fields(fields, &FIELDS)
+    }
+
+    pub(crate) fn into_fields(self, fields: &mut Map<String, Value>) -> Result<()> {
+        crate::extensions::replace_fields(fields, &FIELDS, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::File;
+    use crate::{Asset, Item};
+
+    #[test]
+    fn read() {
+        let item: Item = crate::read("examples/file.json").unwrap();
+        let file = item.assets["data"].file().unwrap().unwrap();
+        assert_eq!(file.byte_order.unwrap(), "little-endian");
+        assert_eq!(file.size.unwrap(), 1048576);
+        let values = file.values.unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[1].values, [1, 2]);
+        assert!(file.local_path.is_none());
+    }
+
+    #[test]
+    fn set_keeps_unknown_fields() {
+        let mut asset = Asset::new("an-href");
+        let _ = asset
+            .additional_fields
+            .insert("file:unknown".to_string(), "foo".into());
+        assert!(asset.file().unwrap().is_none());
+        let file = File {
+            header_size: Some(1024),
+            ..Default::default()
+        };
+        asset.set_file(file.clone()).unwrap();
+        assert_eq!(asset.file().unwrap().unwrap(), file);
+        assert_eq!(asset.additional_fields["file:header_size"], 1024);
+        assert_eq!(asset.additional_fields["file:unknown"], "foo");
+        asset.set_file(File::default()).unwrap();
+        assert!(asset.file().unwrap().is_none());
+    }
+
+    crate::tests::roundtrip!(file, "examples/file.json", Item);
+}
@@ -1,3 +1,9 @@
+//! Hrefs, the locations of STAC objects and assets.
+//!
+//! An href is either a local path (e.g. `data/item.json` or
+//! `file:///data/item.json`) or a remote url (e.g.
+//! `https://stac-rs.test/item.json` or `s3://bucket/item.json`).
+
 use url::Url;
 
 /// Implemented by all three STAC objects, the [Href] trait allows getting and setting an object's href.
@@ -14,34 +20,486 @@ use url::Url;
 /// let item: Item = stac::read("data/simple-item.json").unwrap();
 /// assert!(item.href().is_some());
 /// ```
+///
+/// The trait is object-safe, so objects of different types can be held together:
+///
+/// ```
+/// use stac::{Catalog, Href, Item};
+/// let mut objects: Vec<Box<dyn Href>> = vec![
+///     Box::new(Item::new("an-id")),
+///     Box::new(Catalog::new("an-id", "a description")),
+/// ];
+/// for object in &mut objects {
+///     object.set_href("an/href.json");
+/// }
+/// assert!(objects.iter().all(|object| object.href() == Some("an/href.json")));
+/// ```
 pub trait Href {
     /// Gets this object's href.
     fn href(&self) -> Option<&str>;
 
     /// Sets this object's href.
-    fn set_href(&mut self, href: impl ToString);
+    fn set_href(&mut self, href: &str);
+}
+
+impl<T: Href + ?Sized> Href for &mut T {
+    fn href(&self) -> Option<&str> {
+        (**self).href()
+    }
+
+    fn set_href(&mut self, href: &str) {
+        (**self).set_href(href)
+    }
 }
 
-/// Parses an href into a [Url] if the scheme is `http` or `https`.
+impl<T: Href + ?Sized> Href for Box<T> {
+    fn href(&self) -> Option<&str> {
+        (**self).href()
+    }
+
+    fn set_href(&mut self, href: &str) {
+        (**self).set_href(href)
+    }
+}
+
+/// Parses an href into a [Url] if it is [remote](is_remote).
 ///
-/// Otherwise, returns `None`. This is useful for determining whether
-/// a given href should be opened with a local filesystem reader or
-/// [reqwest].
+/// Otherwise, e.g. for a path or a `file://` url, returns `None`. This is
+/// useful for determining whether a given href should be opened with a local
+/// filesystem reader or [reqwest]. Only `http` and `https` urls can be read,
+/// so reading any other remote href, e.g. `s3://bucket/item.json`, is an error
+/// from [reqwest] instead of a missing file.
 ///
 /// # Examples
 ///
 /// ```
 /// assert!(stac::href_to_url("C:\\\\data").is_none());
+/// assert!(stac::href_to_url("file:///data/item.json").is_none());
 /// assert!(stac::href_to_url("http://stac-rs.test").is_some());
+/// assert!(stac::href_to_url("s3://bucket/item.json").is_some());
 /// ```
 pub fn href_to_url(href: &str) -> Option<Url> {
-    if let Ok(url) = Url::parse(href) {
-        if url.scheme().starts_with("http") {
-            Some(url)
+    if is_remote(href) {
+        Url::parse(href).ok()
+    } else {
+        None
+    }
+}
+
+/// Returns the scheme of an href, if it has one.
+///
+/// Schemes are returned as written. Bare paths, including Windows paths like
+/// `C:\data`, have no scheme.
+///
+/// # Examples
+///
+/// ```
+/// use stac::href;
+/// assert_eq!(href::scheme("https://stac-rs.test/item.json"), Some("https"));
+/// assert_eq!(href::scheme("s3://bucket/item.json"), Some("s3"));
+/// assert_eq!(href::scheme("data/item.json"), None);
+/// assert_eq!(href::scheme("C:\\data\\item.json"), None);
+/// ```
+pub fn scheme(href: &str) -> Option<&str> {
+    let (scheme, _) = href.split_once(':')?;
+    let mut chars = scheme.chars();
+    if scheme.len() > 1
+        && chars
+            .next()
+            .map(|c| c.is_ascii_alphabetic())
+            .unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !is_windows_path(href)
+    {
+        Some(scheme)
+    } else {
+        None
+    }
+}
+
+/// Returns true if an href is a local path.
+///
+/// Bare paths and `file://` urls are local.
+///
+/// # Examples
+///
+/// ```
+/// use stac::href;
+/// assert!(href::is_local("data/item.json"));
+/// assert!(href::is_local("file:///data/item.json"));
+/// assert!(!href::is_local("s3://bucket/item.json"));
+/// ```
+pub fn is_local(href: &str) -> bool {
+    scheme(href)
+        .map(|scheme| scheme.eq_ignore_ascii_case("file"))
+        .unwrap_or(true)
+}
+
+/// Returns true if an href is a remote url, i.e. it has a scheme other than `file`.
+///
+/// # Examples
+///
+/// ```
+/// use stac::href;
+/// assert!(href::is_remote("https://stac-rs.test/item.json"));
+/// assert!(href::is_remote("s3://bucket/item.json"));
+/// assert!(!href::is_remote("data/item.json"));
+/// ```
+pub fn is_remote(href: &str) -> bool {
+    !is_local(href)
+}
+
+/// Returns a key that is the same for all spellings of the same href.
+///
+/// The href is resolved against `base` (e.g. the href of the object that
+/// links to it), and redundant `.` and `..` segments and duplicate separators
+/// are removed. Relative paths are resolved against the current directory,
+/// without touching the filesystem. Windows paths use forward slashes and are
+/// lowercased, since they're case-insensitive. This is used to avoid reading
+/// the same object more than once while walking a tree.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     stac::canonical_key("data/catalog.json", "./foo/../bar/item.json"),
+///     stac::canonical_key("data/catalog.json", "bar/item.json"),
+/// );
+/// assert_eq!(
+///     stac::canonical_key("http://stac-rs.test/catalog.json", "./a/../item.json"),
+///     "http://stac-rs.test/item.json"
+/// );
+/// ```
+pub fn canonical_key(base: &str, href: &str) -> String {
+    if let Some(url) = parse_url(href) {
+        return url.to_string();
+    }
+    if let Some(base) = parse_url(base) {
+        if let Ok(url) = base.join(href) {
+            return url.to_string();
+        }
+    }
+    let (path, windows) = normalize_path(base, href);
+    if windows {
+        path.to_lowercase()
+    } else {
+        path
+    }
+}
+
+/// Resolves a path against a base path and removes redundant segments, keeping its case.
+///
+/// Returns the path, with forward slashes, and whether it's a Windows path.
+/// Non-Windows paths start with a slash.
+fn normalize_path(base: &str, href: &str) -> (String, bool) {
+    let windows = is_windows_path(base) || is_windows_path(href);
+    let href = if windows {
+        href.replace('\\', "/")
+    } else {
+        href.to_string()
+    };
+    let path = if href.starts_with('/') || is_windows_path(&href) {
+        href
+    } else {
+        let base = if windows {
+            base.replace('\\', "/")
+        } else {
+            base.to_string()
+        };
+        let directory = &base[..base.rfind('/').map(|i| i + 1).unwrap_or(0)];
+        let path = format!("{}{}", directory, href);
+        if path.starts_with('/') || is_windows_path(&path) {
+            path
+        } else if let Ok(current_dir) = std::env::current_dir() {
+            format!("{}/{}", current_dir.to_string_lossy(), path)
         } else {
-            None
+            path
         }
+    };
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.len() > 1 || parts.first().map(|p| !is_drive(p)).unwrap_or(false) {
+                    let _ = parts.pop();
+                }
+            }
+            part => parts.push(part),
+        }
+    }
+    let path = parts.join("/");
+    if windows {
+        (path, true)
     } else {
-        None
+        (format!("/{}", path), false)
+    }
+}
+
+/// Returns the shortest relative href from one object's location to another's.
+///
+/// `from` is the href of the object that will hold the relative href, e.g. a
+/// catalog that links to a child, and `to` is the href being linked to.
+/// Both are either urls or paths; relative paths are resolved against the
+/// current directory, as in [canonical_key]. The result always starts with
+/// `./` or `../`. Windows paths are compared case-insensitively, but the
+/// result keeps the case of `to`. Returns `None` if `to` can't be relative to `from`, e.g.
+/// one is a path and the other is a url, or they're urls with different
+/// schemes or hosts, in which case the absolute href should be kept.
+///
+/// # Examples
+///
+/// ```
+/// use stac::href;
+/// assert_eq!(
+///     href::make_relative("/data/catalog.json", "/data/child/catalog.json").unwrap(),
+///     "./child/catalog.json"
+/// );
+/// assert_eq!(
+///     href::make_relative(
+///         "http://stac-rs.test/a/b/item.json",
+///         "http://stac-rs.test/a/catalog.json"
+///     )
+///     .unwrap(),
+///     "../catalog.json"
+/// );
+/// assert!(href::make_relative("/data/catalog.json", "http://stac-rs.test/item.json").is_none());
+/// ```
+pub fn make_relative(from: &str, to: &str) -> Option<String> {
+    let relative = match (parse_url(from), parse_url(to)) {
+        (Some(from), Some(to)) => match from.make_relative(&to)? {
+            relative if relative.is_empty() => to.path_segments()?.next_back()?.to_string(),
+            relative => relative,
+        },
+        (None, None) => {
+            let (from, from_windows) = normalize_path("", from);
+            let (to, to_windows) = normalize_path("", to);
+            let windows = from_windows || to_windows;
+            let from_parts: Vec<_> = from.split('/').collect();
+            let to_parts: Vec<_> = to.split('/').collect();
+            let from_directory = &from_parts[..from_parts.len() - 1];
+            let common = to_parts[..to_parts.len() - 1]
+                .iter()
+                .zip(from_directory)
+                .take_while(|(a, b)| a == b || windows && a.to_lowercase() == b.to_lowercase())
+                .count();
+            if common == 0 {
+                // Windows paths on different drives.
+                return None;
+            }
+            let mut parts = vec![".."; from_directory.len() - common];
+            parts.extend(&to_parts[common..]);
+            parts.join("/")
+        }
+        _ => return None,
+    };
+    if relative.starts_with("../") {
+        Some(relative)
+    } else {
+        Some(format!("./{}", relative))
+    }
+}
+
+fn parse_url(href: &str) -> Option<Url> {
+    Url::parse(href)
+        .ok()
+        .filter(|url| url.scheme().len() > 1 && !is_windows_path(href))
+}
+
+fn is_windows_path(href: &str) -> bool {
+    href.contains('\\') || href.split('/').next().map(is_drive).unwrap_or(false)
+}
+
+fn is_drive(part: &str) -> bool {
+    let bytes = part.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_key, is_local, is_remote, make_relative, scheme, Href};
+    use crate::{Collection, Item};
+
+    fn set_and_get(mut object: impl Href) -> Option<String> {
+        object.set_href("an/href.json");
+        object.href().map(String::from)
+    }
+
+    #[test]
+    fn forwarding_impls() {
+        let mut item = Item::new("an-id");
+        assert_eq!(set_and_get(&mut item).as_deref(), Some("an/href.json"));
+        assert_eq!(item.href(), Some("an/href.json"));
+        let boxed: Box<dyn Href> = Box::new(Collection::new("an-id", "a description"));
+        assert_eq!(set_and_get(boxed).as_deref(), Some("an/href.json"));
+    }
+
+    #[test]
+    fn schemes() {
+        assert_eq!(scheme("http://stac-rs.test"), Some("http"));
+        assert_eq!(scheme("HTTPS://stac-rs.test/item.json"), Some("HTTPS"));
+        assert_eq!(scheme("s3://bucket/item.json"), Some("s3"));
+        assert_eq!(scheme("gs://bucket/item.json"), Some("gs"));
+        assert_eq!(scheme("file:///data/item.json"), Some("file"));
+        assert_eq!(scheme("git+ssh://host/repo"), Some("git+ssh"));
+        assert_eq!(scheme("data/item.json"), None);
+        assert_eq!(scheme("./item.json"), None);
+        assert_eq!(scheme("/data/item.json"), None);
+        assert_eq!(scheme("C:\\data\\item.json"), None);
+        assert_eq!(scheme("c:/data/item.json"), None);
+        assert_eq!(scheme("data/item:1.json"), None);
+        assert_eq!(scheme("1a://foo"), None);
+        assert_eq!(scheme(""), None);
+    }
+
+    #[test]
+    fn local_and_remote() {
+        for href in [
+            "data/item.json",
+            "./item.json",
+            "/data/item.json",
+            "C:\\data\\item.json",
+            "file:///data/item.json",
+            "FILE:///data/item.json",
+            "",
+        ] {
+            assert!(is_local(href), "{}", href);
+            assert!(!is_remote(href), "{}", href);
+        }
+        for href in [
+            "http://stac-rs.test/item.json",
+            "https://stac-rs.test/item.json",
+            "s3://bucket/item.json",
+            "gs://bucket/item.json",
+            "az://container/item.json",
+        ] {
+            assert!(is_remote(href), "{}", href);
+            assert!(!is_local(href), "{}", href);
+        }
+    }
+
+    #[test]
+    fn canonical_key_paths() {
+        assert_eq!(
+            canonical_key("/data/catalog.json", "./foo/../bar/./item.json"),
+            "/data/bar/item.json"
+        );
+        assert_eq!(
+            canonical_key("/data/catalog.json", "bar//item.json"),
+            "/data/bar/item.json"
+        );
+        assert_eq!(
+            canonical_key("/data/a/catalog.json", "/data/b/../a/item.json"),
+            canonical_key("/data/b/catalog.json", "../a/item.json")
+        );
+        assert_eq!(
+            canonical_key("/catalog.json", "../../item.json"),
+            "/item.json"
+        );
+    }
+
+    #[test]
+    fn canonical_key_relative_paths() {
+        let current_dir = std::env::current_dir().unwrap();
+        assert_eq!(
+            canonical_key("data/catalog.json", "./item.json"),
+            format!("{}/data/item.json", current_dir.to_string_lossy())
+        );
+    }
+
+    #[test]
+    fn canonical_key_windows_paths() {
+        assert_eq!(
+            canonical_key("C:\\Data\\catalog.json", ".\\Foo\\..\\Item.json"),
+            "c:/data/item.json"
+        );
+        assert_eq!(
+            canonical_key("c:/data/catalog.json", "C:\\DATA\\item.json"),
+            "c:/data/item.json"
+        );
+        assert_eq!(
+            canonical_key("C:\\catalog.json", "..\\item.json"),
+            "c:/item.json"
+        );
+    }
+
+    #[test]
+    fn canonical_key_urls() {
+        assert_eq!(
+            canonical_key("http://stac-rs.test/a/catalog.json", "../b/./item.json"),
+            "http://stac-rs.test/b/item.json"
+        );
+        assert_eq!(
+            canonical_key("/data/catalog.json", "HTTP://STAC-RS.test/a/../item.json"),
+            "http://stac-rs.test/item.json"
+        );
+    }
+
+    #[test]
+    fn make_relative_paths() {
+        assert_eq!(
+            make_relative("/a/catalog.json", "/a/b/item.json").unwrap(),
+            "./b/item.json"
+        );
+        assert_eq!(
+            make_relative("/a/b/item.json", "/a/catalog.json").unwrap(),
+            "../catalog.json"
+        );
+        assert_eq!(
+            make_relative("/a/b/item.json", "/a/c/data.tif").unwrap(),
+            "../c/data.tif"
+        );
+        assert_eq!(
+            make_relative("/a/catalog.json", "/a/catalog.json").unwrap(),
+            "./catalog.json"
+        );
+        assert_eq!(
+            make_relative("data/catalog.json", "./data/./b/../item.json").unwrap(),
+            "./item.json"
+        );
+        assert_eq!(
+            make_relative(r"C:\data\catalog.json", r"C:\data\b\item.json").unwrap(),
+            "./b/item.json"
+        );
+        assert!(make_relative(r"C:\data\catalog.json", r"D:\data\item.json").is_none());
+        assert_eq!(
+            make_relative(r"C:\Data\catalog.json", r"c:\data\Child\Item.json").unwrap(),
+            "./Child/Item.json"
+        );
+        assert_eq!(
+            make_relative("/Data/catalog.json", "/Data/Child/Item.json").unwrap(),
+            "./Child/Item.json"
+        );
+        assert_eq!(
+            make_relative("/data/catalog.json", "/Data/item.json").unwrap(),
+            "../Data/item.json"
+        );
+    }
+
+    #[test]
+    fn make_relative_urls() {
+        assert_eq!(
+            make_relative(
+                "http://stac-rs.test/a/catalog.json",
+                "http://stac-rs.test/a/b/item.json"
+            )
+            .unwrap(),
+            "./b/item.json"
+        );
+        assert_eq!(
+            make_relative("s3://bucket/a/catalog.json", "s3://bucket/a/catalog.json").unwrap(),
+            "./catalog.json"
+        );
+        assert!(make_relative(
+            "http://stac-rs.test/catalog.json",
+            "http://other.test/item.json"
+        )
+        .is_none());
+        assert!(make_relative(
+            "http://stac-rs.test/catalog.json",
+            "https://stac-rs.test/item.json"
+        )
+        .is_none());
+        assert!(make_relative("http://stac-rs.test/catalog.json", "/a/item.json").is_none());
     }
 }
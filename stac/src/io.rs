@@ -1,24 +1,101 @@
-use crate::{Error, Href, Result};
-use serde::de::DeserializeOwned;
-use std::{fs::File, io::BufReader, path::Path};
+#[cfg(feature = "zip")]
+use crate::Value;
+use crate::{link::CheckLinksOptions, Error, Href, Link, MaybeStac, ReadCache, Result};
+#[cfg(feature = "reqwest")]
+use reqwest::blocking::Client;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fs::File, io::BufReader, ops::Range, path::Path};
 use url::Url;
 
+/// There's no client to reuse when checking urls without the `reqwest` feature.
+#[cfg(not(feature = "reqwest"))]
+type Client = ();
+
+const DIRECTORY_FILE_NAMES: [&str; 2] = ["catalog.json", "collection.json"];
+#[cfg(feature = "zip")]
+const DEFAULT_ZIP_ENTRY: &str = "catalog.json";
+
 /// Reads any STAC object from an href.
 ///
+/// If the href is a local directory, the `catalog.json` (or, if that doesn't
+/// exist, the `collection.json`) inside that directory is read, and the
+/// object's href is set to that file. If the `yaml` feature is enabled, hrefs
+/// that end in `.yaml` or `.yml` are read as YAML.
+///
 /// # Examples
 ///
 /// ```
 /// let item: stac::Item = stac::read("data/simple-item.json").unwrap();
+/// let catalog: stac::Catalog = stac::read("data").unwrap();
 /// ```
 pub fn read<T: Href + DeserializeOwned>(href: impl ToString) -> Result<T> {
-    let href = href.to_string();
+    let href = resolve_directory(href.to_string())?;
     let mut value: T = read_json(&href)?;
-    value.set_href(href);
+    value.set_href(&href);
     Ok(value)
 }
 
+/// Reads any STAC object from an href, using a cache.
+///
+/// If the href's JSON is in the cache, it's used instead of reading the href
+/// again. Otherwise the href is read as in [read] and its JSON is cached.
+/// Cached JSON can be stale, see [ReadCache].
+///
+/// # Examples
+///
+/// ```
+/// use stac::{Catalog, ReadCache};
+/// let cache = ReadCache::default();
+/// let catalog: Catalog = stac::read_cached("data/catalog.json", &cache).unwrap();
+/// let catalog: Catalog = stac::read_cached("data/catalog.json", &cache).unwrap(); // from the cache
+/// ```
+pub fn read_cached<T: Href + DeserializeOwned>(
+    href: impl ToString,
+    cache: &ReadCache,
+) -> Result<T> {
+    let href = resolve_directory(href.to_string())?;
+    let json = if let Some(json) = cache.get(&href) {
+        json
+    } else {
+        let json: serde_json::Value = read_json(&href)?;
+        cache.insert(&href, json.clone());
+        json
+    };
+    let mut value: T = serde_json::from_value(json)?;
+    value.set_href(&href);
+    Ok(value)
+}
+
+/// Reads the JSON at an href, which may or may not be a STAC object.
+///
+/// Unlike [read], JSON that isn't a STAC object isn't an error: it's returned
+/// as [MaybeStac::Other], so tools can collect the STAC objects in a directory
+/// and skip everything else. STAC objects have their href set. Errors are
+/// still returned if the href can't be read or isn't JSON.
+///
+/// # Examples
+///
+/// ```
+/// use stac::MaybeStac;
+/// let maybe = stac::read_maybe("data/simple-item.json").unwrap();
+/// assert!(maybe.into_stac().unwrap().is_item());
+/// let maybe = stac::read_maybe("schemas/v1.0.0/item.json").unwrap();
+/// assert!(matches!(maybe, MaybeStac::Other(_)));
+/// ```
+pub fn read_maybe(href: impl ToString) -> Result<MaybeStac> {
+    let href = resolve_directory(href.to_string())?;
+    let mut maybe: MaybeStac = read_json(&href)?;
+    if let MaybeStac::Stac(value) = &mut maybe {
+        value.set_href(&href);
+    }
+    Ok(maybe)
+}
+
 /// Reads any deserializable value from the JSON at an href.
 ///
+/// Directories are resolved to their `catalog.json` or `collection.json`, as in [read].
+/// If the `yaml` feature is enabled, hrefs that end in `.yaml` or `.yml` are read as YAML.
+///
 /// # Examples
 ///
 /// ```
@@ -31,26 +108,508 @@ where
     if let Some(url) = crate::href_to_url(href) {
         read_json_from_url(url)
     } else {
-        read_json_from_path(href)
+        read_json_from_path(resolve_directory(href.to_string())?)
+    }
+}
+
+/// Reads byte ranges of files, e.g. to fetch the header of a cloud-optimized asset.
+///
+/// stac-rs doesn't read ranges itself, so callers can use whatever HTTP client
+/// or object store they already have. Hrefs are absolute, having been resolved
+/// by the caller (e.g. [Item::read_asset_range](crate::Item::read_asset_range)).
+/// The trait is implemented for closures with the same signature as
+/// [RangeReader::read_range].
+///
+/// # Examples
+///
+/// ```
+/// use std::{io::{Read, Seek, SeekFrom}, ops::Range};
+/// use stac::RangeReader;
+///
+/// let reader = |href: &str, range: Range<u64>| -> stac::Result<Vec<u8>> {
+///     let mut file = std::fs::File::open(href)?;
+///     let _ = file.seek(SeekFrom::Start(range.start))?;
+///     let mut buf = Vec::new();
+///     let _ = file.take(range.end - range.start).read_to_end(&mut buf)?;
+///     Ok(buf)
+/// };
+/// let bytes = reader.read_range("data/simple-item.json", 0..1).unwrap();
+/// assert_eq!(bytes, b"{");
+/// ```
+pub trait RangeReader {
+    /// Reads the bytes in `range` from the file at `href`.
+    ///
+    /// Fewer bytes can be returned if the file ends before the range does.
+    fn read_range(&self, href: &str, range: Range<u64>) -> Result<Vec<u8>>;
+}
+
+impl<F> RangeReader for F
+where
+    F: Fn(&str, Range<u64>) -> Result<Vec<u8>>,
+{
+    fn read_range(&self, href: &str, range: Range<u64>) -> Result<Vec<u8>> {
+        self(href, range)
+    }
+}
+
+/// Resolves bare ids and relative hrefs against a base before reading.
+///
+/// This is for tools that refer to objects by short names, e.g. on the command
+/// line. Relative hrefs are resolved against `base_href`, which is a directory
+/// or a url prefix, and `default_extension` is appended to hrefs whose last
+/// path segment doesn't have an extension. Absolute hrefs, i.e. urls and
+/// absolute paths, are used as they are, since they already name a resource,
+/// e.g. an OGC API endpoint that doesn't use extensions. Local directories are
+/// left alone so [read] can find their `catalog.json` or `collection.json`.
+///
+/// # Examples
+///
+/// ```
+/// use stac::{Item, ReadContext};
+/// let context = ReadContext::new("data");
+/// let item: Item = context.read("simple-item").unwrap();
+/// assert_eq!(item.id, "20201211_223832_CS2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadContext {
+    /// The directory or url prefix that relative hrefs are resolved against.
+    pub base_href: String,
+
+    /// The extension, without a leading dot, added to hrefs that don't have one.
+    pub default_extension: Option<String>,
+}
+
+impl ReadContext {
+    /// Creates a new read context with a base href and a `json` default extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadContext;
+    /// let context = ReadContext::new("http://stac-rs.test/catalog");
+    /// assert_eq!(context.default_extension.as_deref(), Some("json"));
+    /// ```
+    pub fn new(base_href: impl ToString) -> ReadContext {
+        ReadContext {
+            base_href: base_href.to_string(),
+            default_extension: Some("json".to_string()),
+        }
+    }
+
+    /// Resolves an href against this context, without reading it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::ReadContext;
+    /// let context = ReadContext::new("http://stac-rs.test/catalog");
+    /// assert_eq!(
+    ///     context.resolve("items/an-id").unwrap(),
+    ///     "http://stac-rs.test/catalog/items/an-id.json"
+    /// );
+    /// ```
+    pub fn resolve(&self, href: &str) -> Result<String> {
+        if crate::link::is_absolute(href) {
+            return Ok(href.to_string());
+        }
+        if let Some(base) = crate::href_to_url(&self.base_href) {
+            let base = if base.path().ends_with('/') {
+                base
+            } else {
+                Url::parse(&format!("{}/", base.as_str().trim_end_matches('/')))?
+            };
+            Ok(self.with_extension_url(base.join(href)?).into())
+        } else {
+            let path = Path::new(&self.base_href).join(href);
+            Ok(self.with_extension_path(path.to_string_lossy().into_owned()))
+        }
+    }
+
+    /// Reads a STAC object after [resolving](ReadContext::resolve) its href.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, ReadContext};
+    /// let context = ReadContext::new(".");
+    /// let catalog: Catalog = context.read("data/catalog").unwrap();
+    /// ```
+    pub fn read<T: Href + DeserializeOwned>(&self, href: &str) -> Result<T> {
+        read(self.resolve(href)?)
+    }
+
+    fn with_extension_url(&self, mut url: Url) -> Url {
+        if let Some(extension) = self.default_extension.as_deref() {
+            let last = url
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .unwrap_or_default();
+            if !last.is_empty() && !last.contains('.') {
+                let path = format!("{}.{}", url.path(), extension);
+                url.set_path(&path);
+            }
+        }
+        url
+    }
+
+    fn with_extension_path(&self, href: String) -> String {
+        match self.default_extension.as_deref() {
+            Some(extension)
+                if Path::new(&href).extension().is_none() && !Path::new(&href).is_dir() =>
+            {
+                format!("{}.{}", href, extension)
+            }
+            _ => href,
+        }
+    }
+}
+
+/// Options for serializing STAC objects to JSON.
+///
+/// The default is pretty-printed output, with keys in their original order.
+///
+/// # Examples
+///
+/// ```
+/// use stac::SerializeOptions;
+/// let options = SerializeOptions { pretty: false, sort_keys: true };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Pretty-print the JSON, which is easier to read, edit, and diff.
+    ///
+    /// Otherwise the JSON is compact.
+    pub pretty: bool,
+
+    /// Sort the keys of every object, for reproducible output.
+    pub sort_keys: bool,
+}
+
+/// Writes any serializable value to a path as pretty-printed JSON.
+///
+/// # Examples
+///
+/// ```no_run
+/// let item = stac::Item::new("an-id");
+/// stac::write("an-id.json", &item).unwrap();
+/// ```
+pub fn write(path: impl AsRef<Path>, value: impl Serialize) -> Result<()> {
+    write_with(path, value, SerializeOptions::default())
+}
+
+/// Writes any serializable value to a path as YAML.
+///
+/// YAML is handy for hand-authored catalogs, since it allows comments and is
+/// easier to edit, but it isn't STAC: other tools expect JSON, and YAML output
+/// doesn't get the JSON guarantees of [write_with], e.g. sorted keys or
+/// [SerializeOptions]. Comments aren't preserved when YAML is read and written
+/// again. Requires the `yaml` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// let item = stac::Item::new("an-id");
+/// stac::write_yaml("an-id.yaml", &item).unwrap();
+/// let item: stac::Item = stac::read("an-id.yaml").unwrap();
+/// ```
+#[cfg(feature = "yaml")]
+pub fn write_yaml(path: impl AsRef<Path>, value: impl Serialize) -> Result<()> {
+    let file = File::create(path)?;
+    serde_yaml::to_writer(std::io::BufWriter::new(file), &value).map_err(Error::from)
+}
+
+/// Writes any serializable value to a path as JSON, with options.
+///
+/// # Examples
+///
+/// ```no_run
+/// use stac::SerializeOptions;
+/// let item = stac::Item::new("an-id");
+/// let options = SerializeOptions { pretty: false, sort_keys: true };
+/// stac::write_with("an-id.json", &item, options).unwrap();
+/// ```
+pub fn write_with(
+    path: impl AsRef<Path>,
+    value: impl Serialize,
+    options: SerializeOptions,
+) -> Result<()> {
+    std::fs::write(path, options.to_json_string(value)?).map_err(Error::from)
+}
+
+impl SerializeOptions {
+    /// Serializes any serializable value to a JSON string with these options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::SerializeOptions;
+    /// let options = SerializeOptions { pretty: false, sort_keys: true };
+    /// let json = options.to_json_string(stac::Item::new("an-id")).unwrap();
+    /// assert!(json.starts_with(r#"{"assets":{},"geometry":null"#));
+    /// ```
+    pub fn to_json_string(&self, value: impl Serialize) -> Result<String> {
+        let string = if self.sort_keys {
+            let value = sort_keys(serde_json::to_value(value)?);
+            if self.pretty {
+                serde_json::to_string_pretty(&value)
+            } else {
+                serde_json::to_string(&value)
+            }
+        } else if self.pretty {
+            serde_json::to_string_pretty(&value)
+        } else {
+            serde_json::to_string(&value)
+        };
+        string.map_err(Error::from)
+    }
+}
+
+impl Default for SerializeOptions {
+    fn default() -> SerializeOptions {
+        SerializeOptions {
+            pretty: true,
+            sort_keys: false,
+        }
+    }
+}
+
+fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(sort_keys).collect())
+        }
+        value => value,
+    }
+}
+
+/// Reads any STAC object from a file inside a zip archive.
+///
+/// `inner_href` is the path of the object inside the archive, and defaults to
+/// `catalog.json`. Relative links are resolved within the archive, so their
+/// hrefs can be passed straight back to this function to read linked objects.
+/// The object's href is set to its path inside the archive. Requires the `zip`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// use stac::Links;
+/// let catalog = stac::read_from_zip("examples/catalog.zip", None).unwrap();
+/// let child = catalog.iter_child_links().next().unwrap();
+/// let collection = stac::read_from_zip("examples/catalog.zip", Some(&child.href)).unwrap();
+/// assert!(collection.is_collection());
+/// ```
+#[cfg(feature = "zip")]
+pub fn read_from_zip(path: impl AsRef<Path>, inner_href: Option<&str>) -> Result<Value> {
+    use crate::Links;
+
+    let file = File::open(path)?;
+    let mut archive = zip::ZipArchive::new(BufReader::new(file))?;
+    let inner_href = resolve_zip_href("", inner_href.unwrap_or(DEFAULT_ZIP_ENTRY));
+    let mut value: Value = serde_json::from_reader(archive.by_name(&inner_href)?)?;
+    let (directory, _) = inner_href.rsplit_once('/').unwrap_or(("", ""));
+    for link in value.links_mut() {
+        if !link.is_absolute() {
+            link.href = resolve_zip_href(directory, &link.href);
+        }
+    }
+    value.set_href(&inner_href);
+    Ok(value)
+}
+
+/// Returns true if a file or url exists, without reading it.
+///
+/// Files are checked on the filesystem, and urls with a `HEAD` request, which
+/// requires the `reqwest` feature. A url that returns 404 or 410 doesn't
+/// exist, and any other error status is an error. This is the same check as
+/// [Links::check_links](crate::Links::check_links).
+///
+/// # Examples
+///
+/// ```
+/// assert!(stac::exists("data/simple-item.json").unwrap());
+/// assert!(!stac::exists("data/not-an-item.json").unwrap());
+/// ```
+pub fn exists(href: &str) -> Result<bool> {
+    match check_href(href, &CheckLinksOptions::default(), &mut None) {
+        Ok(()) => Ok(true),
+        Err(err) if err.is_not_found() => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+pub(crate) fn check_links(
+    links: &[Link],
+    base_href: &str,
+    options: &CheckLinksOptions,
+) -> Vec<(Link, Result<()>)> {
+    let mut client = None;
+    links
+        .iter()
+        .map(|link| {
+            let result = link
+                .absolute_href(base_href)
+                .and_then(|href| check_href(&href, options, &mut client));
+            (link.clone(), result)
+        })
+        .collect()
+}
+
+/// Checks that the target of an href exists, reusing the client for urls.
+///
+/// A missing target is an error for which [Error::is_not_found] is true.
+pub(crate) fn check_href(
+    href: &str,
+    options: &CheckLinksOptions,
+    client: &mut Option<Client>,
+) -> Result<()> {
+    if let Some(url) = crate::href_to_url(href) {
+        check_url(url, options, client)
+    } else {
+        std::fs::metadata(href).map(|_| ()).map_err(Error::from)
     }
 }
 
+pub(crate) fn resolve_directory(href: String) -> Result<String> {
+    if crate::href_to_url(&href).is_some() {
+        return Ok(href);
+    }
+    let path = Path::new(&href);
+    if !path.is_dir() {
+        return Ok(href);
+    }
+    for file_name in DIRECTORY_FILE_NAMES {
+        let path = path.join(file_name);
+        if path.is_file() {
+            return Ok(path.to_string_lossy().into_owned());
+        }
+    }
+    Err(Error::NoCatalogInDirectory(href))
+}
+
+#[cfg(feature = "zip")]
+fn resolve_zip_href(directory: &str, href: &str) -> String {
+    let mut parts: Vec<&str> = directory.split('/').filter(|s| !s.is_empty()).collect();
+    for part in href.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                let _ = parts.pop();
+            }
+            s => parts.push(s),
+        }
+    }
+    parts.join("/")
+}
+
 fn read_json_from_path<T>(path: impl AsRef<Path>) -> Result<T>
 where
     T: DeserializeOwned,
 {
     let file = File::open(path.as_ref())?;
     let reader = BufReader::new(file);
+    #[cfg(feature = "yaml")]
+    if is_yaml(&path.as_ref().to_string_lossy()) {
+        return serde_yaml::from_reader(reader).map_err(Error::from);
+    }
     serde_json::from_reader(reader).map_err(Error::from)
 }
 
+/// Returns true if an href's path ends in `.yaml` or `.yml`.
+#[cfg(feature = "yaml")]
+fn is_yaml(path: &str) -> bool {
+    let path = path.to_ascii_lowercase();
+    path.ends_with(".yaml") || path.ends_with(".yml")
+}
+
+/// The `Accept` header sent when reading JSON from a url.
+#[cfg(feature = "reqwest")]
+pub(crate) const ACCEPT_JSON: &str = "application/json, application/geo+json";
+
+/// Reads JSON from a url, whatever its path looks like.
+///
+/// Servers that pick a format from the request (e.g. STAC API endpoints like
+/// `/collections/an-id`) are asked for JSON via the `Accept` header. If the
+/// response doesn't say it's JSON, its body is still parsed as JSON, and only
+/// if that fails, e.g. for an HTML landing page, is the request retried with
+/// `?f=json`, which many OGC API servers use to select a format.
 #[cfg(feature = "reqwest")]
 fn read_json_from_url<T>(url: Url) -> Result<T>
 where
     T: DeserializeOwned,
 {
-    let response = reqwest::blocking::get(url.clone())?;
-    response.json().map_err(Error::from)
+    use reqwest::header::ACCEPT;
+
+    let client = Client::new();
+    #[cfg(feature = "yaml")]
+    if is_yaml(url.path()) {
+        let bytes = client.get(url).send()?.error_for_status()?.bytes()?;
+        return serde_yaml::from_slice(&bytes).map_err(Error::from);
+    }
+    let response = client.get(url.clone()).header(ACCEPT, ACCEPT_JSON).send()?;
+    if is_json_response(response.headers()) || has_format_query(&url) {
+        return response.json().map_err(Error::from);
+    }
+    let bytes = response.bytes()?;
+    match serde_json::from_slice(&bytes) {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            let fallback = client
+                .get(with_json_format(url))
+                .header(ACCEPT, ACCEPT_JSON)
+                .send()?;
+            if is_json_response(fallback.headers()) {
+                fallback.json().map_err(Error::from)
+            } else {
+                Err(Error::from(err))
+            }
+        }
+    }
+}
+
+/// Returns true if a response's `Content-Type` is JSON, or if it doesn't have one.
+///
+/// Requires the `reqwest` feature.
+///
+/// # Examples
+///
+/// ```
+/// use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+/// let mut headers = HeaderMap::new();
+/// assert!(stac::is_json_response(&headers));
+/// let _ = headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/geo+json"));
+/// assert!(stac::is_json_response(&headers));
+/// let _ = headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+/// assert!(!stac::is_json_response(&headers));
+/// ```
+#[cfg(feature = "reqwest")]
+pub fn is_json_response(headers: &reqwest::header::HeaderMap) -> bool {
+    headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(crate::media_type::is_json)
+        .unwrap_or(true)
+}
+
+#[cfg(feature = "reqwest")]
+fn has_format_query(url: &Url) -> bool {
+    url.query_pairs().any(|(key, _)| key == "f")
+}
+
+#[cfg(feature = "reqwest")]
+fn with_json_format(mut url: Url) -> Url {
+    let _ = url.query_pairs_mut().append_pair("f", "json");
+    url
 }
 
 #[cfg(not(feature = "reqwest"))]
@@ -61,9 +620,28 @@ where
     Err(crate::Error::ReqwestNotEnabled)
 }
 
+#[cfg(feature = "reqwest")]
+fn check_url(url: Url, options: &CheckLinksOptions, client: &mut Option<Client>) -> Result<()> {
+    use reqwest::Method;
+
+    let method = Method::from_bytes(options.method.to_uppercase().as_bytes())
+        .map_err(|_| Error::InvalidMethod(options.method.clone()))?;
+    let client = match client {
+        Some(client) => client,
+        None => client.insert(Client::builder().timeout(options.timeout).build()?),
+    };
+    let _ = client.request(method, url).send()?.error_for_status()?;
+    Ok(())
+}
+
+#[cfg(not(feature = "reqwest"))]
+fn check_url(_: Url, _: &CheckLinksOptions, _: &mut Option<Client>) -> Result<()> {
+    Err(Error::ReqwestNotEnabled)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Catalog, Collection, Item, ItemCollection};
+    use crate::{Catalog, Collection, Item, ItemCollection, ReadContext};
 
     macro_rules! read {
         ($function:ident, $filename:expr, $value:ty) => {
@@ -77,6 +655,47 @@ mod tests {
         };
     }
 
+    #[test]
+    fn read_context_resolve() {
+        let context = ReadContext::new("data");
+        assert_eq!(
+            context.resolve("simple-item").unwrap(),
+            "data/simple-item.json"
+        );
+        assert_eq!(
+            context.resolve("simple-item.json").unwrap(),
+            "data/simple-item.json"
+        );
+        assert_eq!(
+            context.resolve("extensions-collection").unwrap(),
+            "data/extensions-collection"
+        );
+        assert_eq!(context.resolve("/an/item").unwrap(), "/an/item");
+        assert_eq!(
+            context.resolve("http://stac-rs.test/item?f=json").unwrap(),
+            "http://stac-rs.test/item?f=json"
+        );
+        let context = ReadContext {
+            base_href: "http://stac-rs.test/catalog/".to_string(),
+            default_extension: None,
+        };
+        assert_eq!(
+            context.resolve("../items/an-id").unwrap(),
+            "http://stac-rs.test/items/an-id"
+        );
+    }
+
+    #[test]
+    fn read_context_read() {
+        use crate::Href;
+
+        let context = ReadContext::new("data");
+        let item: Item = context.read("simple-item").unwrap();
+        assert!(item.href().unwrap().ends_with("data/simple-item.json"));
+        let catalog: Catalog = context.read("extensions-collection/../").unwrap();
+        assert_eq!(catalog.id, "examples");
+    }
+
     read!(read_item_from_path, "data/simple-item.json", Item);
     read!(read_catalog_from_path, "data/catalog.json", Catalog);
     read!(
@@ -90,9 +709,288 @@ mod tests {
         ItemCollection
     );
 
+    #[test]
+    fn write_with() {
+        use super::SerializeOptions;
+
+        let item: Item = crate::read("data/simple-item.json").unwrap();
+        let path = std::env::temp_dir().join("stac-rs-write-with.json");
+        let options = SerializeOptions {
+            pretty: false,
+            sort_keys: true,
+        };
+        super::write_with(&path, &item, options).unwrap();
+        let string = std::fs::read_to_string(&path).unwrap();
+        assert!(!string.contains('\n'));
+        assert!(string.starts_with(r#"{"assets":{"thumbnail":"#));
+        assert_eq!(
+            crate::read::<Item>(path.to_str().unwrap()).unwrap().id,
+            item.id
+        );
+
+        super::write(&path, &item).unwrap();
+        let string = std::fs::read_to_string(&path).unwrap();
+        assert!(string.contains('\n'));
+        assert!(string.starts_with("{\n  \"stac_extensions\""));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "yaml")]
+    mod yaml {
+        use crate::{Catalog, Href, Item};
+
+        #[test]
+        fn read() {
+            let item: Item = crate::read("examples/simple-item.yaml").unwrap();
+            let expected: Item = crate::read("data/simple-item.json").unwrap();
+            assert_eq!(item.href().unwrap(), "examples/simple-item.yaml");
+            assert_eq!(
+                serde_json::to_value(item).unwrap(),
+                serde_json::to_value(expected).unwrap()
+            );
+        }
+
+        #[test]
+        fn roundtrip() {
+            let catalog: Catalog = crate::read("data/catalog.json").unwrap();
+            let path = std::env::temp_dir().join("stac-rs-write-yaml.yml");
+            crate::write_yaml(&path, &catalog).unwrap();
+            let string = std::fs::read_to_string(&path).unwrap();
+            assert!(string.lines().any(|line| line == "type: Catalog"));
+            let read: Catalog = crate::read(path.to_str().unwrap()).unwrap();
+            std::fs::remove_file(path).unwrap();
+            assert_eq!(
+                serde_json::to_value(read).unwrap(),
+                serde_json::to_value(catalog).unwrap()
+            );
+        }
+
+        #[test]
+        fn invalid() {
+            let path = std::env::temp_dir().join("stac-rs-invalid.yaml");
+            std::fs::write(&path, "type: [Feature").unwrap();
+            let error = crate::read::<Item>(path.to_str().unwrap()).unwrap_err();
+            std::fs::remove_file(path).unwrap();
+            assert!(matches!(error, crate::Error::SerdeYaml(_)));
+        }
+    }
+
+    #[test]
+    fn read_catalog_from_directory() {
+        use crate::Href;
+
+        let catalog: Catalog = crate::read("data").unwrap();
+        assert!(catalog.href().unwrap().ends_with("data/catalog.json"));
+    }
+
+    #[test]
+    fn read_collection_from_directory() {
+        use crate::Href;
+
+        let collection: Collection = crate::read("data/collection-only/").unwrap();
+        assert!(collection
+            .href()
+            .unwrap()
+            .ends_with("data/collection-only/collection.json"));
+    }
+
+    #[test]
+    fn read_json_from_directory() {
+        let value: serde_json::Value = crate::read_json("data").unwrap();
+        assert_eq!(value["type"], "Catalog");
+    }
+
+    #[test]
+    fn read_maybe() {
+        use crate::{Href, MaybeStac};
+
+        let value = crate::read_maybe("data").unwrap().into_stac().unwrap();
+        assert!(value.is_catalog());
+        assert!(value.href().unwrap().ends_with("data/catalog.json"));
+        assert!(matches!(
+            crate::read_maybe("schemas/v1.0.0/catalog.json").unwrap(),
+            MaybeStac::Other(_)
+        ));
+        assert!(crate::read_maybe("not-a-file.json").is_err());
+    }
+
+    #[test]
+    fn read_directory_without_catalog() {
+        assert!(matches!(
+            crate::read::<Catalog>("examples").unwrap_err(),
+            crate::Error::NoCatalogInDirectory(href) if href == "examples"
+        ));
+    }
+
+    #[cfg(feature = "zip")]
+    mod with_zip {
+        use crate::{Href, Links};
+
+        #[test]
+        fn read_from_zip() {
+            let catalog = crate::read_from_zip("examples/catalog.zip", None).unwrap();
+            assert!(catalog.is_catalog());
+            assert_eq!(catalog.href().unwrap(), "catalog.json");
+            assert_eq!(catalog.root_link().unwrap().href, "catalog.json");
+        }
+
+        #[test]
+        fn read_from_zip_resolves_links() {
+            let collection = crate::read_from_zip(
+                "examples/catalog.zip",
+                Some("./extensions-collection/collection.json"),
+            )
+            .unwrap();
+            assert_eq!(
+                collection.href().unwrap(),
+                "extensions-collection/collection.json"
+            );
+            assert_eq!(collection.root_link().unwrap().href, "catalog.json");
+            let item = collection.iter_item_links().next().unwrap();
+            assert_eq!(
+                item.href,
+                "extensions-collection/proj-example/proj-example.json"
+            );
+            let item = crate::read_from_zip("examples/catalog.zip", Some(&item.href)).unwrap();
+            assert!(item.is_item());
+        }
+
+        #[test]
+        fn read_from_zip_missing_entry() {
+            assert!(matches!(
+                crate::read_from_zip("examples/catalog.zip", Some("not-a-file.json")).unwrap_err(),
+                crate::Error::Zip(_)
+            ));
+        }
+    }
+
     #[cfg(feature = "reqwest")]
     mod with_reqwest {
         use crate::{Catalog, Collection, Item};
+        use mockito::{Matcher, Server};
+
+        #[test]
+        fn read_json_without_extension() {
+            let mut server = Server::new();
+            let mock = server
+                .mock("GET", "/collections/an-id")
+                .match_header("accept", super::super::ACCEPT_JSON)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::to_string(&Collection::new("an-id", "a description")).unwrap(),
+                )
+                .create();
+            let collection: Collection =
+                crate::read(format!("{}/collections/an-id", server.url())).unwrap();
+            assert_eq!(collection.id, "an-id");
+            mock.assert();
+        }
+
+        #[test]
+        fn read_json_format_fallback() {
+            let mut server = Server::new();
+            let html = server
+                .mock("GET", "/collections/an-id")
+                .match_query(Matcher::Missing)
+                .with_header("content-type", "text/html")
+                .with_body("<html></html>")
+                .create();
+            let json = server
+                .mock("GET", "/collections/an-id")
+                .match_query(Matcher::UrlEncoded("f".into(), "json".into()))
+                .with_header("content-type", "application/geo+json")
+                .with_body(
+                    serde_json::to_string(&Collection::new("an-id", "a description")).unwrap(),
+                )
+                .create();
+            let collection: Collection =
+                crate::read(format!("{}/collections/an-id", server.url())).unwrap();
+            assert_eq!(collection.id, "an-id");
+            html.assert();
+            json.assert();
+        }
+
+        #[test]
+        fn read_json_body_without_json_content_type() {
+            let mut server = Server::new();
+            let plain = server
+                .mock("GET", "/collections/an-id")
+                .match_query(Matcher::Missing)
+                .with_header("content-type", "text/plain")
+                .with_body(
+                    serde_json::to_string(&Collection::new("an-id", "a description")).unwrap(),
+                )
+                .create();
+            let fallback = server
+                .mock("GET", "/collections/an-id")
+                .match_query(Matcher::UrlEncoded("f".into(), "json".into()))
+                .expect(0)
+                .create();
+            let collection: Collection =
+                crate::read(format!("{}/collections/an-id", server.url())).unwrap();
+            assert_eq!(collection.id, "an-id");
+            plain.assert();
+            fallback.assert();
+        }
+
+        #[test]
+        fn check_links() {
+            use crate::{link::CheckLinksOptions, Link, Links};
+
+            let mut server = Server::new();
+            let found = server.mock("HEAD", "/found.json").create();
+            let missing = server
+                .mock("HEAD", "/missing.json")
+                .with_status(404)
+                .create();
+            let get = server.mock("GET", "/found.json").create();
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::item("./found.json"));
+            catalog.links.push(Link::item("./missing.json"));
+            let base_href = format!("{}/catalog.json", server.url());
+            let results = catalog.check_links(&base_href);
+            assert!(results[0].1.is_ok());
+            assert!(results[1].1.as_ref().unwrap_err().is_not_found());
+            found.assert();
+            missing.assert();
+
+            catalog.links.truncate(1);
+            let options = CheckLinksOptions {
+                method: "get".to_string(),
+                ..Default::default()
+            };
+            assert!(catalog.check_links_with(&base_href, &options)[0].1.is_ok());
+            get.assert();
+            let options = CheckLinksOptions {
+                method: "not a method".to_string(),
+                ..Default::default()
+            };
+            assert!(matches!(
+                catalog.check_links_with(&base_href, &options)[0].1,
+                Err(crate::Error::InvalidMethod(_))
+            ));
+        }
+
+        #[test]
+        fn read_remote_href_that_is_not_http() {
+            let err = crate::read::<Item>("s3://bucket/item.json").unwrap_err();
+            assert!(matches!(err, crate::Error::Reqwest(_)));
+            assert!(!err.is_not_found());
+        }
+
+        #[test]
+        fn exists() {
+            let mut server = Server::new();
+            let found = server.mock("HEAD", "/found.json").create();
+            let gone = server.mock("HEAD", "/gone.json").with_status(410).create();
+            let error = server.mock("HEAD", "/error.json").with_status(500).create();
+            assert!(crate::exists(&format!("{}/found.json", server.url())).unwrap());
+            assert!(!crate::exists(&format!("{}/gone.json", server.url())).unwrap());
+            assert!(crate::exists(&format!("{}/error.json", server.url())).is_err());
+            found.assert();
+            gone.assert();
+            error.assert();
+        }
 
         read!(
             read_item_from_url,
@@ -1,10 +1,51 @@
-use crate::{Asset, Assets, Error, Extensions, Href, Link, Links, Result, STAC_VERSION};
-use chrono::Utc;
+use crate::{
+    classification::{self, Classification},
+    datacube::{self, Datacube},
+    link::{self, CANONICAL_REL},
+    processing::{self, Processing},
+    Asset, Assets, Bbox, Collection, Error, Extensions, Href, Link, Links, RangeReader, Result,
+    STAC_VERSION,
+};
+use chrono::{DateTime, Utc};
 use geojson::Geometry;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 
+/// How far, in degrees, a bbox can be from its geometry's bounds in [Item::check_bbox_matches_geometry].
+#[cfg(feature = "geo")]
+pub const BBOX_TOLERANCE: f64 = 1e-6;
+
+/// The fields that [Item::content_eq] ignores.
+///
+/// These are set automatically by many pipelines, so they change between runs
+/// even when nothing else does.
+pub const TIMESTAMP_FIELDS: [&str; 2] = ["created", "updated"];
+
+/// Extension fields in item properties whose values are numbers, and whether they're integers.
+///
+/// Used by [Item::coerce_numeric_properties].
+const NUMERIC_FIELDS: [(&str, bool); 18] = [
+    ("eo:cloud_cover", false),
+    ("eo:snow_cover", false),
+    ("proj:epsg", true),
+    ("sar:center_frequency", false),
+    ("sar:resolution_range", false),
+    ("sar:resolution_azimuth", false),
+    ("sar:pixel_spacing_range", false),
+    ("sar:pixel_spacing_azimuth", false),
+    ("sar:looks_range", true),
+    ("sar:looks_azimuth", true),
+    ("sar:looks_equivalent_number", false),
+    ("sat:absolute_orbit", true),
+    ("sat:relative_orbit", true),
+    ("view:off_nadir", false),
+    ("view:incidence_angle", false),
+    ("view:azimuth", false),
+    ("view:sun_azimuth", false),
+    ("view:sun_elevation", false),
+];
+
 /// The type field for [Items](Item).
 pub const ITEM_TYPE: &str = "Feature";
 
@@ -15,7 +56,12 @@ pub const ITEM_TYPE: &str = "Feature";
 /// `Item` is the core object in a STAC catalog, containing the core metadata that
 /// enables any client to search or crawl online catalogs of spatial 'assets'
 /// (e.g., satellite imagery, derived data, DEMs).
+///
+/// Cloning an `Item` is a deep copy: the clone, including its properties,
+/// assets, `additional_fields`, and href, shares no state with the original.
+/// `Item` is `Send` and `Sync`, so clones can be handed to other threads.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Item {
     /// A list of extensions the `Item` implements.
     #[serde(rename = "stac_extensions")]
@@ -35,6 +81,7 @@ pub struct Item {
     /// geometries can be included. Coordinates are specified in
     /// Longitude/Latitude or Longitude/Latitude/Elevation based on [WGS
     /// 84](http://www.opengis.net/def/crs/OGC/1.3/CRS84).
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Value>"))]
     pub geometry: Option<Geometry>,
 
     /// Bounding Box of the asset represented by this `Item`, formatted according
@@ -83,7 +130,13 @@ pub struct Item {
 }
 
 /// Additional metadata fields can be added to the GeoJSON Object Properties.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+///
+/// The common metadata fields, e.g. `gsd` and `instruments`, are typed. When
+/// deserializing, a common metadata field with an unexpected type (e.g.
+/// `"gsd": "30"`) is kept as-is in `additional_fields` instead of being an
+/// error, so it round-trips unchanged.
+#[derive(Debug, Serialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Properties {
     /// The searchable date and time of the assets, which must be in UTC.
     ///
@@ -91,6 +144,38 @@ pub struct Properties {
     /// requires `start_datetime` and `end_datetime` from common metadata to be set.
     pub datetime: Option<String>,
 
+    /// A human-readable title of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// A detailed, multi-line description of the item, which may use CommonMark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Keywords that describe the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+
+    /// Unique name of the specific platform to which the instrument is attached.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+
+    /// Name of instrument or sensor used (e.g., MODIS, ASTER, OLI, Canon F-1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instruments: Option<Vec<String>>,
+
+    /// Name of the constellation to which the platform belongs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constellation: Option<String>,
+
+    /// Name of the mission for which data is collected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mission: Option<String>,
+
+    /// Ground Sample Distance at the sensor, in meters (m), must be greater than 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gsd: Option<f64>,
+
     /// Additional fields on the properties.
     #[serde(flatten)]
     pub additional_fields: Map<String, Value>,
@@ -100,11 +185,208 @@ impl Default for Properties {
     fn default() -> Properties {
         Properties {
             datetime: Some(Utc::now().to_rfc3339()),
+            title: None,
+            description: None,
+            keywords: None,
+            platform: None,
+            instruments: None,
+            constellation: None,
+            mission: None,
+            gsd: None,
             additional_fields: Map::new(),
         }
     }
 }
 
+impl<'de> Deserialize<'de> for Properties {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Properties, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let mut properties = Properties {
+            datetime: None,
+            title: None,
+            description: None,
+            keywords: None,
+            platform: None,
+            instruments: None,
+            constellation: None,
+            mission: None,
+            gsd: None,
+            additional_fields: Map::new(),
+        };
+        for (key, value) in Map::<String, Value>::deserialize(deserializer)? {
+            let is_typed = match key.as_str() {
+                "datetime" => {
+                    properties.datetime =
+                        Option::deserialize(value).map_err(serde::de::Error::custom)?;
+                    continue;
+                }
+                "title" => set_common_metadata(&mut properties.title, &value),
+                "description" => set_common_metadata(&mut properties.description, &value),
+                "keywords" => set_common_metadata(&mut properties.keywords, &value),
+                "platform" => set_common_metadata(&mut properties.platform, &value),
+                "instruments" => set_common_metadata(&mut properties.instruments, &value),
+                "constellation" => set_common_metadata(&mut properties.constellation, &value),
+                "mission" => set_common_metadata(&mut properties.mission, &value),
+                "gsd" => set_common_metadata(&mut properties.gsd, &value),
+                _ => false,
+            };
+            if !is_typed {
+                let _ = properties.additional_fields.insert(key, value);
+            }
+        }
+        Ok(properties)
+    }
+}
+
+impl Properties {
+    /// Merges another set of properties into these properties.
+    ///
+    /// Additional fields are deep-merged: nested objects are merged key by key,
+    /// and for any other conflicting value (including arrays) `other` wins. If
+    /// `other` has a `datetime`, or any of the typed common metadata fields
+    /// (e.g. `gsd`), it replaces the value in these properties; a `None` in
+    /// `other` leaves the value unchanged.
+    ///
+    /// Note that [Properties::default] sets `datetime` to now, so build `other`
+    /// with `datetime: None` unless you mean to replace the datetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Properties;
+    /// let mut properties = Properties {
+    ///     datetime: Some("2023-01-01T00:00:00Z".to_string()),
+    ///     platform: Some("landsat-8".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let other = Properties {
+    ///     datetime: None,
+    ///     gsd: Some(30.0),
+    ///     ..Default::default()
+    /// };
+    /// properties.merge(&other);
+    /// assert_eq!(properties.datetime.unwrap(), "2023-01-01T00:00:00Z");
+    /// assert_eq!(properties.platform.unwrap(), "landsat-8");
+    /// assert_eq!(properties.gsd.unwrap(), 30.0);
+    /// ```
+    pub fn merge(&mut self, other: &Properties) {
+        if let Some(datetime) = other.datetime.as_ref() {
+            self.datetime = Some(datetime.clone());
+        }
+        if let Some(title) = other.title.as_ref() {
+            self.title = Some(title.clone());
+        }
+        if let Some(description) = other.description.as_ref() {
+            self.description = Some(description.clone());
+        }
+        if let Some(keywords) = other.keywords.as_ref() {
+            self.keywords = Some(keywords.clone());
+        }
+        if let Some(platform) = other.platform.as_ref() {
+            self.platform = Some(platform.clone());
+        }
+        if let Some(instruments) = other.instruments.as_ref() {
+            self.instruments = Some(instruments.clone());
+        }
+        if let Some(constellation) = other.constellation.as_ref() {
+            self.constellation = Some(constellation.clone());
+        }
+        if let Some(mission) = other.mission.as_ref() {
+            self.mission = Some(mission.clone());
+        }
+        if let Some(gsd) = other.gsd {
+            self.gsd = Some(gsd);
+        }
+        merge_fields(&mut self.additional_fields, &other.additional_fields);
+    }
+
+    /// Returns the title, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let item: Item = stac::read("data/core-item.json").unwrap();
+    /// assert_eq!(item.properties.title().unwrap(), "Core Item");
+    /// ```
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Sets the title.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Properties;
+    /// let mut properties = Properties::default();
+    /// properties.set_title("A title");
+    /// assert_eq!(properties.title().unwrap(), "A title");
+    /// ```
+    pub fn set_title(&mut self, title: impl ToString) {
+        self.title = Some(title.to_string());
+    }
+
+    /// Returns the description, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let item: Item = stac::read("data/simple-item.json").unwrap();
+    /// assert!(item.properties.description().is_none());
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Sets the description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Properties;
+    /// let mut properties = Properties::default();
+    /// properties.set_description("A description");
+    /// assert_eq!(properties.description().unwrap(), "A description");
+    /// ```
+    pub fn set_description(&mut self, description: impl ToString) {
+        self.description = Some(description.to_string());
+    }
+
+    /// Returns the keywords, which are empty if there aren't any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Properties;
+    /// assert!(Properties::default().keywords().is_empty());
+    /// ```
+    pub fn keywords(&self) -> &[String] {
+        self.keywords.as_deref().unwrap_or_default()
+    }
+
+    /// Sets the keywords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Properties;
+    /// let mut properties = Properties::default();
+    /// properties.set_keywords(["landsat", "usgs"]);
+    /// assert_eq!(properties.keywords(), ["landsat", "usgs"]);
+    /// ```
+    pub fn set_keywords<I, K>(&mut self, keywords: I)
+    where
+        I: IntoIterator<Item = K>,
+        K: ToString,
+    {
+        self.keywords = Some(keywords.into_iter().map(|k| k.to_string()).collect());
+    }
+}
+
 impl Item {
     /// Creates a new `Item` with the given `id`.
     ///
@@ -162,129 +444,2254 @@ impl Item {
     pub fn collection_link(&self) -> Option<&Link> {
         self.links.iter().find(|link| link.is_collection())
     }
-}
 
-impl Href for Item {
-    fn href(&self) -> Option<&str> {
-        self.href.as_deref()
+    /// Returns this item's canonical link.
+    ///
+    /// This is the first link with a rel="canonical", which points to the
+    /// authoritative location of an item that's in more than one catalog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// assert!(item.canonical_link().is_none());
+    /// item.set_canonical("https://stac.test/an-id.json");
+    /// assert_eq!(item.canonical_link().unwrap().href, "https://stac.test/an-id.json");
+    /// ```
+    pub fn canonical_link(&self) -> Option<&Link> {
+        self.links.iter().find(|link| link.is_canonical())
     }
 
-    fn set_href(&mut self, href: impl ToString) {
-        self.href = Some(href.to_string())
+    /// Sets the href of this item's canonical link.
+    ///
+    /// The first canonical link is updated in place (or a new one is added),
+    /// its media type is set to JSON, and all other canonical links are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// item.set_canonical("./a.json");
+    /// item.set_canonical("./b.json");
+    /// assert_eq!(item.links.len(), 1);
+    /// assert_eq!(item.canonical_link().unwrap().href, "./b.json");
+    /// ```
+    pub fn set_canonical(&mut self, href: impl ToString) {
+        link::set_singleton_href(&mut self.links, CANONICAL_REL, href.to_string())
     }
-}
 
-impl Links for Item {
-    fn links(&self) -> &[Link] {
-        &self.links
-    }
-    fn links_mut(&mut self) -> &mut Vec<Link> {
-        &mut self.links
+    /// Returns the href of this item's canonical link, resolved against this item's href.
+    ///
+    /// Returns `None` if there's no canonical link. If the canonical href is
+    /// relative and this item doesn't have an href, it's returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Href, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.set_href("https://stac.test/mirror/an-id.json");
+    /// item.set_canonical("../an-id.json");
+    /// assert_eq!(
+    ///     item.canonical_href().unwrap().unwrap(),
+    ///     "https://stac.test/an-id.json"
+    /// );
+    /// ```
+    pub fn canonical_href(&self) -> Result<Option<String>> {
+        match (self.canonical_link(), self.href()) {
+            (Some(link), Some(href)) => link.absolute_href(href).map(Some),
+            (Some(link), None) => Ok(Some(link.href.clone())),
+            (None, _) => Ok(None),
+        }
     }
-}
 
-impl Assets for Item {
-    fn assets(&self) -> &HashMap<String, Asset> {
-        &self.assets
-    }
-    fn assets_mut(&mut self) -> &mut HashMap<String, Asset> {
-        &mut self.assets
+    /// Merges properties into this item's properties.
+    ///
+    /// See [Properties::merge] for how conflicts are resolved, including why
+    /// `datetime` should usually be `None` in the merged properties.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Item, Properties};
+    /// let mut item = Item::new("an-id");
+    /// let datetime = item.properties.datetime.clone();
+    /// let properties = Properties {
+    ///     datetime: None,
+    ///     gsd: Some(30.0),
+    ///     ..Default::default()
+    /// };
+    /// item.merge_properties(properties);
+    /// assert_eq!(item.properties.datetime, datetime);
+    /// assert_eq!(item.properties.gsd.unwrap(), 30.0);
+    /// ```
+    pub fn merge_properties(&mut self, properties: Properties) {
+        self.properties.merge(&properties)
     }
-}
 
-impl Extensions for Item {
-    fn extensions(&self) -> Option<&[String]> {
-        self.extensions.as_deref()
+    /// Returns the value of a field for one of this item's assets.
+    ///
+    /// Extension fields (e.g. `eo:bands` or `proj:epsg`) can be set on the
+    /// item's properties or on individual assets. If the field is set on the
+    /// asset, the asset's value wins. Otherwise, the value from the item's
+    /// properties is returned. Returns `None` if there is no asset with the
+    /// given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Asset, Item};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert("proj:epsg".to_string(), 4326.into());
+    /// let mut asset = Asset::new("an-href");
+    /// let _ = asset.additional_fields.insert("proj:epsg".to_string(), 32621.into());
+    /// let _ = item.assets.insert("data".to_string(), asset);
+    /// assert_eq!(item.asset_field("data", "proj:epsg").unwrap(), 32621);
+    /// ```
+    pub fn asset_field(&self, key: &str, field: &str) -> Option<&Value> {
+        self.assets.get(key).and_then(|asset| {
+            asset
+                .additional_fields
+                .get(field)
+                .or_else(|| self.properties.additional_fields.get(field))
+        })
     }
-}
 
-impl TryFrom<Item> for Map<String, Value> {
-    type Error = Error;
-    fn try_from(item: Item) -> Result<Self> {
-        if let serde_json::Value::Object(object) = serde_json::to_value(item)? {
-            Ok(object)
-        } else {
-            panic!("all STAC items should serialize to a serde_json::Value::Object")
+    /// Fills in missing asset metadata from a collection's `item_assets`.
+    ///
+    /// The [item assets](https://github.com/stac-extensions/item-assets)
+    /// extension lets a collection describe the assets its items have, so the
+    /// same titles, roles, and so on don't need to be repeated in every item.
+    /// For each of this item's assets with a matching key in `item_assets`,
+    /// fields that the asset doesn't have (e.g. `title`, `roles`, `type`, or
+    /// `eo:bands`) are copied from the definition. Fields already on the asset
+    /// are kept, and hrefs are never changed. Returns an error if an
+    /// `item_assets` definition can't be read as an asset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use stac::{Asset, Collection, Item};
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// let _ = collection.additional_fields.insert(
+    ///     "item_assets".to_string(),
+    ///     json!({"data": {"title": "Data", "roles": ["data"]}}),
+    /// );
+    /// let mut item = Item::new("an-id");
+    /// let mut asset = Asset::new("./data.tif");
+    /// asset.title = Some("My data".to_string());
+    /// let _ = item.assets.insert("data".to_string(), asset);
+    /// item.enrich_from_item_assets(&collection).unwrap();
+    /// assert_eq!(item.assets["data"].title.as_deref().unwrap(), "My data");
+    /// assert_eq!(item.assets["data"].roles.as_ref().unwrap(), &["data"]);
+    /// ```
+    pub fn enrich_from_item_assets(&mut self, collection: &Collection) -> Result<()> {
+        let item_assets = match collection
+            .additional_fields
+            .get("item_assets")
+            .and_then(Value::as_object)
+        {
+            Some(item_assets) => item_assets,
+            None => return Ok(()),
+        };
+        for (key, asset) in self.assets.iter_mut() {
+            let mut definition = match item_assets.get(key).and_then(Value::as_object) {
+                Some(definition) => definition.clone(),
+                None => continue,
+            };
+            let _ = definition.insert("href".to_string(), asset.href.clone().into());
+            let definition: Asset = serde_json::from_value(Value::Object(definition))?;
+            if asset.title.is_none() {
+                asset.title = definition.title;
+            }
+            if asset.description.is_none() {
+                asset.description = definition.description;
+            }
+            if asset.r#type.is_none() {
+                asset.r#type = definition.r#type;
+            }
+            if asset.roles.is_none() {
+                asset.roles = definition.roles;
+            }
+            for (field, value) in definition.additional_fields {
+                let _ = asset.additional_fields.entry(field).or_insert(value);
+            }
         }
+        Ok(())
     }
-}
 
-impl TryFrom<Map<String, Value>> for Item {
-    type Error = serde_json::Error;
-    fn try_from(map: Map<String, Value>) -> std::result::Result<Self, Self::Error> {
-        serde_json::from_value(Value::Object(map))
+    /// Deserializes an array property into a vector of typed values.
+    ///
+    /// Properties can hold arbitrarily nested values, e.g. the timestamps and
+    /// positions of a moving platform. They're stored losslessly in the
+    /// properties' `additional_fields`, and this is a typed way to read them.
+    /// Returns an error if the property isn't set, or if it isn't an array of
+    /// values that deserialize to `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// use serde_json::json;
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert(
+    ///     "positions".to_string(),
+    ///     json!([[-105.1, 41.1], [-105.2, 41.2]]),
+    /// );
+    /// let positions: Vec<[f64; 2]> = item.property_array("positions").unwrap();
+    /// assert_eq!(positions[1], [-105.2, 41.2]);
+    /// assert!(item.property_array::<f64>("times").is_err());
+    /// ```
+    pub fn property_array<T>(&self, key: &str) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let value = self
+            .properties
+            .additional_fields
+            .get(key)
+            .ok_or_else(|| Error::MissingField(key.to_string()))?;
+        Vec::<T>::deserialize(value).map_err(Error::from)
     }
-}
-
-fn deserialize_type<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    crate::deserialize_type(deserializer, ITEM_TYPE)
-}
-
-fn serialize_type<S>(r#type: &String, serializer: S) -> std::result::Result<S::Ok, S::Error>
-where
-    S: serde::ser::Serializer,
-{
-    crate::serialize_type(r#type, serializer, ITEM_TYPE)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Item;
-    use crate::STAC_VERSION;
-    use serde_json::Value;
 
-    #[test]
-    fn new() {
-        let item = Item::new("an-id");
-        assert_eq!(item.geometry, None);
-        assert!(item.properties.datetime.is_some());
-        assert!(item.assets.is_empty());
-        assert!(item.collection.is_none());
-        assert_eq!(item.r#type, "Feature");
-        assert_eq!(item.version, STAC_VERSION);
-        assert!(item.extensions.is_none());
-        assert_eq!(item.id, "an-id");
-        assert!(item.links.is_empty());
+    /// Returns a reference to the value at a [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) into this item's additional fields.
+    ///
+    /// Pointers are written as if into the item's serialized form, but only
+    /// values that are stored as JSON can be reached, so this reaches into
+    /// three subtrees: the item's additional fields (e.g. `/custom`), its
+    /// properties' additional fields (e.g. `/properties/custom`), and its
+    /// assets' additional fields (e.g. `/assets/data/custom`). Typed fields
+    /// like `/id`, `/bbox`, `/properties/datetime`, or `/assets/data/href`, the
+    /// empty pointer, and the `/properties` and `/assets/data` objects
+    /// themselves return `None`. [Item::pointer_mut] reaches the same values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// let _ = item
+    ///     .properties
+    ///     .additional_fields
+    ///     .insert("custom".to_string(), json!({"nested": [1, 2, 3]}));
+    /// assert_eq!(item.pointer("/properties/custom/nested/1").unwrap(), 2);
+    /// assert!(item.pointer("/id").is_none());
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        let (token, rest) = split_pointer(pointer)?;
+        let (fields, rest) = match token.as_str() {
+            "properties" => (&self.properties.additional_fields, rest),
+            "assets" => {
+                let (key, rest) = split_pointer(rest)?;
+                (&self.assets.get(&key)?.additional_fields, rest)
+            }
+            _ => (&self.additional_fields, pointer),
+        };
+        let (key, rest) = split_pointer(rest)?;
+        fields.get(&key)?.pointer(rest)
     }
 
-    #[test]
-    fn skip_serializing() {
-        let item = Item::new("an-id");
-        let value = serde_json::to_value(item).unwrap();
-        assert!(value.get("stac_extensions").is_none());
-        assert!(value.get("bbox").is_none());
-        assert!(value.get("collection").is_none());
+    /// Returns a mutable reference to the value at a JSON pointer into this item's additional fields.
+    ///
+    /// See [Item::pointer] for which values can be reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use stac::{Asset, Item};
+    /// let mut item = Item::new("an-id");
+    /// let mut asset = Asset::new("an-href");
+    /// let _ = asset.additional_fields.insert("custom".to_string(), json!({"a": 1}));
+    /// let _ = item.assets.insert("data".to_string(), asset);
+    /// *item.pointer_mut("/assets/data/custom/a").unwrap() = json!(2);
+    /// assert_eq!(item.assets["data"].additional_fields["custom"]["a"], 2);
+    /// assert!(item.pointer_mut("/id").is_none());
+    /// ```
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        let (token, rest) = split_pointer(pointer)?;
+        let (fields, rest) = match token.as_str() {
+            "properties" => (&mut self.properties.additional_fields, rest),
+            "assets" => {
+                let (key, rest) = split_pointer(rest)?;
+                (&mut self.assets.get_mut(&key)?.additional_fields, rest)
+            }
+            _ => (&mut self.additional_fields, pointer),
+        };
+        let (key, rest) = split_pointer(rest)?;
+        fields.get_mut(&key)?.pointer_mut(rest)
     }
 
-    #[test]
-    fn deserialize_invalid_type_field() {
-        let mut item: Value = crate::read_json("data/simple-item.json").unwrap();
-        item["type"] = "Item".into(); // must be "Feature"
-        assert!(serde_json::from_value::<Item>(item).is_err());
+    /// Returns true if two items are equal, ignoring their timestamps and hrefs.
+    ///
+    /// The [TIMESTAMP_FIELDS] (`created` and `updated`) are ignored in the
+    /// properties and in every asset. Use [Item::content_eq_ignoring] to
+    /// choose the ignored fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Href, Item};
+    /// let mut a = Item::new("an-id");
+    /// let _ = a
+    ///     .properties
+    ///     .additional_fields
+    ///     .insert("created".to_string(), "2023-01-01T00:00:00Z".into());
+    /// let mut b = a.clone();
+    /// let _ = b
+    ///     .properties
+    ///     .additional_fields
+    ///     .insert("created".to_string(), "2023-01-02T00:00:00Z".into());
+    /// b.set_href("an-href.json");
+    /// assert!(a != b);
+    /// assert!(a.content_eq(&b));
+    /// ```
+    pub fn content_eq(&self, other: &Item) -> bool {
+        self.content_eq_ignoring(other, &TIMESTAMP_FIELDS)
     }
 
-    #[test]
-    fn serialize_invalid_type_field() {
-        let mut item = Item::new("an-id");
-        item.r#type = "Item".to_string(); // must be "Feature"
-        assert!(serde_json::to_value(item).is_err());
+    /// Returns true if two items are equal, ignoring their hrefs and some fields.
+    ///
+    /// `fields` are ignored in the properties and in every asset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let a = Item::new("an-id");
+    /// let mut b = a.clone();
+    /// b.properties.datetime = Some("2023-01-01T00:00:00Z".to_string());
+    /// assert!(!a.content_eq(&b));
+    /// assert!(a.content_eq_ignoring(&b, &["datetime"]));
+    /// ```
+    pub fn content_eq_ignoring(&self, other: &Item, fields: &[&str]) -> bool {
+        let without_fields = |item: &Item| {
+            let mut value = serde_json::to_value(item).ok()?;
+            let object = value.as_object_mut()?;
+            let mut objects = Vec::new();
+            for (key, value) in object.iter_mut() {
+                match (key.as_str(), value) {
+                    ("properties", Value::Object(properties)) => objects.push(properties),
+                    ("assets", Value::Object(assets)) => {
+                        objects.extend(assets.values_mut().filter_map(Value::as_object_mut))
+                    }
+                    _ => {}
+                }
+            }
+            for object in objects {
+                for field in fields {
+                    let _ = object.remove(*field);
+                }
+            }
+            Some(value)
+        };
+        match (without_fields(self), without_fields(other)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        }
     }
 
-    mod roundtrip {
-        use super::Item;
-        use crate::tests::roundtrip;
-
-        roundtrip!(simple_item, "data/simple-item.json", Item);
-        roundtrip!(extended_item, "data/extended-item.json", Item);
-        roundtrip!(core_item, "data/core-item.json", Item);
+    /// Checks that this item fits within a collection's declared extent and summaries.
+    ///
+    /// This catches items that were filed into the wrong collection. The
+    /// checks are:
+    ///
+    /// - the item's `collection`, if set, is the collection's id
+    /// - the item's datetime, or its `start_datetime` and `end_datetime`, is
+    ///   within the collection's overall temporal interval
+    /// - the item's bbox (or, if it doesn't have one, the bbox of its geometry)
+    ///   is within the collection's overall spatial bbox
+    /// - each property with a range summary (e.g. `eo:cloud_cover`) is within
+    ///   the range, and each property with a list of summarized values (e.g.
+    ///   `platform`) is one of those values
+    ///
+    /// Returns [Error::CollectionMismatch] with a message for every check
+    /// that fails. Returns an error if the collection's temporal extent isn't
+    /// made of RFC 3339 datetimes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Collection, Item};
+    /// let item: Item = stac::read("data/simple-item.json").unwrap();
+    /// let collection: Collection = stac::read("data/collection.json").unwrap();
+    /// item.validate_against_collection(&collection).unwrap();
+    ///
+    /// let mut collection = Collection::new("another-collection", "a description");
+    /// collection.extent.spatial.bbox = vec![vec![0.0, 0.0, 1.0, 1.0]];
+    /// let err = item.validate_against_collection(&collection).unwrap_err();
+    /// ```
+    pub fn validate_against_collection(&self, collection: &Collection) -> Result<()> {
+        let mut violations = Vec::new();
+        if let Some(id) = self.collection.as_deref() {
+            if id != collection.id {
+                violations.push(format!(
+                    "item is in collection {}, not {}",
+                    id, collection.id
+                ));
+            }
+        }
+
+        let (start, end) = collection.extent.temporal.overall_interval()?;
+        let parse = |datetime: Option<&str>| {
+            datetime
+                .and_then(|datetime| DateTime::parse_from_rfc3339(datetime).ok())
+                .map(|datetime| datetime.with_timezone(&Utc))
+        };
+        let datetime = parse(self.properties.datetime.as_deref());
+        let field = |key: &str| {
+            parse(
+                self.properties
+                    .additional_fields
+                    .get(key)
+                    .and_then(Value::as_str),
+            )
+        };
+        let item_start = datetime.or_else(|| field("start_datetime"));
+        let item_end = datetime.or_else(|| field("end_datetime"));
+        if let (Some(item_start), Some(start)) = (item_start, start) {
+            if item_start < start {
+                violations.push(format!(
+                    "item starts at {}, before the collection's temporal extent starts at {}",
+                    item_start.to_rfc3339(),
+                    start.to_rfc3339()
+                ));
+            }
+        }
+        if let (Some(item_end), Some(end)) = (item_end, end) {
+            if item_end > end {
+                violations.push(format!(
+                    "item ends at {}, after the collection's temporal extent ends at {}",
+                    item_end.to_rfc3339(),
+                    end.to_rfc3339()
+                ));
+            }
+        }
+
+        if let (Some(bbox), Some(overall)) = (
+            self.bbox.clone().or_else(|| self.compute_bbox()),
+            collection.extent.spatial.overall_bbox(),
+        ) {
+            if !crate::collection::bbox_contains(overall, &bbox) {
+                violations.push(format!(
+                    "bbox {:?} is not within the collection's spatial extent {:?}",
+                    bbox, overall
+                ));
+            }
+        }
+
+        if let Some(summaries) = collection.summaries.as_ref() {
+            if let Value::Object(properties) = serde_json::to_value(&self.properties)? {
+                for (key, summary) in summaries {
+                    if let Some(value) = properties.get(key) {
+                        violations.extend(check_summary(key, value, summary));
+                    }
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::CollectionMismatch(violations))
+        }
+    }
+
+    /// Converts string values of known numeric extension fields in this item's properties to numbers.
+    ///
+    /// Some producers write numbers as strings, e.g. `"eo:cloud_cover":
+    /// "12.5"`, which doesn't validate and can't be used in numeric
+    /// comparisons. This is opt-in. Only fields from the eo, projection, sar,
+    /// sat, and view extensions whose values are numbers are converted;
+    /// integer fields (e.g. `proj:epsg`) only accept integer strings. Unknown
+    /// fields, and strings that can't be parsed, are left as-is. Returns the
+    /// keys of the fields that were converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// let _ = item
+    ///     .properties
+    ///     .additional_fields
+    ///     .insert("eo:cloud_cover".to_string(), "12.5".into());
+    /// assert_eq!(item.coerce_numeric_properties(), ["eo:cloud_cover"]);
+    /// assert_eq!(item.properties.additional_fields["eo:cloud_cover"], 12.5);
+    /// ```
+    pub fn coerce_numeric_properties(&mut self) -> Vec<String> {
+        let mut coerced = Vec::new();
+        for (key, is_integer) in NUMERIC_FIELDS {
+            let value = match self.properties.additional_fields.get_mut(key) {
+                Some(value) => value,
+                None => continue,
+            };
+            let number = match value.as_str().map(str::trim) {
+                Some(s) if is_integer => s.parse::<i64>().ok().map(serde_json::Number::from),
+                Some(s) => s.parse::<f64>().ok().and_then(serde_json::Number::from_f64),
+                None => None,
+            };
+            if let Some(number) = number {
+                *value = Value::Number(number);
+                coerced.push(key.to_string());
+            }
+        }
+        coerced
+    }
+
+    /// Returns a single datetime that can be used to order items in time.
+    ///
+    /// In order of precedence, this is:
+    ///
+    /// 1. `datetime`
+    /// 2. the midpoint between `start_datetime` and `end_datetime`
+    /// 3. `start_datetime`
+    /// 4. `end_datetime`
+    ///
+    /// Values that aren't valid RFC 3339 datetimes are skipped. Returns `None`
+    /// if none of these are set and valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// item.properties.datetime = None;
+    /// assert!(item.sort_datetime().is_none());
+    /// let _ = item.properties.additional_fields.insert(
+    ///     "start_datetime".to_string(),
+    ///     "2023-01-01T00:00:00Z".into(),
+    /// );
+    /// let _ = item.properties.additional_fields.insert(
+    ///     "end_datetime".to_string(),
+    ///     "2023-01-03T00:00:00Z".into(),
+    /// );
+    /// assert_eq!(
+    ///     item.sort_datetime().unwrap().to_rfc3339(),
+    ///     "2023-01-02T00:00:00+00:00"
+    /// );
+    /// ```
+    pub fn sort_datetime(&self) -> Option<DateTime<Utc>> {
+        let parse = |datetime: &str| {
+            DateTime::parse_from_rfc3339(datetime)
+                .ok()
+                .map(|datetime| datetime.with_timezone(&Utc))
+        };
+        let field = |key: &str| {
+            self.properties
+                .additional_fields
+                .get(key)
+                .and_then(|value| value.as_str())
+                .and_then(parse)
+        };
+        if let Some(datetime) = self.properties.datetime.as_deref().and_then(parse) {
+            return Some(datetime);
+        }
+        match (field("start_datetime"), field("end_datetime")) {
+            (Some(start), Some(end)) => Some(start + (end - start) / 2),
+            (start, end) => start.or(end),
+        }
+    }
+
+    /// Returns the [classification](crate::classification) fields of this item's properties, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item = stac::Item::new("an-id");
+    /// assert!(item.classification().unwrap().is_none());
+    /// ```
+    pub fn classification(&self) -> Result<Option<Classification>> {
+        Classification::from_fields(&self.properties.additional_fields)
+    }
+
+    /// Sets the [classification](crate::classification) fields of this item's properties.
+    ///
+    /// The extension's schema is added to this item's extensions. An empty
+    /// classification removes the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{classification::{Class, Classification}, Item};
+    /// let mut item = Item::new("an-id");
+    /// let mut classification = Classification::default();
+    /// classification.classes.push(Class::new(1, "water"));
+    /// item.set_classification(classification).unwrap();
+    /// assert!(item.classification().unwrap().is_some());
+    /// ```
+    pub fn set_classification(&mut self, classification: Classification) -> Result<()> {
+        if !classification.is_empty() {
+            self.add_extension(classification::SCHEMA_URI);
+        }
+        classification.into_fields(&mut self.properties.additional_fields)
+    }
+
+    /// Returns the [processing](crate::processing) fields of this item's properties, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let item: stac::Item = stac::read("examples/processing.json").unwrap();
+    /// assert_eq!(item.processing().unwrap().unwrap().level.unwrap(), "L2");
+    /// ```
+    pub fn processing(&self) -> Result<Option<Processing>> {
+        Processing::from_fields(&self.properties.additional_fields)
+    }
+
+    /// Sets the [processing](crate::processing) fields of this item's properties.
+    ///
+    /// The extension's schema is added to this item's extensions. Empty
+    /// processing removes the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{processing::Processing, Item};
+    /// let mut item = Item::new("an-id");
+    /// let processing = Processing {
+    ///     level: Some("L2".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// item.set_processing(processing).unwrap();
+    /// assert!(item.processing().unwrap().is_some());
+    /// ```
+    pub fn set_processing(&mut self, processing: Processing) -> Result<()> {
+        if !processing.is_empty() {
+            self.add_extension(processing::SCHEMA_URI);
+        }
+        processing.into_fields(&mut self.properties.additional_fields)
+    }
+
+    /// Returns the [datacube](crate::datacube) fields of this item's properties, if it has any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let item = Item::new("an-id");
+    /// assert!(item.datacube().unwrap().is_none());
+    /// ```
+    pub fn datacube(&self) -> Result<Option<Datacube>> {
+        Datacube::from_fields(&self.properties.additional_fields)
+    }
+
+    /// Sets the [datacube](crate::datacube) fields of this item's properties.
+    ///
+    /// The extension's schema is added to this item's extensions. An empty
+    /// datacube removes the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{datacube::{Datacube, Dimension}, Item};
+    /// let mut item = Item::new("an-id");
+    /// let mut datacube = Datacube::default();
+    /// let _ = datacube.dimensions.insert("time".to_string(), Dimension::new("temporal"));
+    /// item.set_datacube(datacube).unwrap();
+    /// assert!(item.properties.additional_fields.contains_key("cube:dimensions"));
+    /// ```
+    pub fn set_datacube(&mut self, datacube: Datacube) -> Result<()> {
+        if !datacube.is_empty() {
+            self.add_extension(datacube::SCHEMA_URI);
+        }
+        datacube.into_fields(&mut self.properties.additional_fields)
+    }
+
+    /// Reads a byte range of an asset with a [RangeReader].
+    ///
+    /// A relative asset href is resolved against this item's href, if it has
+    /// one, as in [Link::absolute_href]. Returns [Error::MissingAsset] if there's no asset with this key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Asset, Item};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.assets.insert("data".to_string(), Asset::new("data.tif"));
+    /// let reader = |href: &str, range: std::ops::Range<u64>| -> stac::Result<Vec<u8>> {
+    ///     assert_eq!(href, "data.tif");
+    ///     Ok(vec![0; (range.end - range.start) as usize])
+    /// };
+    /// assert_eq!(item.read_asset_range("data", 0..4, &reader).unwrap().len(), 4);
+    /// ```
+    pub fn read_asset_range(
+        &self,
+        key: &str,
+        range: std::ops::Range<u64>,
+        reader: &impl RangeReader,
+    ) -> Result<Vec<u8>> {
+        let asset = self
+            .assets
+            .get(key)
+            .ok_or_else(|| Error::MissingAsset(key.to_string()))?;
+        let href = match self.href.as_deref() {
+            Some(base) => Link::new(&asset.href, "").absolute_href(base)?,
+            None => asset.href.clone(),
+        };
+        reader.read_range(&href, range)
+    }
+
+    /// Reads an asset's header with a [RangeReader].
+    ///
+    /// The header's size comes from the asset's
+    /// [file:header_size](crate::file::File::header_size) field. Returns
+    /// [Error::MissingField] if it isn't set. See [Item::read_asset_range].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let item: Item = stac::read("examples/file.json").unwrap();
+    /// let reader = |href: &str, range: std::ops::Range<u64>| -> stac::Result<Vec<u8>> {
+    ///     assert!(href.ends_with("examples/data.tif"));
+    ///     Ok(vec![0; (range.end - range.start) as usize])
+    /// };
+    /// assert_eq!(item.read_asset_header("data", &reader).unwrap().len(), 16384);
+    /// ```
+    pub fn read_asset_header(&self, key: &str, reader: &impl RangeReader) -> Result<Vec<u8>> {
+        let header_size = self
+            .assets
+            .get(key)
+            .ok_or_else(|| Error::MissingAsset(key.to_string()))?
+            .file()?
+            .and_then(|file| file.header_size)
+            .ok_or_else(|| Error::MissingField("file:header_size".to_string()))?;
+        self.read_asset_range(key, 0..header_size, reader)
+    }
+
+    /// Returns this item's bbox as a [Bbox], or its geometry's bounds if it has no bbox.
+    ///
+    /// The `bbox` field is used if it's set. Otherwise, the bounds are
+    /// computed from the geometry as in [Item::compute_bbox]. Returns `None`
+    /// if there's neither a bbox nor a geometry with positions, and
+    /// [Error::InvalidBbox] if the bbox doesn't have four or six values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Bbox, Geometry, Item};
+    /// let mut item = Item::new("an-id");
+    /// assert!(item.bbox_or_geometry_bounds().unwrap().is_none());
+    /// item.geometry = Some(Geometry::new(geojson::Value::Point(vec![-105.0, 40.0])));
+    /// assert_eq!(item.bbox_or_geometry_bounds().unwrap().unwrap().xmin(), -105.0);
+    /// item.set_bbox(Bbox::TwoDimensional([-106.0, 40.0, -104.0, 41.0]));
+    /// assert_eq!(item.bbox_or_geometry_bounds().unwrap().unwrap().xmin(), -106.0);
+    /// ```
+    pub fn bbox_or_geometry_bounds(&self) -> Result<Option<Bbox>> {
+        match self.bbox.as_deref() {
+            Some(bbox) => Bbox::try_from(bbox).map(Some),
+            None => self.compute_bbox().map(Bbox::try_from).transpose(),
+        }
+    }
+
+    /// Sets this item's bbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Bbox, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.set_bbox(Bbox::TwoDimensional([-105.0, 40.0, -104.0, 41.0]));
+    /// assert_eq!(item.bbox.unwrap(), [-105.0, 40.0, -104.0, 41.0]);
+    /// ```
+    pub fn set_bbox(&mut self, bbox: Bbox) {
+        self.bbox = Some(bbox.into());
+    }
+
+    /// Computes a bbox from this item's geometry.
+    ///
+    /// The bbox is 3D if every position in the geometry has an elevation, and
+    /// 2D otherwise. Returns `None` if there's no geometry, or if the geometry
+    /// has no positions.
+    ///
+    /// The bbox of a `GeometryCollection`, including nested collections, is
+    /// the union of its members' bboxes. If the members are split across the
+    /// antimeridian, e.g. one ends at 180° and another starts at -180°, the
+    /// bbox crosses the antimeridian, i.e. its west is greater than its east.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Geometry, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.geometry = Some(Geometry::new(geojson::Value::LineString(vec![
+    ///     vec![-105.0, 40.0],
+    ///     vec![-104.0, 41.0],
+    /// ])));
+    /// assert_eq!(item.compute_bbox().unwrap(), vec![-105.0, 40.0, -104.0, 41.0]);
+    /// ```
+    pub fn compute_bbox(&self) -> Option<Vec<f64>> {
+        let value = &self.geometry.as_ref()?.value;
+        let mut positions = Vec::new();
+        collect_positions(value, &mut positions);
+        let dims = if positions.iter().all(|position| position.len() >= 3) {
+            3
+        } else {
+            2
+        };
+        let mut bbox = vec![f64::INFINITY; dims];
+        bbox.extend(vec![f64::NEG_INFINITY; dims]);
+        for position in &positions {
+            if position.len() < 2 {
+                continue;
+            }
+            for i in 0..dims {
+                bbox[i] = bbox[i].min(position[i]);
+                bbox[dims + i] = bbox[dims + i].max(position[i]);
+            }
+        }
+        if !bbox.iter().all(|value| value.is_finite()) {
+            return None;
+        }
+        if let geojson::Value::GeometryCollection(_) = value {
+            let mut ranges = Vec::new();
+            collect_longitude_ranges(value, &mut ranges);
+            if let Some((west, east)) = union_longitude_ranges(ranges) {
+                bbox[0] = west;
+                bbox[dims] = east;
+            }
+        }
+        Some(bbox)
+    }
+
+    /// Sets this item's bbox from its geometry, if the bbox is missing.
+    ///
+    /// Per the spec, an item with a geometry should have a bbox, so this is a
+    /// useful repair step for items from producers that leave it out. An
+    /// existing bbox is left as-is, even if it doesn't match the geometry; use
+    /// [Item::compute_bbox] to check it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Geometry, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.geometry = Some(Geometry::new(geojson::Value::Point(vec![-105.0, 40.0])));
+    /// item.ensure_bbox();
+    /// assert_eq!(item.bbox.unwrap(), vec![-105.0, 40.0, -105.0, 40.0]);
+    /// ```
+    pub fn ensure_bbox(&mut self) {
+        if self.bbox.is_none() {
+            self.bbox = self.compute_bbox();
+        }
+    }
+
+    /// Returns the centroid of this item's geometry.
+    ///
+    /// If the item has no geometry but does have a bbox, the center of the bbox
+    /// is returned. The center of a bbox that crosses the antimeridian is on
+    /// the far side of the globe, e.g. `[170, 0, -170, 2]` is centered on
+    /// `(180, 1)`. Requires the `geo` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// assert!(item.centroid().is_none());
+    /// item.bbox = Some(vec![0.0, 0.0, 2.0, 4.0]);
+    /// assert_eq!(item.centroid().unwrap(), geo::Point::new(1.0, 2.0));
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn centroid(&self) -> Option<geo::Point<f64>> {
+        use geo::Centroid;
+
+        if self.geometry.is_some() {
+            return self.geo_geometry()?.centroid();
+        }
+        let bbox = Bbox::try_from(self.bbox.as_deref()?).ok()?;
+        let (west, mut east) = (bbox.xmin(), bbox.xmax());
+        if west > east {
+            east += 360.0;
+        }
+        let mut x = (west + east) / 2.0;
+        if x > 180.0 {
+            x -= 360.0;
+        }
+        Some(geo::Point::new(x, (bbox.ymin() + bbox.ymax()) / 2.0))
+    }
+
+    /// Returns the geodesic area of this item's geometry, in square meters.
+    ///
+    /// If the item has no geometry but does have a bbox, the area of the bbox
+    /// polygon is returned. A bbox that crosses the antimeridian is split into
+    /// two polygons, one on each side, so its area isn't the area of the rest
+    /// of the globe. Requires the `geo` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Item;
+    /// let mut item = Item::new("an-id");
+    /// assert!(item.area().is_none());
+    /// item.bbox = Some(vec![0.0, 0.0, 1.0, 1.0]);
+    /// let area = item.area().unwrap();
+    /// assert!((area - 12_308_778_361.).abs() < 1e3);
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn area(&self) -> Option<f64> {
+        use geo::GeodesicArea;
+
+        Some(self.geo_geometry()?.geodesic_area_unsigned())
+    }
+
+    /// Returns this item's geometry, simplified with the Douglas–Peucker algorithm.
+    ///
+    /// Points closer than `tolerance` (in the geometry's units, usually
+    /// degrees) to the simplified geometry may be removed. The geometry type is
+    /// kept, and polygon rings keep at least four positions so they don't
+    /// collapse. Points and multi-points are returned unchanged. Simplified
+    /// positions are two-dimensional. Returns `None` if the item doesn't have a
+    /// geometry. Requires the `geo` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Geometry, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.geometry = Some(Geometry::new(geojson::Value::LineString(vec![
+    ///     vec![0.0, 0.0],
+    ///     vec![1.0, 0.01],
+    ///     vec![2.0, 0.0],
+    /// ])));
+    /// let geometry = item.simplified_geometry(0.1).unwrap();
+    /// assert_eq!(
+    ///     geometry.value,
+    ///     geojson::Value::LineString(vec![vec![0.0, 0.0], vec![2.0, 0.0]])
+    /// );
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn simplified_geometry(&self, tolerance: f64) -> Option<Geometry> {
+        let mut geometry = self.geometry.clone()?;
+        if let Ok(value) = geo::Geometry::try_from(geometry.value.clone()) {
+            geometry.value = geojson::Value::from(&simplify(value, tolerance));
+        }
+        Some(geometry)
+    }
+
+    /// Simplifies this item's geometry in place, as in [Item::simplified_geometry].
+    ///
+    /// The bbox is left as-is, since simplifying never grows the geometry.
+    /// Requires the `geo` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Geometry, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.geometry = Some(Geometry::new(geojson::Value::LineString(vec![
+    ///     vec![0.0, 0.0],
+    ///     vec![1.0, 0.01],
+    ///     vec![2.0, 0.0],
+    /// ])));
+    /// item.simplify_in_place(0.1);
+    /// assert_eq!(
+    ///     item.geometry.unwrap().value,
+    ///     geojson::Value::LineString(vec![vec![0.0, 0.0], vec![2.0, 0.0]])
+    /// );
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn simplify_in_place(&mut self, tolerance: f64) {
+        if let Some(geometry) = self.simplified_geometry(tolerance) {
+            self.geometry = Some(geometry);
+        }
+    }
+
+    /// Checks that this item's bbox matches the bounds of its geometry.
+    ///
+    /// A bbox that doesn't match is usually stale, e.g. left over after the
+    /// geometry was edited, which schema validation can't catch. Only the
+    /// horizontal bounds are compared, and each can be off by up to
+    /// [BBOX_TOLERANCE] degrees. Bboxes that cross the antimeridian (with a
+    /// west bound greater than their east bound) are only checked for their
+    /// south and north bounds. Items without a bbox or without a geometry
+    /// pass. Returns [Error::BboxMismatch] otherwise. Requires the `geo`
+    /// feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Geometry, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.geometry = Some(Geometry::new(geojson::Value::Point(vec![-105.0, 40.0])));
+    /// item.bbox = Some(vec![-105.0, 40.0, -105.0, 40.0]);
+    /// item.check_bbox_matches_geometry().unwrap();
+    /// item.geometry = Some(Geometry::new(geojson::Value::Point(vec![-104.0, 40.0])));
+    /// assert!(item.check_bbox_matches_geometry().is_err());
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn check_bbox_matches_geometry(&self) -> Result<()> {
+        use geo::BoundingRect;
+
+        let (bbox, geometry) = match (self.bbox.as_ref(), self.geometry.as_ref()) {
+            (Some(bbox), Some(geometry)) => (bbox, geometry),
+            _ => return Ok(()),
+        };
+        let rect = match geo::Geometry::try_from(geometry.value.clone())
+            .ok()
+            .and_then(|geometry| geometry.bounding_rect())
+        {
+            Some(rect) => rect,
+            None => return Ok(()),
+        };
+        let bounds = vec![rect.min().x, rect.min().y, rect.max().x, rect.max().y];
+        let horizontal = match bbox.len() {
+            4 => [bbox[0], bbox[1], bbox[2], bbox[3]],
+            6 => [bbox[0], bbox[1], bbox[3], bbox[4]],
+            _ => {
+                return Err(Error::BboxMismatch {
+                    bbox: bbox.clone(),
+                    bounds,
+                })
+            }
+        };
+        let crosses_antimeridian = horizontal[0] > horizontal[2];
+        let matches = horizontal
+            .iter()
+            .zip(&bounds)
+            .enumerate()
+            .filter(|(i, _)| !crosses_antimeridian || i % 2 == 1)
+            .all(|(_, (a, b))| (a - b).abs() <= BBOX_TOLERANCE);
+        if matches {
+            Ok(())
+        } else {
+            Err(Error::BboxMismatch {
+                bbox: bbox.clone(),
+                bounds,
+            })
+        }
+    }
+
+    #[cfg(feature = "geo")]
+    fn geo_geometry(&self) -> Option<geo::Geometry<f64>> {
+        if let Some(geometry) = self.geometry.as_ref() {
+            geo::Geometry::try_from(geometry.value.clone()).ok()
+        } else {
+            let bbox = Bbox::try_from(self.bbox.as_deref()?).ok()?;
+            let polygon =
+                |west, east| geo::Rect::new((west, bbox.ymin()), (east, bbox.ymax())).to_polygon();
+            if bbox.xmin() > bbox.xmax() {
+                Some(
+                    geo::MultiPolygon::new(vec![
+                        polygon(bbox.xmin(), 180.0),
+                        polygon(-180.0, bbox.xmax()),
+                    ])
+                    .into(),
+                )
+            } else {
+                Some(polygon(bbox.xmin(), bbox.xmax()).into())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "geo")]
+fn simplify(geometry: geo::Geometry<f64>, tolerance: f64) -> geo::Geometry<f64> {
+    use geo::{Geometry, GeometryCollection, Simplify};
+
+    match geometry {
+        Geometry::LineString(line_string) => line_string.simplify(&tolerance).into(),
+        Geometry::MultiLineString(multi_line_string) => {
+            multi_line_string.simplify(&tolerance).into()
+        }
+        Geometry::Polygon(polygon) => polygon.simplify(&tolerance).into(),
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon.simplify(&tolerance).into(),
+        Geometry::GeometryCollection(collection) => {
+            Geometry::GeometryCollection(GeometryCollection::new_from(
+                collection
+                    .into_iter()
+                    .map(|geometry| simplify(geometry, tolerance))
+                    .collect(),
+            ))
+        }
+        geometry => geometry,
+    }
+}
+
+impl Href for Item {
+    fn href(&self) -> Option<&str> {
+        self.href.as_deref()
+    }
+
+    fn set_href(&mut self, href: &str) {
+        self.href = Some(href.to_string())
+    }
+}
+
+impl Links for Item {
+    fn links(&self) -> &[Link] {
+        &self.links
+    }
+    fn links_mut(&mut self) -> &mut Vec<Link> {
+        &mut self.links
+    }
+}
+
+impl Assets for Item {
+    fn assets(&self) -> &HashMap<String, Asset> {
+        &self.assets
+    }
+    fn assets_mut(&mut self) -> &mut HashMap<String, Asset> {
+        &mut self.assets
+    }
+}
+
+impl Extensions for Item {
+    fn extensions(&self) -> Option<&[String]> {
+        self.extensions.as_deref()
+    }
+    fn extensions_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.extensions
+    }
+}
+
+impl TryFrom<Item> for geojson::Feature {
+    type Error = Error;
+    fn try_from(item: Item) -> Result<Self> {
+        if let Value::Object(properties) = serde_json::to_value(item.properties)? {
+            Ok(geojson::Feature {
+                bbox: item.bbox,
+                geometry: item.geometry,
+                id: Some(geojson::feature::Id::String(item.id)),
+                properties: Some(properties),
+                foreign_members: None,
+            })
+        } else {
+            panic!("all STAC item properties should serialize to a serde_json::Value::Object")
+        }
+    }
+}
+
+impl TryFrom<Item> for Map<String, Value> {
+    type Error = Error;
+    fn try_from(item: Item) -> Result<Self> {
+        if let serde_json::Value::Object(object) = serde_json::to_value(item)? {
+            Ok(object)
+        } else {
+            panic!("all STAC items should serialize to a serde_json::Value::Object")
+        }
+    }
+}
+
+impl TryFrom<Map<String, Value>> for Item {
+    type Error = serde_json::Error;
+    fn try_from(map: Map<String, Value>) -> std::result::Result<Self, Self::Error> {
+        serde_json::from_value(Value::Object(map))
+    }
+}
+
+fn collect_positions<'a>(value: &'a geojson::Value, positions: &mut Vec<&'a [f64]>) {
+    use geojson::Value::*;
+    match value {
+        Point(position) => positions.push(position),
+        MultiPoint(line) | LineString(line) => positions.extend(line.iter().map(Vec::as_slice)),
+        MultiLineString(lines) | Polygon(lines) => {
+            positions.extend(lines.iter().flat_map(|line| line.iter().map(Vec::as_slice)))
+        }
+        MultiPolygon(polygons) => positions.extend(
+            polygons
+                .iter()
+                .flatten()
+                .flat_map(|line| line.iter().map(Vec::as_slice)),
+        ),
+        GeometryCollection(geometries) => {
+            for geometry in geometries {
+                collect_positions(&geometry.value, positions);
+            }
+        }
+    }
+}
+
+fn collect_longitude_ranges(value: &geojson::Value, ranges: &mut Vec<(f64, f64)>) {
+    if let geojson::Value::GeometryCollection(geometries) = value {
+        for geometry in geometries {
+            collect_longitude_ranges(&geometry.value, ranges);
+        }
+    } else {
+        let mut positions = Vec::new();
+        collect_positions(value, &mut positions);
+        let longitudes = positions
+            .iter()
+            .filter(|position| position.len() >= 2)
+            .map(|position| position[0]);
+        let (west, east) = longitudes.fold((f64::INFINITY, f64::NEG_INFINITY), |(w, e), x| {
+            (w.min(x), e.max(x))
+        });
+        if west.is_finite() && east.is_finite() {
+            ranges.push((west, east));
+        }
+    }
+}
+
+/// Returns the smallest (west, east) range that covers all of the ranges.
+///
+/// The covering range is the complement of the largest gap between the
+/// ranges, going around the globe, so it crosses the antimeridian if the
+/// largest gap doesn't.
+pub(crate) fn union_longitude_ranges(mut ranges: Vec<(f64, f64)>) -> Option<(f64, f64)> {
+    ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut merged: Vec<(f64, f64)> = Vec::with_capacity(ranges.len());
+    for (west, east) in ranges {
+        match merged.last_mut() {
+            Some(last) if west <= last.1 => last.1 = last.1.max(east),
+            _ => merged.push((west, east)),
+        }
+    }
+    let first = merged.first()?;
+    let last = merged.last()?;
+    let mut bounds = (first.0, last.1);
+    let mut largest_gap = first.0 + 360.0 - last.1;
+    for pair in merged.windows(2) {
+        let gap = pair[1].0 - pair[0].1;
+        if gap > largest_gap {
+            largest_gap = gap;
+            bounds = (pair[1].0, pair[0].1);
+        }
+    }
+    Some(bounds)
+}
+
+fn check_summary(key: &str, value: &Value, summary: &Value) -> Option<String> {
+    match summary {
+        Value::Object(range) => {
+            let value = value.as_f64()?;
+            let minimum = range.get("minimum").and_then(Value::as_f64);
+            let maximum = range.get("maximum").and_then(Value::as_f64);
+            if minimum.map(|minimum| value < minimum).unwrap_or(false)
+                || maximum.map(|maximum| value > maximum).unwrap_or(false)
+            {
+                Some(format!(
+                    "{} is {}, which is outside of the collection's summary range {}",
+                    key,
+                    value,
+                    Value::Object(range.clone())
+                ))
+            } else {
+                None
+            }
+        }
+        Value::Array(values) => {
+            let items = match value {
+                Value::Array(items) => items.as_slice(),
+                _ => std::slice::from_ref(value),
+            };
+            if items.iter().all(|item| values.contains(item)) {
+                None
+            } else {
+                Some(format!(
+                    "{} is {}, which is not one of the collection's summarized values {}",
+                    key, value, summary
+                ))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Splits the first reference token, unescaped, from a JSON pointer.
+fn split_pointer(pointer: &str) -> Option<(String, &str)> {
+    let pointer = pointer.strip_prefix('/')?;
+    let (token, rest) = pointer.split_at(pointer.find('/').unwrap_or(pointer.len()));
+    Some((token.replace("~1", "/").replace("~0", "~"), rest))
+}
+
+fn merge_fields(fields: &mut Map<String, Value>, other: &Map<String, Value>) {
+    for (key, value) in other {
+        match (fields.get_mut(key), value) {
+            (Some(Value::Object(object)), Value::Object(other)) => merge_fields(object, other),
+            _ => {
+                let _ = fields.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Sets a common metadata field, returning false if the value doesn't have the expected type.
+fn set_common_metadata<T>(field: &mut Option<T>, value: &Value) -> bool
+where
+    T: DeserializeOwned,
+{
+    *field = T::deserialize(value).ok();
+    field.is_some()
+}
+
+fn deserialize_type<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    crate::deserialize_type(deserializer, ITEM_TYPE)
+}
+
+fn serialize_type<S>(r#type: &String, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::ser::Serializer,
+{
+    crate::serialize_type(r#type, serializer, ITEM_TYPE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Item;
+    use crate::{Asset, STAC_VERSION};
+    use serde_json::{json, Value};
+
+    #[test]
+    fn canonical() {
+        use crate::{Href, Link};
+
+        let mut item = Item::new("an-id");
+        assert!(item.canonical_href().unwrap().is_none());
+        item.links.push(Link::new("./a.json", "canonical"));
+        item.links.push(Link::root("./catalog.json"));
+        item.links.push(Link::new("./b.json", "canonical"));
+        assert_eq!(item.canonical_href().unwrap().unwrap(), "./a.json");
+        item.set_canonical("./c.json");
+        assert_eq!(item.links.len(), 2);
+        assert!(item.links[0].is_canonical());
+        assert_eq!(item.links[0].href, "./c.json");
+        item.set_href("/stac/mirror/an-id.json");
+        assert_eq!(
+            item.canonical_href().unwrap().unwrap(),
+            "/stac/mirror/c.json"
+        );
+        item.set_canonical("https://stac.test/an-id.json");
+        assert_eq!(
+            item.canonical_href().unwrap().unwrap(),
+            "https://stac.test/an-id.json"
+        );
+    }
+
+    #[test]
+    fn new() {
+        let item = Item::new("an-id");
+        assert_eq!(item.geometry, None);
+        assert!(item.properties.datetime.is_some());
+        assert!(item.assets.is_empty());
+        assert!(item.collection.is_none());
+        assert_eq!(item.r#type, "Feature");
+        assert_eq!(item.version, STAC_VERSION);
+        assert!(item.extensions.is_none());
+        assert_eq!(item.id, "an-id");
+        assert!(item.links.is_empty());
+    }
+
+    #[test]
+    fn content_eq() {
+        use crate::Href;
+
+        let mut a: Item = crate::read("data/simple-item.json").unwrap();
+        let _ = a
+            .properties
+            .additional_fields
+            .insert("created".to_string(), "2023-01-01T00:00:00Z".into());
+        let mut b = a.clone();
+        b.set_href("another-href.json");
+        let _ = b
+            .properties
+            .additional_fields
+            .insert("updated".to_string(), "2023-01-02T00:00:00Z".into());
+        let _ = b
+            .assets
+            .get_mut("thumbnail")
+            .unwrap()
+            .additional_fields
+            .insert("created".to_string(), "2023-01-03T00:00:00Z".into());
+        assert!(a.content_eq(&b));
+        assert!(b.content_eq(&a));
+
+        b.properties.set_title("a new title");
+        assert!(!a.content_eq(&b));
+        assert!(a.content_eq_ignoring(&b, &["created", "updated", "title"]));
+        assert!(!a.content_eq_ignoring(&b, &[]));
+    }
+
+    #[test]
+    fn pointer() {
+        let mut item = Item::new("an-id");
+        let _ = item
+            .additional_fields
+            .insert("top/level".to_string(), json!({"a~b": [true]}));
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("custom".to_string(), json!({"nested": {"value": 42}}));
+        item.properties.gsd = Some(30.0);
+        item.bbox = Some(vec![-105.1, 41.1, -105.0, 41.2]);
+        let mut asset = Asset::new("an-href");
+        let _ = asset
+            .additional_fields
+            .insert("raster:bands".to_string(), json!([{"nodata": 0}]));
+        let _ = item.assets.insert("data".to_string(), asset);
+
+        assert_eq!(item.pointer("/top~1level/a~0b/0").unwrap(), true);
+        assert_eq!(
+            item.pointer("/properties/custom/nested").unwrap(),
+            &json!({"value": 42})
+        );
+        assert_eq!(
+            item.pointer("/assets/data/raster:bands/0/nodata").unwrap(),
+            0
+        );
+        for missing in [
+            "",
+            "/",
+            "/id",
+            "/bbox/2",
+            "/properties",
+            "/properties/gsd",
+            "/properties/datetime",
+            "/assets/data",
+            "/assets/data/href",
+            "/geometry/coordinates",
+            "/assets/thumbnail/custom",
+            "properties/custom",
+        ] {
+            assert!(item.pointer(missing).is_none(), "{}", missing);
+        }
+    }
+
+    #[test]
+    fn pointer_mut() {
+        let mut item = Item::new("an-id");
+        let _ = item
+            .additional_fields
+            .insert("top".to_string(), json!({"a": 1}));
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("custom".to_string(), json!({"nested": {"value": 42}}));
+        let mut asset = Asset::new("an-href");
+        let _ = asset
+            .additional_fields
+            .insert("raster:bands".to_string(), json!([{"nodata": 0}]));
+        let _ = item.assets.insert("data".to_string(), asset);
+
+        *item.pointer_mut("/top/a").unwrap() = json!(2);
+        *item.pointer_mut("/properties/custom/nested/value").unwrap() = json!(43);
+        *item
+            .pointer_mut("/assets/data/raster:bands/0/nodata")
+            .unwrap() = json!(-1);
+        assert_eq!(item.additional_fields["top"]["a"], 2);
+        assert_eq!(
+            item.properties.additional_fields["custom"]["nested"]["value"],
+            43
+        );
+        assert_eq!(
+            item.assets["data"].additional_fields["raster:bands"][0]["nodata"],
+            -1
+        );
+        for pointer in [
+            "",
+            "/",
+            "/id",
+            "/bbox",
+            "/properties",
+            "/properties/datetime",
+            "/properties/missing",
+            "/assets/data",
+            "/assets/data/href",
+            "properties/custom",
+        ] {
+            assert!(item.pointer_mut(pointer).is_none(), "{}", pointer);
+        }
+    }
+
+    #[test]
+    fn validate_against_collection() {
+        use crate::{Collection, Error};
+
+        let mut item = Item::new("an-id");
+        item.collection = Some("another-collection".to_string());
+        item.bbox = Some(vec![170.0, 0.0, -170.0, 1.0]);
+        item.properties.datetime = Some("2022-12-31T00:00:00Z".to_string());
+        item.properties.platform = Some("landsat-9".to_string());
+        item.properties.instruments = Some(vec!["oli".to_string()]);
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("eo:cloud_cover".to_string(), 50.into());
+        let mut collection = Collection::new("a-collection", "a description");
+        collection.extent.temporal.interval =
+            vec![[Some("2023-01-01T00:00:00Z".to_string()), None]];
+        collection.extent.spatial.bbox = vec![vec![-180.0, -90.0, 180.0, 90.0]];
+        collection.summaries = Some(
+            json!({
+                "eo:cloud_cover": {"minimum": 0, "maximum": 10},
+                "platform": ["landsat-8"],
+                "instruments": ["oli", "tirs"],
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        );
+        match item.validate_against_collection(&collection).unwrap_err() {
+            Error::CollectionMismatch(violations) => {
+                assert_eq!(violations.len(), 4, "{:?}", violations);
+                assert!(violations[0].contains("another-collection"));
+                assert!(violations[1].contains("before"));
+                assert!(violations.iter().any(|v| v.starts_with("eo:cloud_cover")));
+                assert!(violations.iter().any(|v| v.starts_with("platform")));
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+
+        item.collection = Some("a-collection".to_string());
+        item.properties.datetime = Some("2023-01-01T00:00:00Z".to_string());
+        item.properties.platform = Some("landsat-8".to_string());
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("eo:cloud_cover".to_string(), 10.into());
+        item.validate_against_collection(&collection).unwrap();
+
+        collection.extent.spatial.bbox = vec![vec![-10.0, -10.0, 10.0, 10.0]];
+        assert!(item.validate_against_collection(&collection).is_err());
+    }
+
+    #[test]
+    fn coerce_numeric_properties() {
+        use serde_json::json;
+
+        let mut item = Item::new("an-id");
+        let fields = &mut item.properties.additional_fields;
+        let _ = fields.insert("eo:cloud_cover".to_string(), " 12.5 ".into());
+        let _ = fields.insert("proj:epsg".to_string(), "32617".into());
+        let _ = fields.insert("sat:relative_orbit".to_string(), "12.5".into());
+        let _ = fields.insert("view:off_nadir".to_string(), "NaN".into());
+        let _ = fields.insert("view:azimuth".to_string(), 42.into());
+        let _ = fields.insert("view:sun_azimuth".to_string(), "not a number".into());
+        let _ = fields.insert("foo:bar".to_string(), "12".into());
+        assert_eq!(
+            item.coerce_numeric_properties(),
+            ["eo:cloud_cover", "proj:epsg"]
+        );
+        let fields = &item.properties.additional_fields;
+        assert_eq!(fields["eo:cloud_cover"], json!(12.5));
+        assert_eq!(fields["proj:epsg"], json!(32617));
+        assert_eq!(fields["sat:relative_orbit"], "12.5");
+        assert_eq!(fields["view:off_nadir"], "NaN");
+        assert_eq!(fields["view:azimuth"], 42);
+        assert_eq!(fields["view:sun_azimuth"], "not a number");
+        assert_eq!(fields["foo:bar"], "12");
+        assert!(item.coerce_numeric_properties().is_empty());
+    }
+
+    #[test]
+    fn sort_datetime() {
+        let mut item = Item::new("an-id");
+        item.properties.datetime = Some("2023-01-01T12:00:00-06:00".to_string());
+        assert_eq!(
+            item.sort_datetime().unwrap().to_rfc3339(),
+            "2023-01-01T18:00:00+00:00"
+        );
+        item.properties.datetime = Some("not a datetime".to_string());
+        assert!(item.sort_datetime().is_none());
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("end_datetime".to_string(), "2023-01-03T00:00:00Z".into());
+        assert_eq!(
+            item.sort_datetime().unwrap().to_rfc3339(),
+            "2023-01-03T00:00:00+00:00"
+        );
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("start_datetime".to_string(), "2023-01-02T00:00:00Z".into());
+        assert_eq!(
+            item.sort_datetime().unwrap().to_rfc3339(),
+            "2023-01-02T12:00:00+00:00"
+        );
+        let _ = item.properties.additional_fields.remove("end_datetime");
+        assert_eq!(
+            item.sort_datetime().unwrap().to_rfc3339(),
+            "2023-01-02T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn read_asset_range() {
+        use crate::Error;
+        use std::ops::Range;
+
+        let item: Item = crate::read("examples/file.json").unwrap();
+        let reader = |href: &str, range: Range<u64>| -> crate::Result<Vec<u8>> {
+            Ok(format!("{} {:?}", href, range).into_bytes())
+        };
+        let bytes = item.read_asset_range("data", 8..16, &reader).unwrap();
+        assert!(bytes.ends_with(b"/examples/data.tif 8..16"));
+        let bytes = item.read_asset_header("data", &reader).unwrap();
+        assert!(bytes.ends_with(b"/examples/data.tif 0..16384"));
+        assert!(matches!(
+            item.read_asset_range("not-an-asset", 0..1, &reader)
+                .unwrap_err(),
+            Error::MissingAsset(key) if key == "not-an-asset"
+        ));
+
+        let mut item = Item::new("an-id");
+        let _ = item
+            .assets
+            .insert("data".to_string(), Asset::new("data.tif"));
+        assert!(matches!(
+            item.read_asset_header("data", &reader).unwrap_err(),
+            Error::MissingField(field) if field == "file:header_size"
+        ));
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        use crate::Href;
+
+        let mut item = Item::new("an-id");
+        item.set_href("an-href");
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("foo".to_string(), "bar".into());
+        let _ = item
+            .additional_fields
+            .insert("nested".to_string(), serde_json::json!({"a": [1, 2]}));
+        let _ = item
+            .assets
+            .insert("data".to_string(), Asset::new("data.tif"));
+        let mut clone = item.clone();
+        assert_eq!(clone, item);
+        assert_eq!(clone.href(), Some("an-href"));
+
+        clone.set_href("another-href");
+        let _ = clone
+            .properties
+            .additional_fields
+            .insert("foo".to_string(), "baz".into());
+        clone.additional_fields["nested"]["a"][0] = 42.into();
+        clone.assets.get_mut("data").unwrap().href = "other.tif".to_string();
+        assert_eq!(item.href(), Some("an-href"));
+        assert_eq!(item.properties.additional_fields["foo"], "bar");
+        assert_eq!(item.additional_fields["nested"]["a"][0], 1);
+        assert_eq!(item.assets["data"].href, "data.tif");
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Item>();
+    }
+
+    #[test]
+    fn skip_serializing() {
+        let item = Item::new("an-id");
+        let value = serde_json::to_value(item).unwrap();
+        assert!(value.get("stac_extensions").is_none());
+        assert!(value.get("bbox").is_none());
+        assert!(value.get("collection").is_none());
+    }
+
+    mod bbox {
+        use super::Item;
+        use serde_json::json;
+
+        fn item(bbox: Option<serde_json::Value>) -> Item {
+            let mut value = json!({
+                "type": "Feature",
+                "stac_version": "1.0.0",
+                "id": "an-id",
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[[-105.0, 40.0], [-104.0, 40.0], [-104.0, 41.0], [-105.0, 40.0]]]
+                },
+                "properties": {"datetime": "2023-01-01T00:00:00Z"},
+                "links": [],
+                "assets": {}
+            });
+            if let Some(bbox) = bbox {
+                let _ = value
+                    .as_object_mut()
+                    .unwrap()
+                    .insert("bbox".to_string(), bbox);
+            }
+            serde_json::from_value(value).unwrap()
+        }
+
+        #[test]
+        fn null() {
+            let mut item = item(Some(serde_json::Value::Null));
+            assert!(item.bbox.is_none());
+            item.ensure_bbox();
+            assert_eq!(item.bbox.unwrap(), vec![-105.0, 40.0, -104.0, 41.0]);
+        }
+
+        #[test]
+        fn absent() {
+            let mut item = item(None);
+            assert!(item.bbox.is_none());
+            item.ensure_bbox();
+            assert_eq!(item.bbox.unwrap(), vec![-105.0, 40.0, -104.0, 41.0]);
+        }
+
+        #[test]
+        fn stale() {
+            let mut item = item(Some(json!([0.0, 0.0, 1.0, 1.0])));
+            item.ensure_bbox();
+            assert_eq!(item.bbox.as_ref().unwrap(), &vec![0.0, 0.0, 1.0, 1.0]);
+            assert_eq!(
+                item.compute_bbox().unwrap(),
+                vec![-105.0, 40.0, -104.0, 41.0]
+            );
+        }
+
+        #[test]
+        fn no_geometry() {
+            let mut item = Item::new("an-id");
+            item.ensure_bbox();
+            assert!(item.bbox.is_none());
+        }
+
+        fn collection(geometries: Vec<geojson::Value>) -> geojson::Value {
+            geojson::Value::GeometryCollection(
+                geometries.into_iter().map(geojson::Geometry::new).collect(),
+            )
+        }
+
+        fn rectangle(west: f64, south: f64, east: f64, north: f64) -> geojson::Value {
+            geojson::Value::Polygon(vec![vec![
+                vec![west, south],
+                vec![east, south],
+                vec![east, north],
+                vec![west, north],
+                vec![west, south],
+            ]])
+        }
+
+        #[test]
+        fn geometry_collection_disjoint() {
+            let mut item = Item::new("an-id");
+            item.geometry = Some(geojson::Geometry::new(collection(vec![
+                rectangle(-105.0, 40.0, -104.0, 41.0),
+                collection(vec![geojson::Value::Point(vec![10.0, -5.0])]),
+                rectangle(20.0, 0.0, 21.0, 1.0),
+            ])));
+            assert_eq!(item.compute_bbox().unwrap(), vec![-105.0, -5.0, 21.0, 41.0]);
+        }
+
+        #[test]
+        fn geometry_collection_antimeridian() {
+            let mut item = Item::new("an-id");
+            item.geometry = Some(geojson::Geometry::new(collection(vec![
+                rectangle(170.0, -10.0, 180.0, 10.0),
+                collection(vec![rectangle(-180.0, -5.0, -170.0, 5.0)]),
+            ])));
+            assert_eq!(
+                item.compute_bbox().unwrap(),
+                vec![170.0, -10.0, -170.0, 10.0]
+            );
+        }
+
+        #[test]
+        fn three_dimensions() {
+            let mut item = Item::new("an-id");
+            item.geometry = Some(geojson::Geometry::new(geojson::Value::MultiPoint(vec![
+                vec![-105.0, 40.0, 1.0],
+                vec![-104.0, 41.0, 2.0],
+            ])));
+            assert_eq!(
+                item.compute_bbox().unwrap(),
+                vec![-105.0, 40.0, 1.0, -104.0, 41.0, 2.0]
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_invalid_type_field() {
+        let mut item: Value = crate::read_json("data/simple-item.json").unwrap();
+        item["type"] = "Item".into(); // must be "Feature"
+        assert!(serde_json::from_value::<Item>(item).is_err());
+    }
+
+    #[test]
+    fn deserialize_invalid_geometry_type() {
+        let mut item: Value = crate::read_json("data/simple-item.json").unwrap();
+        item["geometry"]["type"] = "Pentagon".into();
+        assert!(serde_json::from_value::<Item>(item).is_err());
+    }
+
+    #[test]
+    fn serialize_invalid_type_field() {
+        let mut item = Item::new("an-id");
+        item.r#type = "Item".to_string(); // must be "Feature"
+        assert!(serde_json::to_value(item).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn centroid_and_area() {
+        use crate::Geometry;
+
+        let mut item = Item::new("an-id");
+        assert!(item.centroid().is_none());
+        assert!(item.area().is_none());
+        item.bbox = Some(vec![0.0, 0.0, 0.0, 2.0, 2.0, 10.0]);
+        assert_eq!(item.centroid().unwrap(), geo::Point::new(1.0, 1.0));
+        let bbox_area = item.area().unwrap();
+        assert!(bbox_area > 0.0);
+        item.geometry = Some(Geometry::new(geojson::Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+        ]])));
+        assert_eq!(item.centroid().unwrap(), geo::Point::new(0.5, 0.5));
+        assert!(item.area().unwrap() < bbox_area);
+        item.geometry = Some(Geometry::new(geojson::Value::Point(vec![1.0, 2.0])));
+        assert_eq!(item.area().unwrap(), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn centroid_and_area_antimeridian() {
+        let mut item = Item::new("an-id");
+        item.bbox = Some(vec![-10.0, 0.0, 10.0, 2.0]);
+        let area = item.area().unwrap();
+        item.bbox = Some(vec![170.0, 0.0, -170.0, 2.0]);
+        assert_eq!(item.centroid().unwrap(), geo::Point::new(180.0, 1.0));
+        assert!((item.area().unwrap() - area).abs() < area * 0.01);
+        item.bbox = Some(vec![170.0, 0.0, -160.0, 2.0]);
+        assert_eq!(item.centroid().unwrap(), geo::Point::new(-175.0, 1.0));
+    }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn check_bbox_matches_geometry() {
+        use crate::{Error, Geometry};
+
+        let mut item: Item = crate::read("data/simple-item.json").unwrap();
+        item.check_bbox_matches_geometry().unwrap();
+        item.bbox = None;
+        item.check_bbox_matches_geometry().unwrap();
+
+        item.geometry = Some(Geometry::new(geojson::Value::LineString(vec![
+            vec![0.0, 0.0, 5.0],
+            vec![1.0, 2.0, 10.0],
+        ])));
+        item.bbox = Some(vec![0.0, 0.0, 1.0, 2.0 + 1e-9]);
+        item.check_bbox_matches_geometry().unwrap();
+        item.bbox = Some(vec![0.0, 0.0, 5.0, 1.0, 2.0, 10.0]);
+        item.check_bbox_matches_geometry().unwrap();
+        item.bbox = Some(vec![0.0, 0.0, 1.0, 1.0]);
+        match item.check_bbox_matches_geometry().unwrap_err() {
+            Error::BboxMismatch { bbox, bounds } => {
+                assert_eq!(bbox, [0.0, 0.0, 1.0, 1.0]);
+                assert_eq!(bounds, [0.0, 0.0, 1.0, 2.0]);
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+        item.bbox = Some(vec![-1.0, -1.0, 2.0, 3.0]);
+        assert!(item.check_bbox_matches_geometry().is_err());
+        item.bbox = Some(vec![0.0, 0.0, 1.0]);
+        assert!(item.check_bbox_matches_geometry().is_err());
+
+        item.geometry = Some(Geometry::new(geojson::Value::MultiPolygon(vec![
+            vec![vec![
+                vec![170.0, 0.0],
+                vec![180.0, 0.0],
+                vec![180.0, 1.0],
+                vec![170.0, 0.0],
+            ]],
+            vec![vec![
+                vec![-180.0, 0.0],
+                vec![-170.0, 0.0],
+                vec![-180.0, 1.0],
+                vec![-180.0, 0.0],
+            ]],
+        ])));
+        item.bbox = Some(vec![170.0, 0.0, -170.0, 1.0]);
+        item.check_bbox_matches_geometry().unwrap();
+        item.bbox = Some(vec![170.0, 0.0, -170.0, 2.0]);
+        assert!(item.check_bbox_matches_geometry().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn simplified_geometry() {
+        use crate::Geometry;
+
+        let mut item = Item::new("an-id");
+        assert!(item.simplified_geometry(1.0).is_none());
+        item.geometry = Some(Geometry::new(geojson::Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![0.5, 0.001],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+        ]])));
+        match item.simplified_geometry(0.01).unwrap().value {
+            geojson::Value::Polygon(rings) => assert_eq!(rings[0].len(), 5),
+            value => panic!("unexpected geometry: {:?}", value),
+        }
+        match item.simplified_geometry(100.0).unwrap().value {
+            geojson::Value::Polygon(rings) => assert!(rings[0].len() >= 4),
+            value => panic!("unexpected geometry: {:?}", value),
+        }
+        item.geometry = Some(Geometry::new(geojson::Value::Point(vec![1.0, 2.0])));
+        item.simplify_in_place(100.0);
+        assert_eq!(
+            item.geometry.unwrap().value,
+            geojson::Value::Point(vec![1.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn into_geojson_feature() {
+        let item: Item = crate::read("data/simple-item.json").unwrap();
+        let feature = geojson::Feature::try_from(item.clone()).unwrap();
+        assert_eq!(feature.geometry, item.geometry);
+        assert_eq!(feature.bbox, item.bbox);
+        assert_eq!(
+            feature.id,
+            Some(geojson::feature::Id::String(item.id.clone()))
+        );
+        assert_eq!(
+            feature.property("datetime").unwrap(),
+            item.properties.datetime.as_ref().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn asset_field_overrides_item() {
+        let mut item = Item::new("an-id");
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("proj:epsg".to_string(), 4326.into());
+        let mut asset = Asset::new("an-href");
+        let _ = asset
+            .additional_fields
+            .insert("proj:epsg".to_string(), 32621.into());
+        let _ = item.assets.insert("data".to_string(), asset);
+        let _ = item
+            .assets
+            .insert("thumbnail".to_string(), Asset::new("a-thumbnail"));
+        assert_eq!(item.asset_field("data", "proj:epsg").unwrap(), 32621);
+        assert_eq!(item.asset_field("thumbnail", "proj:epsg").unwrap(), 4326);
+        assert!(item.asset_field("data", "eo:bands").is_none());
+        assert!(item.asset_field("not-an-asset", "proj:epsg").is_none());
+    }
+
+    #[test]
+    fn enrich_from_item_assets() {
+        use crate::Collection;
+        use serde_json::json;
+
+        let mut collection = Collection::new("an-id", "a description");
+        let _ = collection.additional_fields.insert(
+            "item_assets".to_string(),
+            json!({
+                "data": {
+                    "title": "Data",
+                    "type": "image/tiff; application=geotiff",
+                    "roles": ["data"],
+                    "eo:bands": [{"name": "red"}],
+                    "gsd": 10
+                },
+                "thumbnail": {"title": "Thumbnail"}
+            }),
+        );
+        let mut item = Item::new("an-id");
+        let mut data = Asset::new("./data.tif");
+        data.roles = Some(vec!["data".to_string(), "reflectance".to_string()]);
+        let _ = data.additional_fields.insert("gsd".to_string(), 30.into());
+        let _ = item.assets.insert("data".to_string(), data);
+        let _ = item
+            .assets
+            .insert("metadata".to_string(), Asset::new("./metadata.xml"));
+        item.enrich_from_item_assets(&collection).unwrap();
+
+        let data = &item.assets["data"];
+        assert_eq!(data.href, "./data.tif");
+        assert_eq!(data.title.as_deref().unwrap(), "Data");
+        assert_eq!(
+            data.r#type.as_deref().unwrap(),
+            "image/tiff; application=geotiff"
+        );
+        assert_eq!(data.roles.as_ref().unwrap(), &["data", "reflectance"]);
+        assert_eq!(data.additional_fields["gsd"], 30);
+        assert_eq!(data.additional_fields["eo:bands"][0]["name"], "red");
+        assert_eq!(item.assets["metadata"], Asset::new("./metadata.xml"));
+        assert!(!item.assets.contains_key("thumbnail"));
+
+        let _ = collection.additional_fields.insert(
+            "item_assets".to_string(),
+            json!({"data": {"roles": "not an array"}}),
+        );
+        assert!(item.enrich_from_item_assets(&collection).is_err());
+        item.enrich_from_item_assets(&Collection::new("an-id", "a description"))
+            .unwrap();
+    }
+
+    #[test]
+    fn property_array() {
+        use crate::Error;
+        use serde_json::json;
+
+        let mut item = Item::new("an-id");
+        let fields = &mut item.properties.additional_fields;
+        let _ = fields.insert(
+            "times".to_string(),
+            json!(["2023-01-01T00:00:00Z", "2023-01-01T00:00:01Z"]),
+        );
+        let _ = fields.insert("count".to_string(), 2.into());
+        let times: Vec<String> = item.property_array("times").unwrap();
+        assert_eq!(times, ["2023-01-01T00:00:00Z", "2023-01-01T00:00:01Z"]);
+        assert!(matches!(
+            item.property_array::<String>("positions").unwrap_err(),
+            Error::MissingField(key) if key == "positions"
+        ));
+        assert!(matches!(
+            item.property_array::<f64>("times").unwrap_err(),
+            Error::SerdeJson(_)
+        ));
+        assert!(item.property_array::<f64>("count").is_err());
+    }
+
+    mod merge {
+        use crate::{Item, Properties};
+        use serde_json::json;
+
+        #[test]
+        fn other_wins() {
+            let mut properties = Properties {
+                platform: Some("landsat-8".to_string()),
+                ..Default::default()
+            };
+            let _ = properties
+                .additional_fields
+                .insert("eo:cloud_cover".to_string(), 10.into());
+            let mut other = Properties {
+                datetime: Some("2023-01-01T00:00:00Z".to_string()),
+                platform: Some("landsat-9".to_string()),
+                ..Default::default()
+            };
+            let _ = other
+                .additional_fields
+                .insert("eo:cloud_cover".to_string(), 20.into());
+            properties.merge(&other);
+            assert_eq!(properties.datetime.unwrap(), "2023-01-01T00:00:00Z");
+            assert_eq!(properties.platform.unwrap(), "landsat-9");
+            assert_eq!(properties.additional_fields["eo:cloud_cover"], 20);
+        }
+
+        #[test]
+        fn keep_datetime() {
+            let mut properties = Properties {
+                datetime: Some("2023-01-01T00:00:00Z".to_string()),
+                ..Default::default()
+            };
+            let other = Properties {
+                datetime: None,
+                gsd: Some(30.0),
+                ..Default::default()
+            };
+            properties.merge(&other);
+            assert_eq!(properties.datetime.unwrap(), "2023-01-01T00:00:00Z");
+            assert_eq!(properties.gsd.unwrap(), 30.0);
+        }
+
+        #[test]
+        fn keep_common_metadata() {
+            let mut properties = Properties {
+                instruments: Some(vec!["oli".to_string()]),
+                gsd: Some(30.0),
+                ..Default::default()
+            };
+            properties.merge(&Properties::default());
+            assert_eq!(properties.instruments.unwrap(), vec!["oli"]);
+            assert_eq!(properties.gsd.unwrap(), 30.0);
+        }
+
+        #[test]
+        fn nested_objects() {
+            let mut properties: Properties = serde_json::from_value(json!({
+                "datetime": null,
+                "a": {"b": 1, "c": {"d": 2}, "e": [1, 2]},
+                "f": "g"
+            }))
+            .unwrap();
+            let other: Properties = serde_json::from_value(json!({
+                "datetime": null,
+                "a": {"c": {"h": 3}, "e": [3]},
+                "f": {"i": 4}
+            }))
+            .unwrap();
+            properties.merge(&other);
+            assert_eq!(
+                serde_json::to_value(properties).unwrap(),
+                json!({
+                    "datetime": null,
+                    "a": {"b": 1, "c": {"d": 2, "h": 3}, "e": [3]},
+                    "f": {"i": 4}
+                })
+            );
+        }
+
+        #[test]
+        fn item_merge_properties() {
+            let mut item = Item::new("an-id");
+            item.properties.datetime = Some("2023-01-01T00:00:00Z".to_string());
+            let properties = Properties {
+                datetime: None,
+                gsd: Some(30.0),
+                ..Default::default()
+            };
+            item.merge_properties(properties);
+            assert_eq!(item.properties.datetime.unwrap(), "2023-01-01T00:00:00Z");
+            assert_eq!(item.properties.gsd.unwrap(), 30.0);
+        }
+    }
+
+    mod roundtrip {
+        use super::Item;
+        use crate::tests::{roundtrip, roundtrip_bytes};
+
+        roundtrip!(simple_item, "data/simple-item.json", Item);
+        roundtrip!(extended_item, "data/extended-item.json", Item);
+        roundtrip!(core_item, "data/core-item.json", Item);
         roundtrip!(collectionless_item, "data/collectionless-item.json", Item);
         roundtrip!(
             proj_example_item,
             "data/extensions-collection/proj-example/proj-example.json",
             Item
         );
+        roundtrip_bytes!(extra_fields, "examples/extra-fields-item.json", Item);
+        roundtrip!(
+            common_metadata_item,
+            "examples/common-metadata-item.json",
+            Item
+        );
+
+        #[test]
+        fn common_metadata_fields() {
+            let item: Item = crate::read("examples/common-metadata-item.json").unwrap();
+            assert_eq!(item.properties.title().unwrap(), "Common metadata");
+            assert!(item.properties.description().is_some());
+            assert_eq!(item.properties.keywords(), ["a keyword", "another keyword"]);
+            for field in ["title", "description", "keywords"] {
+                assert!(!item.properties.additional_fields.contains_key(field));
+            }
+
+            let item: Item = crate::read("data/simple-item.json").unwrap();
+            assert!(item.properties.title().is_none());
+            let value = serde_json::to_value(item).unwrap();
+            for field in ["title", "description", "keywords"] {
+                assert!(value["properties"].get(field).is_none());
+            }
+        }
+
+        #[test]
+        fn large_nested_property() {
+            use serde_json::{json, Value};
+
+            let times: Vec<String> = (0..10_000)
+                .map(|i| {
+                    format!(
+                        "2023-01-01T{:02}:{:02}:{:02}Z",
+                        i / 3600,
+                        i / 60 % 60,
+                        i % 60
+                    )
+                })
+                .collect();
+            let positions: Vec<[f64; 3]> = (0..10_000)
+                .map(|i| {
+                    let i = f64::from(i);
+                    [-105.0 + i / 3e7, 40.0 + i / 7e7, i * 0.1]
+                })
+                .collect();
+            let mut item = Item::new("an-id");
+            let fields = &mut item.properties.additional_fields;
+            let _ = fields.insert("times".to_string(), json!(times));
+            let _ = fields.insert("positions".to_string(), json!(positions));
+            let _ = fields.insert(
+                "nested".to_string(),
+                json!([[[u64::MAX, i64::MIN, 0.1], [{"a": [[]], "b": null}]], []]),
+            );
+            let json = serde_json::to_string(&item).unwrap();
+            let roundtripped: Item = serde_json::from_str(&json).unwrap();
+            assert_eq!(roundtripped, item);
+            assert_eq!(
+                roundtripped.property_array::<String>("times").unwrap(),
+                times
+            );
+            assert_eq!(
+                roundtripped
+                    .property_array::<[f64; 3]>("positions")
+                    .unwrap(),
+                positions
+            );
+            let nested: Vec<Value> = roundtripped.property_array("nested").unwrap();
+            assert_eq!(nested[0][0][0], u64::MAX);
+            assert_eq!(nested[0][0][1], i64::MIN);
+        }
+
+        #[test]
+        fn common_metadata() {
+            let item: Item = crate::read("data/core-item.json").unwrap();
+            let properties = &item.properties;
+            assert_eq!(properties.platform.as_deref().unwrap(), "cool_sat1");
+            assert_eq!(
+                properties.instruments.as_ref().unwrap(),
+                &["cool_sensor_v1"]
+            );
+            assert_eq!(properties.constellation.as_deref().unwrap(), "ion");
+            assert_eq!(properties.mission.as_deref().unwrap(), "collection 5624");
+            assert_eq!(properties.gsd.unwrap(), 0.512);
+            assert_eq!(properties.title().unwrap(), "Core Item");
+            assert!(properties.description().is_some());
+            for key in [
+                "title",
+                "description",
+                "platform",
+                "instruments",
+                "constellation",
+                "mission",
+                "gsd",
+            ] {
+                assert!(!properties.additional_fields.contains_key(key));
+            }
+            let value = serde_json::to_value(&item).unwrap();
+            assert_eq!(value["properties"]["gsd"], 0.512);
+            assert_eq!(value["properties"]["instruments"][0], "cool_sensor_v1");
+        }
+
+        #[test]
+        fn common_metadata_with_unexpected_types() {
+            use crate::Properties;
+            use serde_json::json;
+
+            let value = json!({
+                "datetime": "2023-01-01T00:00:00Z",
+                "title": 42,
+                "keywords": "a keyword",
+                "platform": "landsat-8",
+                "instruments": "oli",
+                "gsd": "30",
+                "mission": null
+            });
+            let properties: Properties = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(properties.platform.as_deref().unwrap(), "landsat-8");
+            for key in ["title", "keywords", "instruments", "gsd", "mission"] {
+                assert_eq!(properties.additional_fields[key], value[key]);
+            }
+            assert!(properties.gsd.is_none());
+            assert!(properties.instruments.is_none());
+            assert_eq!(serde_json::to_value(properties).unwrap(), value);
+
+            assert!(serde_json::from_value::<Properties>(json!({"datetime": 42})).is_err());
+        }
     }
 }
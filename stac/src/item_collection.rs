@@ -1,6 +1,10 @@
+//! Item collections, i.e. GeoJSON feature collections of items.
+
 use crate::{Href, Item, Link, Links};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::{cmp::Ordering, collections::HashMap};
 
 /// The type field for [ItemCollections](ItemCollection).
 pub const ITEM_COLLECTION_TYPE: &str = "FeatureCollection";
@@ -9,6 +13,7 @@ pub const ITEM_COLLECTION_TYPE: &str = "FeatureCollection";
 ///
 /// While not part of the STAC specification, ItemCollections are often used to store many items in a single file.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ItemCollection {
     /// The list of [Items](Item).
     ///
@@ -37,6 +42,151 @@ pub struct ItemCollection {
     href: Option<String>,
 }
 
+/// Which item to keep when [deduplicating](ItemCollection::dedup_by_id_with) items by id.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Keep {
+    /// Keep the first item with each id.
+    #[default]
+    First,
+
+    /// Keep the item with the latest `updated` property.
+    ///
+    /// Items without a parseable `updated` are older than items with one. If
+    /// more than one item has the latest `updated`, the first of them is kept.
+    LatestUpdated,
+}
+
+impl ItemCollection {
+    /// Removes items with the same id as an earlier item, keeping the first.
+    ///
+    /// The remaining items keep their relative order. Returns the number of
+    /// items that were removed. See [ItemCollection::dedup_by_id_with] to keep
+    /// the latest item instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Item, ItemCollection};
+    /// let mut item_collection: ItemCollection =
+    ///     vec![Item::new("a"), Item::new("b"), Item::new("a")].into();
+    /// assert_eq!(item_collection.dedup_by_id(), 1);
+    /// assert_eq!(item_collection.items.len(), 2);
+    /// ```
+    pub fn dedup_by_id(&mut self) -> usize {
+        self.dedup_by_id_with(Keep::First)
+    }
+
+    /// Removes items with the same id, choosing which item to keep.
+    ///
+    /// Each kept item takes the position of the first item with its id, so
+    /// the order of ids is the order in which they first appear. Returns the
+    /// number of items that were removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{item_collection::Keep, Item, ItemCollection};
+    /// let mut old = Item::new("a");
+    /// let _ = old.properties.additional_fields.insert("updated".to_string(), "2023-01-01T00:00:00Z".into());
+    /// let mut new = Item::new("a");
+    /// let _ = new.properties.additional_fields.insert("updated".to_string(), "2023-01-02T00:00:00Z".into());
+    /// let mut item_collection: ItemCollection = vec![old, Item::new("b"), new.clone()].into();
+    /// assert_eq!(item_collection.dedup_by_id_with(Keep::LatestUpdated), 1);
+    /// assert_eq!(item_collection.items[0], new);
+    /// ```
+    pub fn dedup_by_id_with(&mut self, keep: Keep) -> usize {
+        let count = self.items.len();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut items: Vec<Item> = Vec::with_capacity(count);
+        for item in self.items.drain(..) {
+            if let Some(&i) = positions.get(&item.id) {
+                if keep == Keep::LatestUpdated && updated(&item) > updated(&items[i]) {
+                    items[i] = item;
+                }
+            } else {
+                let _ = positions.insert(item.id.clone(), items.len());
+                items.push(item);
+            }
+        }
+        self.items = items;
+        count - self.items.len()
+    }
+
+    /// Sorts the items by datetime.
+    ///
+    /// Items are ordered by their [sort datetime](Item::sort_datetime). Items
+    /// without one are placed at the end, whether sorting in ascending or descending order. The sort is
+    /// stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Item, ItemCollection};
+    /// let mut a = Item::new("a");
+    /// a.properties.datetime = Some("2023-01-02T00:00:00Z".to_string());
+    /// let mut b = Item::new("b");
+    /// b.properties.datetime = Some("2023-01-01T00:00:00Z".to_string());
+    /// let mut item_collection = ItemCollection::from(vec![a, b]);
+    /// item_collection.sort_by_datetime(false);
+    /// assert_eq!(item_collection.items[0].id, "b");
+    /// ```
+    pub fn sort_by_datetime(&mut self, descending: bool) {
+        self.items
+            .sort_by(|a, b| match (a.sort_datetime(), b.sort_datetime()) {
+                (Some(a), Some(b)) => {
+                    if descending {
+                        b.cmp(&a)
+                    } else {
+                        a.cmp(&b)
+                    }
+                }
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            });
+    }
+
+    /// Sorts the items by the distance from their [centroid](Item::centroid) to a point.
+    ///
+    /// Distances are haversine distances, with coordinates in
+    /// longitude/latitude. Items without a geometry or bbox are placed at the
+    /// end. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Geometry, Item, ItemCollection};
+    /// let mut a = Item::new("a");
+    /// a.geometry = Some(Geometry::new(geojson::Value::Point(vec![10.0, 10.0])));
+    /// let mut b = Item::new("b");
+    /// b.geometry = Some(Geometry::new(geojson::Value::Point(vec![1.0, 1.0])));
+    /// let mut item_collection = ItemCollection::from(vec![a, b]);
+    /// item_collection.sort_by_distance(geo::Point::new(0.0, 0.0));
+    /// assert_eq!(item_collection.items[0].id, "b");
+    /// ```
+    #[cfg(feature = "geo")]
+    pub fn sort_by_distance(&mut self, point: geo::Point) {
+        use geo::HaversineDistance;
+
+        let mut items: Vec<_> = std::mem::take(&mut self.items)
+            .into_iter()
+            .map(|item| {
+                let distance = item
+                    .centroid()
+                    .map(|centroid| centroid.haversine_distance(&point));
+                (distance, item)
+            })
+            .collect();
+        items.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a), Some(b)) => a.total_cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        self.items = items.into_iter().map(|(_, item)| item).collect();
+    }
+}
+
 impl From<Vec<Item>> for ItemCollection {
     fn from(items: Vec<Item>) -> Self {
         ItemCollection {
@@ -60,7 +210,7 @@ impl Href for ItemCollection {
         self.href.as_deref()
     }
 
-    fn set_href(&mut self, href: impl ToString) {
+    fn set_href(&mut self, href: &str) {
         self.href = Some(href.to_string())
     }
 }
@@ -74,6 +224,15 @@ impl Links for ItemCollection {
     }
 }
 
+fn updated(item: &Item) -> Option<DateTime<Utc>> {
+    item.properties
+        .additional_fields
+        .get("updated")
+        .and_then(Value::as_str)
+        .and_then(|updated| DateTime::parse_from_rfc3339(updated).ok())
+        .map(|updated| updated.with_timezone(&Utc))
+}
+
 fn deserialize_type<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -90,7 +249,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::ItemCollection;
+    use super::{ItemCollection, Keep};
     use crate::Item;
 
     #[test]
@@ -104,4 +263,116 @@ mod tests {
         let items = vec![Item::new("a"), Item::new("b")];
         let _ = ItemCollection::from_iter(items.into_iter());
     }
+
+    fn item(id: &str, datetime: Option<&str>) -> Item {
+        let mut item = Item::new(id);
+        item.properties.datetime = datetime.map(String::from);
+        item
+    }
+
+    fn ids(item_collection: &ItemCollection) -> Vec<&str> {
+        item_collection
+            .items
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn dedup_by_id() {
+        let mut item_collection: ItemCollection = ["a", "b", "a", "c", "b", "a"]
+            .into_iter()
+            .map(Item::new)
+            .collect();
+        assert_eq!(item_collection.dedup_by_id(), 3);
+        assert_eq!(ids(&item_collection), ["a", "b", "c"]);
+        assert_eq!(item_collection.dedup_by_id(), 0);
+    }
+
+    #[test]
+    fn dedup_by_id_keep_latest_updated() {
+        let updated = |id: &str, updated: Option<&str>, title: &str| {
+            let mut item = Item::new(id);
+            if let Some(updated) = updated {
+                let _ = item
+                    .properties
+                    .additional_fields
+                    .insert("updated".to_string(), updated.into());
+            }
+            item.properties.set_title(title);
+            item
+        };
+        let mut item_collection = ItemCollection::from(vec![
+            updated("a", None, "a-none"),
+            updated("b", Some("2023-01-02T00:00:00Z"), "b-first"),
+            updated("a", Some("2023-01-01T00:00:00Z"), "a-old"),
+            updated("b", Some("2023-01-02T00:00:00Z"), "b-tie"),
+            updated("a", Some("2023-01-03T00:00:00Z"), "a-new"),
+            updated("a", Some("not a datetime"), "a-invalid"),
+        ]);
+        assert_eq!(item_collection.dedup_by_id_with(Keep::LatestUpdated), 4);
+        let titles: Vec<_> = item_collection
+            .items
+            .iter()
+            .map(|item| item.properties.title().unwrap())
+            .collect();
+        assert_eq!(titles, ["a-new", "b-first"]);
+    }
+
+    #[test]
+    fn sort_by_datetime() {
+        let mut start = item("start", None);
+        let _ = start
+            .properties
+            .additional_fields
+            .insert("start_datetime".to_string(), "2023-01-02T00:00:00Z".into());
+        let mut range = item("range", None);
+        let _ = range
+            .properties
+            .additional_fields
+            .insert("start_datetime".to_string(), "2023-01-01T00:00:00Z".into());
+        let _ = range
+            .properties
+            .additional_fields
+            .insert("end_datetime".to_string(), "2023-01-07T00:00:00Z".into());
+        let mut item_collection: ItemCollection = vec![
+            item("none", None),
+            item("late", Some("2023-01-03T00:00:00Z")),
+            start,
+            item("invalid", Some("not a datetime")),
+            item("early", Some("2023-01-01T00:00:00Z")),
+            range,
+        ]
+        .into();
+        item_collection.sort_by_datetime(false);
+        assert_eq!(
+            ids(&item_collection),
+            ["early", "start", "late", "range", "none", "invalid"]
+        );
+        item_collection.sort_by_datetime(true);
+        assert_eq!(
+            ids(&item_collection),
+            ["range", "late", "start", "early", "none", "invalid"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "geo")]
+    fn sort_by_distance() {
+        use crate::Geometry;
+
+        let mut far = Item::new("far");
+        far.geometry = Some(Geometry::new(geojson::Value::Point(vec![50.0, 50.0])));
+        let mut near = Item::new("near");
+        near.geometry = Some(Geometry::new(geojson::Value::Polygon(vec![vec![
+            vec![-1.0, -1.0],
+            vec![1.0, -1.0],
+            vec![1.0, 1.0],
+            vec![-1.0, 1.0],
+            vec![-1.0, -1.0],
+        ]])));
+        let mut item_collection: ItemCollection = vec![Item::new("none"), far, near].into();
+        item_collection.sort_by_distance(geo::Point::new(0.0, 0.0));
+        assert_eq!(ids(&item_collection), ["near", "far", "none"]);
+    }
 }
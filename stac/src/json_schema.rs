@@ -0,0 +1,50 @@
+use schemars::JsonSchema;
+use serde_json::Value;
+
+/// Generates a JSON Schema of the Rust representation of a type.
+///
+/// This is **not** the official STAC JSON Schema, and shouldn't be used for
+/// validation (see the `jsonschema` feature for that). It describes how this
+/// crate (de)serializes a type: e.g. additional fields are flattened into
+/// each object, extension fields aren't typed, and geometries are any JSON
+/// value. It's meant for tooling, e.g. form generation. Requires the
+/// `schemars` feature.
+///
+/// # Examples
+///
+/// ```
+/// let schema = stac::json_schema::<stac::Item>();
+/// assert_eq!(schema["title"], "Item");
+/// assert!(schema["required"].as_array().unwrap().contains(&"id".into()));
+/// ```
+pub fn json_schema<T: JsonSchema>() -> Value {
+    let schema = schemars::schema_for!(T);
+    serde_json::to_value(schema).expect("a schema can always be serialized to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{classification::Classification, Asset, Catalog, Collection, Item, Link};
+
+    #[test]
+    fn schemas() {
+        assert_eq!(super::json_schema::<Catalog>()["title"], "Catalog");
+        assert_eq!(super::json_schema::<Collection>()["title"], "Collection");
+        assert_eq!(super::json_schema::<Asset>()["title"], "Asset");
+        assert_eq!(super::json_schema::<Link>()["title"], "Link");
+        let schema = super::json_schema::<Classification>();
+        assert!(schema["properties"]
+            .as_object()
+            .unwrap()
+            .contains_key("classification:classes"));
+    }
+
+    #[test]
+    fn item() {
+        let schema = super::json_schema::<Item>();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("stac_extensions"));
+        assert!(properties.contains_key("geometry"));
+        assert!(!properties.contains_key("href"));
+    }
+}
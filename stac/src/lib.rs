@@ -40,6 +40,21 @@
 //! item.links.push(Link::new("an/href", "a-rel-type"));
 //! ```
 //!
+//! Item geometries are [geojson] geometries, re-exported as [Geometry].
+//! Geometries are checked for a valid GeoJSON type when they are deserialized.
+//!
+//! ```
+//! use stac::{Geometry, Item};
+//! let mut item = Item::new("id");
+//! item.geometry = Some(Geometry::new(geojson::Value::Point(vec![-105.1, 41.1])));
+//! ```
+//!
+//! Fields that aren't part of the specification are kept in each structure's
+//! `additional_fields`, in the order they were read. Known fields are always
+//! written in the same order, so reading and writing an object written by this
+//! crate produces identical output. The exception is maps of [assets](Asset),
+//! which aren't ordered.
+//!
 //! # Reading
 //!
 //! Synchronous reads from the filesystem are supported via [read]:
@@ -126,34 +141,83 @@
 )]
 
 mod asset;
+pub mod band;
+mod bbox;
+mod cache;
 mod catalog;
+pub mod classification;
+#[cfg(feature = "geotiff")]
+mod cog;
 mod collection;
+pub mod datacube;
 mod error;
 mod extensions;
-mod href;
+pub mod file;
+pub mod href;
 mod io;
 mod item;
-mod item_collection;
+pub mod item_collection;
+#[cfg(feature = "schemars")]
+mod json_schema;
 pub mod link;
 pub mod media_type;
+#[cfg(feature = "reqwest")]
+mod pages;
+pub mod processing;
+#[cfg(feature = "proj")]
+mod proj;
+mod publish;
+pub mod roles;
+mod stream;
+mod summaries;
+#[cfg(feature = "testing")]
+pub mod testing;
 #[cfg(feature = "jsonschema")]
 pub mod validate;
 mod value;
+mod walk;
 
+pub use geojson::Geometry;
+#[cfg(feature = "reqwest")]
+pub use io::is_json_response;
+#[cfg(feature = "zip")]
+pub use io::read_from_zip;
+#[cfg(feature = "yaml")]
+pub use io::write_yaml;
+#[cfg(feature = "geo")]
+pub use item::BBOX_TOLERANCE;
+#[cfg(feature = "schemars")]
+pub use json_schema::json_schema;
+#[cfg(feature = "reqwest")]
+pub use pages::SearchPages;
 #[cfg(feature = "jsonschema")]
-pub use validate::{Validate, Validator};
+pub use validate::{Validate, ValidationReport, Validator};
 pub use {
-    asset::{Asset, Assets},
+    asset::{AlternateAsset, Asset, Assets},
+    bbox::Bbox,
+    cache::{ReadCache, DEFAULT_READ_CACHE_CAPACITY},
     catalog::{Catalog, CATALOG_TYPE},
-    collection::{Collection, Extent, Provider, SpatialExtent, TemporalExtent, COLLECTION_TYPE},
+    collection::{
+        Collection, Extent, Provider, SpatialExtent, TemporalExtent, COLLECTION_TYPE, CRS84,
+    },
     error::Error,
-    extensions::Extensions,
-    href::{href_to_url, Href},
-    io::{read, read_json},
-    item::{Item, Properties, ITEM_TYPE},
+    extensions::{Extensions, Lint, LATEST_EXTENSIONS},
+    href::{canonical_key, href_to_url, Href},
+    io::{
+        exists, read, read_cached, read_json, read_maybe, write, write_with, RangeReader,
+        ReadContext, SerializeOptions,
+    },
+    item::{Item, Properties, ITEM_TYPE, TIMESTAMP_FIELDS},
     item_collection::{ItemCollection, ITEM_COLLECTION_TYPE},
     link::{Link, Links},
-    value::Value,
+    publish::PublishSummary,
+    stream::{stream_items, ItemStream},
+    value::{MaybeStac, Value},
+    walk::{
+        catalog_stats, catalog_stats_with, check_unique_ids, check_unique_ids_with,
+        collect_collections, collect_items, detect_layout, find_first, find_in_tree, visit_tree,
+        visit_tree_with, CatalogStats, Layout, WalkOptions, WalkSummary,
+    },
 };
 
 /// The default STAC version supported by this library.
@@ -221,6 +285,19 @@ mod tests {
         };
     }
     pub(crate) use roundtrip;
+
+    macro_rules! roundtrip_bytes {
+        ($function:ident, $filename:expr, $object:ident) => {
+            #[test]
+            fn $function() {
+                let before = std::fs::read_to_string($filename).unwrap();
+                let object: $object = serde_json::from_str(&before).unwrap();
+                let after = serde_json::to_string_pretty(&object).unwrap();
+                assert_eq!(before.trim_end(), after);
+            }
+        };
+    }
+    pub(crate) use roundtrip_bytes;
 }
 
 // From https://github.com/rust-lang/cargo/issues/383#issuecomment-720873790,
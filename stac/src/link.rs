@@ -1,14 +1,24 @@
 //! Links.
+//!
+//! Static catalogs and STAC APIs link to items differently. A static catalog
+//! or collection has one `rel="item"` link for each of its items, which
+//! [Links::item_links] returns. A STAC API collection instead has a single
+//! `rel="items"` link to its items endpoint, which returns pages of items as
+//! an item collection, and which [Links::items_endpoint_link] returns. Code
+//! that should work with both kinds of catalogs needs to check for both.
 
-use crate::{media_type, Error, Result};
+use crate::{media_type, Error, Href, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::{collections::HashMap, time::Duration};
 use url::Url;
 
 /// Child links.
 pub const CHILD_REL: &str = "child";
 /// Item link.
 pub const ITEM_REL: &str = "item";
+/// Items endpoint link, from a STAC API collection.
+pub const ITEMS_REL: &str = "items";
 /// Parent link.
 pub const PARENT_REL: &str = "parent";
 /// Root link.
@@ -17,6 +27,8 @@ pub const ROOT_REL: &str = "root";
 pub const SELF_REL: &str = "self";
 /// Collection link.
 pub const COLLECTION_REL: &str = "collection";
+/// Canonical link, to the authoritative location of an object that's in more than one catalog.
+pub const CANONICAL_REL: &str = "canonical";
 
 /// This object describes a relationship with another entity.
 ///
@@ -32,6 +44,7 @@ pub const COLLECTION_REL: &str = "collection";
 /// crate](https://github.com/gadomski/stac-rs/stac-api), but in this case it
 /// was simpler to include these attributes in the base [Link] rather to create a new one.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Link {
     /// The actual link in the format of an URL.
     ///
@@ -54,6 +67,17 @@ pub struct Link {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
+    /// The language of the referenced entity, as an [RFC
+    /// 5646](https://www.rfc-editor.org/rfc/rfc5646) language tag (e.g. `en-US`).
+    ///
+    /// From [RFC 8288](https://www.rfc-editor.org/rfc/rfc8288#section-3.4.1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hreflang: Option<String>,
+
+    /// The length of the referenced entity, in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+
     /// The HTTP method of the request, usually GET or POST. Defaults to GET.
     ///
     /// From the STAC API spec.
@@ -86,6 +110,41 @@ pub struct Link {
     pub additional_fields: Map<String, Value>,
 }
 
+/// Options for checking that link targets exist.
+///
+/// # Examples
+///
+/// Some servers don't support `HEAD` requests:
+///
+/// ```
+/// use stac::link::CheckLinksOptions;
+/// let options = CheckLinksOptions {
+///     method: "GET".to_string(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckLinksOptions {
+    /// The HTTP method used to check urls.
+    ///
+    /// Defaults to `HEAD`.
+    pub method: String,
+
+    /// The timeout for each HTTP request, or `None` for no timeout.
+    ///
+    /// Defaults to thirty seconds.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for CheckLinksOptions {
+    fn default() -> CheckLinksOptions {
+        CheckLinksOptions {
+            method: "HEAD".to_string(),
+            timeout: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
 /// Implemented by any object that has links.
 pub trait Links {
     /// Returns a reference to this object's links.
@@ -145,6 +204,110 @@ pub trait Links {
         self.links_mut().push(link)
     }
 
+    /// Adds a link, unless there's already a link with the same rel and href.
+    ///
+    /// Returns true if the link was added. Use this when building a catalog
+    /// so that building it again doesn't add duplicate links. Only the rel
+    /// and href are compared, so an existing link isn't changed even if e.g.
+    /// its title is different.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Link, Links};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// assert!(catalog.add_link_if_absent(Link::child("./a/catalog.json")));
+    /// assert!(!catalog.add_link_if_absent(Link::child("./a/catalog.json")));
+    /// assert_eq!(catalog.links.len(), 1);
+    /// ```
+    fn add_link_if_absent(&mut self, link: Link) -> bool {
+        if self
+            .links()
+            .iter()
+            .any(|l| l.rel == link.rel && l.href == link.href)
+        {
+            false
+        } else {
+            self.links_mut().push(link);
+            true
+        }
+    }
+
+    /// Inserts or replaces the link of the given link's rel type.
+    ///
+    /// This is for singleton rels, e.g. "root" or "parent". The first link of
+    /// that rel type is replaced in place (or the link is added at the end),
+    /// and all other links of that rel type are removed. Unlike
+    /// [Links::set_link], the link keeps its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Link, Links};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.upsert_link(Link::root("./catalog.json"));
+    /// catalog.upsert_link(Link::child("./a/catalog.json"));
+    /// catalog.upsert_link(Link::root("../catalog.json"));
+    /// assert_eq!(catalog.links.len(), 2);
+    /// assert_eq!(catalog.links[0].href, "../catalog.json");
+    /// ```
+    fn upsert_link(&mut self, link: Link) {
+        upsert_singleton(self.links_mut(), link)
+    }
+
+    /// Sets the href of this object's self link.
+    ///
+    /// The first self link is updated in place (or a new one is added), its
+    /// media type is set to JSON, and all other self links are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Links, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.set_self_href("./an-id.json");
+    /// item.set_self_href("./an-id/an-id.json");
+    /// assert_eq!(item.links.len(), 1);
+    /// assert_eq!(item.self_link().unwrap().href, "./an-id/an-id.json");
+    /// ```
+    fn set_self_href(&mut self, href: impl ToString) {
+        set_singleton_href(self.links_mut(), SELF_REL, href.to_string())
+    }
+
+    /// Sets the href of this object's root link.
+    ///
+    /// The first root link is updated in place (or a new one is added), its
+    /// media type is set to JSON, and all other root links are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Links, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.set_root_href("../catalog.json");
+    /// assert_eq!(item.root_link().unwrap().href, "../catalog.json");
+    /// ```
+    fn set_root_href(&mut self, href: impl ToString) {
+        set_singleton_href(self.links_mut(), ROOT_REL, href.to_string())
+    }
+
+    /// Sets the href of this object's parent link.
+    ///
+    /// The first parent link is updated in place (or a new one is added), its
+    /// media type is set to JSON, and all other parent links are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Links, Item};
+    /// let mut item = Item::new("an-id");
+    /// item.set_parent_href("../collection.json");
+    /// assert_eq!(item.parent_link().unwrap().href, "../collection.json");
+    /// ```
+    fn set_parent_href(&mut self, href: impl ToString) {
+        set_singleton_href(self.links_mut(), PARENT_REL, href.to_string())
+    }
+
     /// Returns this object's root link.
     ///
     /// This is the first link with a rel="root".
@@ -216,8 +379,71 @@ pub trait Links {
         Box::new(self.links().iter().filter(|link| link.is_item()))
     }
 
+    /// Returns this object's `rel="item"` links, one for each item in a static catalog.
+    ///
+    /// STAC API collections don't list their items, see
+    /// [Links::items_endpoint_link] and the [module docs](crate::link).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Links;
+    /// let collection: stac::Collection = stac::read("data/collection.json").unwrap();
+    /// assert!(!collection.item_links().is_empty());
+    /// assert!(collection.items_endpoint_link().is_none());
+    /// ```
+    fn item_links(&self) -> Vec<&Link> {
+        self.iter_item_links().collect()
+    }
+
+    /// Returns this object's `rel="items"` link, which points to a STAC API items endpoint.
+    ///
+    /// Static catalogs have `rel="item"` links instead, see [Links::item_links]
+    /// and the [module docs](crate::link).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Collection, Link, Links};
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// collection.links.push(Link::new("http://stac-rs.test/collections/an-id/items", "items"));
+    /// assert!(collection.items_endpoint_link().is_some());
+    /// assert!(collection.item_links().is_empty());
+    /// ```
+    fn items_endpoint_link(&self) -> Option<&Link> {
+        self.links().iter().find(|link| link.is_items())
+    }
+
+    /// Returns an iterator over mutable references to this object's links of a rel type.
+    ///
+    /// # Examples
+    ///
+    /// Rewrite all child hrefs:
+    ///
+    /// ```
+    /// use stac::Links;
+    /// let mut catalog: stac::Catalog = stac::read("data/catalog.json").unwrap();
+    /// for link in catalog.links_iter_mut_by_rel("child") {
+    ///     link.href = link.href.replace("./", "https://stac-rs.test/");
+    /// }
+    /// assert!(catalog.iter_child_links().all(|link| link.is_absolute()));
+    /// ```
+    fn links_iter_mut_by_rel<'a>(
+        &'a mut self,
+        rel: &'a str,
+    ) -> Box<dyn Iterator<Item = &'a mut Link> + 'a> {
+        Box::new(
+            self.links_mut()
+                .iter_mut()
+                .filter(move |link| link.rel == rel),
+        )
+    }
+
     /// Makes all relative links absolute with respect to an href.
     ///
+    /// If the href is a url with a query string (e.g. an access token), that
+    /// query is kept on resolved links that don't have a query of their own.
+    ///
     /// # Examples
     ///
     /// ```
@@ -264,6 +490,236 @@ pub trait Links {
         }
         removed
     }
+
+    /// Retains only the links for which the predicate returns true.
+    ///
+    /// The order of the retained links is preserved.
+    ///
+    /// # Examples
+    ///
+    /// Drop `derived_from` links before publishing:
+    ///
+    /// ```
+    /// use stac::{Catalog, Links, Link};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::new("./source.json", "derived_from"));
+    /// catalog.links.push(Link::child("./child.json"));
+    /// catalog.retain_links(|link| link.rel != "derived_from");
+    /// assert_eq!(catalog.links.len(), 1);
+    /// assert!(catalog.links[0].is_child());
+    /// ```
+    fn retain_links<F>(&mut self, f: F)
+    where
+        F: FnMut(&Link) -> bool,
+    {
+        self.links_mut().retain(f)
+    }
+
+    /// Removes this object's child links that point to an object with the given id.
+    ///
+    /// A link's target id is taken from the link's `id` field, if it has one.
+    /// Otherwise the target is read, with relative hrefs resolved against this
+    /// object's href. Returns true if any links were removed. Use
+    /// [Links::remove_child_href] to avoid reads if you know the href.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Links};
+    /// let mut catalog: Catalog = stac::read("data/catalog.json").unwrap();
+    /// assert!(catalog.remove_child("extensions-collection").unwrap());
+    /// assert!(!catalog.remove_child("extensions-collection").unwrap());
+    /// assert_eq!(catalog.iter_child_links().count(), 2);
+    /// ```
+    fn remove_child(&mut self, id: &str) -> Result<bool>
+    where
+        Self: Href + Sized,
+    {
+        remove_links_by_id(self, id, Link::is_child)
+    }
+
+    /// Removes this object's item links that point to an item with the given id.
+    ///
+    /// Target ids are resolved as in [Links::remove_child]. Returns true if any
+    /// links were removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Links};
+    /// let mut catalog: Catalog = stac::read("data/catalog.json").unwrap();
+    /// assert!(catalog.remove_item("CS3-20160503_132131_08").unwrap());
+    /// assert_eq!(catalog.iter_item_links().count(), 0);
+    /// ```
+    fn remove_item(&mut self, id: &str) -> Result<bool>
+    where
+        Self: Href + Sized,
+    {
+        remove_links_by_id(self, id, Link::is_item)
+    }
+
+    /// Removes this object's child links with the given href.
+    ///
+    /// Hrefs are compared as strings, without being resolved or read. Returns
+    /// true if any links were removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Link, Links};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::child("./child/catalog.json"));
+    /// assert!(catalog.remove_child_href("./child/catalog.json"));
+    /// assert!(catalog.links.is_empty());
+    /// ```
+    fn remove_child_href(&mut self, href: &str) -> bool {
+        remove_links_by_href(self.links_mut(), href, Link::is_child)
+    }
+
+    /// Removes this object's item links with the given href.
+    ///
+    /// Hrefs are compared as strings, without being resolved or read. Returns
+    /// true if any links were removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Link, Links};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::item("./item/item.json"));
+    /// assert!(catalog.remove_item_href("./item/item.json"));
+    /// assert!(catalog.links.is_empty());
+    /// ```
+    fn remove_item_href(&mut self, href: &str) -> bool {
+        remove_links_by_href(self.links_mut(), href, Link::is_item)
+    }
+
+    /// Removes child and item links whose targets no longer exist.
+    ///
+    /// Each child and item link is resolved against `base_href` and its target
+    /// is checked for existence, without being read, as in [crate::exists].
+    /// Links to missing targets are removed and their hrefs are returned.
+    /// Checking urls requires the `reqwest` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Links, Link};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::item("./simple-item.json"));
+    /// catalog.links.push(Link::item("./not-an-item.json"));
+    /// let removed = catalog.prune_dead_links("data/catalog.json").unwrap();
+    /// assert_eq!(removed, vec!["./not-an-item.json"]);
+    /// assert_eq!(catalog.links.len(), 1);
+    /// ```
+    fn prune_dead_links(&mut self, base_href: &str) -> Result<Vec<String>> {
+        let mut removed = Vec::new();
+        let options = CheckLinksOptions::default();
+        let mut client = None;
+        for link in self
+            .links()
+            .iter()
+            .filter(|link| link.is_child() || link.is_item())
+        {
+            let href = link.absolute_href(base_href)?;
+            match crate::io::check_href(&href, &options, &mut client) {
+                Ok(()) => {}
+                Err(err) if err.is_not_found() => removed.push(link.href.clone()),
+                Err(err) => return Err(err),
+            }
+        }
+        self.links_mut()
+            .retain(|link| !((link.is_child() || link.is_item()) && removed.contains(&link.href)));
+        Ok(removed)
+    }
+
+    /// Checks that the target of every link exists.
+    ///
+    /// Each link is resolved against `base_href`. Files are checked for
+    /// existence, and urls are checked with a `HEAD` request, which requires
+    /// the `reqwest` feature. Returns every link with the result of its check,
+    /// so broken links are the ones with an error. Nothing is removed; see
+    /// [Links::prune_dead_links] for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Links, Link};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::item("./simple-item.json"));
+    /// catalog.links.push(Link::item("./not-an-item.json"));
+    /// let broken: Vec<_> = catalog
+    ///     .check_links("data/catalog.json")
+    ///     .into_iter()
+    ///     .filter(|(_, result)| result.is_err())
+    ///     .map(|(link, _)| link.href)
+    ///     .collect();
+    /// assert_eq!(broken, vec!["./not-an-item.json"]);
+    /// ```
+    fn check_links(&self, base_href: &str) -> Vec<(Link, Result<()>)> {
+        self.check_links_with(base_href, &CheckLinksOptions::default())
+    }
+
+    /// Checks that the target of every link exists, with options.
+    ///
+    /// See [Links::check_links] for how links are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{link::CheckLinksOptions, Catalog, Links, Link};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::item("./simple-item.json"));
+    /// let options = CheckLinksOptions {
+    ///     method: "GET".to_string(),
+    ///     timeout: Some(std::time::Duration::from_secs(5)),
+    /// };
+    /// let results = catalog.check_links_with("data/catalog.json", &options);
+    /// assert!(results[0].1.is_ok());
+    /// ```
+    fn check_links_with(
+        &self,
+        base_href: &str,
+        options: &CheckLinksOptions,
+    ) -> Vec<(Link, Result<()>)> {
+        crate::io::check_links(self.links(), base_href, options)
+    }
+
+    /// Removes duplicate links and sorts the remaining links into a canonical order.
+    ///
+    /// Links that are exactly equal to an earlier link are removed. The
+    /// remaining links are ordered self, root, parent, and collection, followed
+    /// by all other links in their original order. This is opt-in, and is
+    /// useful for producing stable, diff-friendly output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Links, Link};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.links.push(Link::child("./child.json"));
+    /// catalog.links.push(Link::root("./catalog.json"));
+    /// catalog.links.push(Link::child("./child.json"));
+    /// catalog.normalize();
+    /// assert_eq!(catalog.links.len(), 2);
+    /// assert!(catalog.links[0].is_root());
+    /// ```
+    fn normalize(&mut self) {
+        let mut links: Vec<Link> = Vec::with_capacity(self.links().len());
+        for link in self.links_mut().drain(..) {
+            if !links.contains(&link) {
+                links.push(link);
+            }
+        }
+        links.sort_by_key(|link| match link.rel.as_str() {
+            SELF_REL => 0,
+            ROOT_REL => 1,
+            PARENT_REL => 2,
+            COLLECTION_REL => 3,
+            _ => 4,
+        });
+        *self.links_mut() = links;
+    }
 }
 
 impl Link {
@@ -283,6 +739,8 @@ impl Link {
             rel: rel.to_string(),
             r#type: None,
             title: None,
+            hreflang: None,
+            length: None,
             method: None,
             headers: None,
             body: None,
@@ -463,6 +921,20 @@ impl Link {
         Link::new(href, COLLECTION_REL).json()
     }
 
+    /// Creates a new canonical link with JSON media type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stac::{Link, media_type};
+    /// let link = Link::canonical("an-href");
+    /// assert!(link.is_canonical());
+    /// assert_eq!(link.r#type.as_ref().unwrap(), media_type::JSON);
+    /// ```
+    pub fn canonical(href: impl ToString) -> Link {
+        Link::new(href, CANONICAL_REL).json()
+    }
+
     /// Returns true if this link's rel is `"item"`.
     ///
     /// # Examples
@@ -478,6 +950,20 @@ impl Link {
         self.rel == ITEM_REL
     }
 
+    /// Returns true if this link's rel is `"items"`, i.e. it points to a STAC API items endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stac::Link;
+    /// let link = Link::new("an-href", "items");
+    /// assert!(link.is_items());
+    /// assert!(!link.is_item());
+    /// ```
+    pub fn is_items(&self) -> bool {
+        self.rel == ITEMS_REL
+    }
+
     /// Returns true if this link's rel is `"child"`.
     ///
     /// # Examples
@@ -553,6 +1039,21 @@ impl Link {
         self.rel == COLLECTION_REL
     }
 
+    /// Returns true if this link's rel is `"canonical"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stac::Link;
+    /// let link = Link::new("an-href", "canonical");
+    /// assert!(link.is_canonical());
+    /// let link = Link::new("an-href", "not-canonical");
+    /// assert!(!link.is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool {
+        self.rel == CANONICAL_REL
+    }
+
     /// Returns true if this link is structural (i.e. not child, parent, item,
     /// root, or self).
     ///
@@ -585,6 +1086,96 @@ impl Link {
         is_absolute(&self.href)
     }
 
+    /// Returns this link's href, resolved against a base href if it is relative.
+    ///
+    /// A relative base is resolved against the current directory. This only
+    /// joins and normalizes paths, so neither the base nor the href have to
+    /// exist, and symlinks aren't followed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Link;
+    /// let link = Link::new("./item.json", "item");
+    /// assert_eq!(
+    ///     link.absolute_href("http://stac-rs.test/catalog.json").unwrap(),
+    ///     "http://stac-rs.test/item.json"
+    /// );
+    /// ```
+    pub fn absolute_href(&self, base: &str) -> Result<String> {
+        let base = if is_absolute(base) {
+            base.to_string()
+        } else {
+            let base = std::env::current_dir()?.join(base);
+            normalize_path(&base.to_string_lossy())
+        };
+        make_absolute(self.href.clone(), Some(&base))
+    }
+
+    /// Expands a URI template in this link's href, e.g. `./items/{item_id}`.
+    ///
+    /// This is [RFC 6570](https://www.rfc-editor.org/rfc/rfc6570) simple
+    /// string expansion: each `{name}` is replaced with the value of `name` in
+    /// `params`, with every character other than letters, digits, `-`, `.`,
+    /// `_`, and `~` percent-encoded. Every placeholder is required, so a
+    /// placeholder that isn't in `params` is an error, as is a template that
+    /// uses an operator (e.g. `{?name}`) or an unmatched brace. Parameters that
+    /// aren't in the template are ignored. This link isn't changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Link;
+    /// use std::collections::HashMap;
+    /// let link = Link::new("http://stac-rs.test/collections/{collection_id}/items/{item_id}", "item");
+    /// let params: HashMap<String, String> = [
+    ///     ("collection_id".to_string(), "a collection".to_string()),
+    ///     ("item_id".to_string(), "an-item".to_string()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(
+    ///     link.expand(&params).unwrap(),
+    ///     "http://stac-rs.test/collections/a%20collection/items/an-item"
+    /// );
+    /// ```
+    pub fn expand(&self, params: &HashMap<String, String>) -> Result<String> {
+        let mut expanded = String::with_capacity(self.href.len());
+        let mut rest = self.href.as_str();
+        while let Some(start) = rest.find(['{', '}']) {
+            if rest[start..].starts_with('}') {
+                return Err(Error::InvalidTemplate(self.href.clone()));
+            }
+            expanded.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+            let end = rest
+                .find(['{', '}'])
+                .filter(|&end| rest[end..].starts_with('}'))
+                .ok_or_else(|| Error::InvalidTemplate(self.href.clone()))?;
+            let name = &rest[..end];
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+            {
+                return Err(Error::InvalidTemplate(self.href.clone()));
+            }
+            let value = params
+                .get(name)
+                .ok_or_else(|| Error::MissingTemplateParameter(name.to_string()))?;
+            for byte in value.bytes() {
+                if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                    expanded.push(byte as char);
+                } else {
+                    expanded.push_str(&format!("%{:02X}", byte));
+                }
+            }
+            rest = &rest[end + 1..];
+        }
+        expanded.push_str(rest);
+        Ok(expanded)
+    }
+
     /// Sets a link's href's query to anything serializable by [serde_urlencoded].
     ///
     /// Raises an error if the href is not parseable as a url. Requires the
@@ -611,19 +1202,79 @@ impl Link {
     }
 }
 
-fn is_absolute(href: &str) -> bool {
+fn remove_links_by_id<L: Links + Href>(
+    object: &mut L,
+    id: &str,
+    is_rel: fn(&Link) -> bool,
+) -> Result<bool> {
+    let mut hrefs = Vec::new();
+    for link in object.links().iter().filter(|link| is_rel(link)) {
+        let target_id = if let Some(target_id) = link.additional_fields.get("id") {
+            target_id.as_str().map(String::from)
+        } else {
+            let href = match object.href() {
+                Some(base) => link.absolute_href(base)?,
+                None => link.href.clone(),
+            };
+            let value: Value = crate::read_json(&href)?;
+            value.get("id").and_then(Value::as_str).map(String::from)
+        };
+        if target_id.as_deref() == Some(id) {
+            hrefs.push(link.href.clone());
+        }
+    }
+    let mut removed = false;
+    for href in hrefs {
+        removed |= remove_links_by_href(object.links_mut(), &href, is_rel);
+    }
+    Ok(removed)
+}
+
+fn remove_links_by_href(links: &mut Vec<Link>, href: &str, is_rel: fn(&Link) -> bool) -> bool {
+    let len = links.len();
+    links.retain(|link| !(is_rel(link) && link.href == href));
+    links.len() != len
+}
+
+pub(crate) fn set_singleton_href(links: &mut Vec<Link>, rel: &str, href: String) {
+    let link = if let Some(link) = links.iter().find(|link| link.rel == rel) {
+        Link {
+            href,
+            ..link.clone()
+        }
+    } else {
+        Link::new(href, rel)
+    };
+    upsert_singleton(links, link.json())
+}
+
+/// Replaces the first link with the same rel in place, or adds it at the end, and removes the others.
+fn upsert_singleton(links: &mut Vec<Link>, link: Link) {
+    if let Some(i) = links.iter().position(|l| l.rel == link.rel) {
+        links.retain(|l| l.rel != link.rel);
+        links.insert(i, link);
+    } else {
+        links.push(link);
+    }
+}
+
+pub(crate) fn is_absolute(href: &str) -> bool {
     Url::parse(&href).is_ok() || href.starts_with('/')
 }
 
-fn make_absolute(href: String, base: Option<&str>) -> Result<String> {
+pub(crate) fn make_absolute(href: String, base: Option<&str>) -> Result<String> {
     // TODO if we make this interface public, make this an impl Option
     if is_absolute(&href) {
         Ok(href)
     } else if let Some(base) = base {
         if let Ok(base) = Url::parse(base) {
-            base.join(&href)
-                .map(|url| url.to_string())
-                .map_err(Error::from)
+            let mut url = base.join(&href)?;
+            // Relative hrefs from STAC APIs often rely on the base's query
+            // (e.g. an access token), so carry it over unless the href has its own.
+            if url.query().is_none() && !href.starts_with('#') {
+                url.set_query(base.query());
+            }
+            Ok(url.to_string())
         } else {
             let (base, _) = base.split_at(base.rfind('/').unwrap_or(0));
             if base.is_empty() {
@@ -660,6 +1311,39 @@ fn normalize_path(path: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::Link;
+    use crate::Error;
+    use std::collections::HashMap;
+
+    #[test]
+    fn expand() {
+        let params: HashMap<String, String> = [
+            ("id".to_string(), "an/id?é".to_string()),
+            ("collection.id".to_string(), "a-collection".to_string()),
+            ("unused".to_string(), "unused".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let link = Link::new("./{collection.id}/items/{id}.json", "item").json();
+        assert_eq!(
+            link.expand(&params).unwrap(),
+            "./a-collection/items/an%2Fid%3F%C3%A9.json"
+        );
+        assert_eq!(link.href, "./{collection.id}/items/{id}.json");
+        assert_eq!(
+            Link::new("./items", "items").expand(&params).unwrap(),
+            "./items"
+        );
+        assert!(matches!(
+            Link::new("./{missing}", "item").expand(&params).unwrap_err(),
+            Error::MissingTemplateParameter(name) if name == "missing"
+        ));
+        for href in ["./{id", "./id}", "./{}", "./{?id}", "./{{id}}"] {
+            assert!(matches!(
+                Link::new(href, "item").expand(&params).unwrap_err(),
+                Error::InvalidTemplate(_)
+            ));
+        }
+    }
 
     #[test]
     fn new() {
@@ -668,6 +1352,8 @@ mod tests {
         assert_eq!(link.rel, "a-rel");
         assert!(link.r#type.is_none());
         assert!(link.title.is_none());
+        assert!(link.hreflang.is_none());
+        assert!(link.length.is_none());
     }
 
     #[test]
@@ -676,6 +1362,23 @@ mod tests {
         let value = serde_json::to_value(link).unwrap();
         assert!(value.get("type").is_none());
         assert!(value.get("title").is_none());
+        assert!(value.get("hreflang").is_none());
+        assert!(value.get("length").is_none());
+    }
+
+    #[test]
+    fn hreflang_and_length() {
+        let value = serde_json::json!({
+            "href": "./README.de.md",
+            "rel": "alternate",
+            "hreflang": "de-CH",
+            "length": 1024
+        });
+        let link: Link = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(link.hreflang.as_deref(), Some("de-CH"));
+        assert_eq!(link.length, Some(1024));
+        assert!(link.additional_fields.is_empty());
+        assert_eq!(serde_json::to_value(link).unwrap(), value);
     }
 
     #[test]
@@ -688,8 +1391,79 @@ mod tests {
         assert_eq!(link.href, "http://stac-rs.test/an-href?baz=boz");
     }
 
+    #[test]
+    fn absolute_href() {
+        let link = Link::new("../item.json", "item");
+        let current_dir = std::env::current_dir().unwrap();
+        assert_eq!(
+            link.absolute_href("not-a-directory/catalog/catalog.json")
+                .unwrap(),
+            format!(
+                "{}/not-a-directory/item.json",
+                current_dir.to_string_lossy()
+            )
+        );
+        assert_eq!(
+            link.absolute_href("/not-a-directory/catalog/catalog.json")
+                .unwrap(),
+            "/not-a-directory/item.json"
+        );
+    }
+
     mod links {
-        use crate::{Catalog, Item, Link, Links};
+        use crate::{Catalog, Item, ItemCollection, Link, Links};
+
+        #[test]
+        fn add_link_if_absent_is_idempotent() {
+            let build = |catalog: &mut Catalog| {
+                let _ = catalog.add_link_if_absent(Link::root("./catalog.json"));
+                let _ = catalog.add_link_if_absent(Link::child("./a/catalog.json"));
+                let _ = catalog.add_link_if_absent(Link::child("./b/catalog.json"));
+                let _ = catalog.add_link_if_absent(Link::item("./a/catalog.json"));
+            };
+            let mut catalog = Catalog::new("an-id", "a description");
+            build(&mut catalog);
+            let links = catalog.links.clone();
+            assert_eq!(links.len(), 4);
+            build(&mut catalog);
+            build(&mut catalog);
+            assert_eq!(catalog.links, links);
+            assert!(
+                !catalog.add_link_if_absent(Link::child("./a/catalog.json").title("A".to_string()))
+            );
+            assert!(catalog.links[1].title.is_none());
+        }
+
+        #[test]
+        fn upsert_link_is_idempotent() {
+            let mut item = Item::new("an-id");
+            item.links.push(Link::parent("./a.json"));
+            item.links.push(Link::root("./catalog.json"));
+            item.links.push(Link::parent("./b.json"));
+            for _ in 0..3 {
+                item.upsert_link(Link::parent("./c.json"));
+                assert_eq!(item.links.len(), 2);
+                assert_eq!(item.links[0].href, "./c.json");
+                assert!(item.links[1].is_root());
+            }
+            item.upsert_link(Link::self_("./an-id.json"));
+            assert!(item.links[2].is_self());
+        }
+
+        #[test]
+        fn links_iter_mut_by_rel() {
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::child("./a.json"));
+            catalog.links.push(Link::root("./catalog.json"));
+            catalog.links.push(Link::child("./b.json"));
+            for link in catalog.links_iter_mut_by_rel("child") {
+                link.href = format!("./children/{}", &link.href[2..]);
+            }
+            assert_eq!(catalog.links[0].href, "./children/a.json");
+            assert_eq!(catalog.links[1].href, "./catalog.json");
+            assert_eq!(catalog.links[2].href, "./children/b.json");
+            assert_eq!(catalog.links_iter_mut_by_rel("item").count(), 0);
+        }
 
         #[test]
         fn link() {
@@ -715,6 +1489,33 @@ mod tests {
             assert!(item.self_link().is_some());
         }
 
+        #[test]
+        fn set_self_href() {
+            let mut item = Item::new("an-item");
+            item.links.push(Link::new("./a.json", "self"));
+            item.links.push(Link::root("./catalog.json"));
+            item.links
+                .push(Link::new("./b.json", "self").title("B".to_string()));
+            item.set_self_href("./c.json");
+            assert_eq!(item.links.len(), 2);
+            assert_eq!(item.links.iter().filter(|link| link.is_self()).count(), 1);
+            let link = &item.links[0];
+            assert_eq!(link.href, "./c.json");
+            assert!(link.is_json());
+        }
+
+        #[test]
+        fn set_root_and_parent_href() {
+            let mut item = Item::new("an-item");
+            item.set_root_href("./catalog.json");
+            item.set_parent_href("./catalog.json");
+            item.set_root_href("../catalog.json");
+            item.set_parent_href("../collection.json");
+            assert_eq!(item.links.len(), 2);
+            assert_eq!(item.root_link().unwrap().href, "../catalog.json");
+            assert_eq!(item.parent_link().unwrap().href, "../collection.json");
+        }
+
         #[test]
         fn make_relative_links_absolute_path() {
             let mut catalog: Catalog = crate::read("data/catalog.json").unwrap();
@@ -741,6 +1542,48 @@ mod tests {
             );
         }
 
+        #[test]
+        fn make_relative_links_absolute_api_url() {
+            let mut item_collection = ItemCollection::from(Vec::new());
+            item_collection.links.push(Link::item("../items/abc"));
+            item_collection.links.push(Link::item("./abc?page=2"));
+            item_collection
+                .make_relative_links_absolute("https://stac-rs.test/collections/xyz/items")
+                .unwrap();
+            assert_eq!(
+                item_collection.links[0].href,
+                "https://stac-rs.test/collections/items/abc"
+            );
+            assert_eq!(
+                item_collection.links[1].href,
+                "https://stac-rs.test/collections/xyz/abc?page=2"
+            );
+        }
+
+        #[test]
+        fn make_relative_links_absolute_keeps_query() {
+            let mut item_collection = ItemCollection::from(Vec::new());
+            item_collection.links.push(Link::item("../items/abc"));
+            item_collection.links.push(Link::item("./abc?page=2"));
+            item_collection
+                .links
+                .push(Link::item("https://stac-rs.test/other"));
+            item_collection
+                .make_relative_links_absolute(
+                    "https://stac-rs.test/collections/xyz/items?token=secret",
+                )
+                .unwrap();
+            assert_eq!(
+                item_collection.links[0].href,
+                "https://stac-rs.test/collections/items/abc?token=secret"
+            );
+            assert_eq!(
+                item_collection.links[1].href,
+                "https://stac-rs.test/collections/xyz/abc?page=2"
+            );
+            assert_eq!(item_collection.links[2].href, "https://stac-rs.test/other");
+        }
+
         #[test]
         fn remove_relative_links() {
             let mut catalog = Catalog::new("an-id", "a description");
@@ -753,5 +1596,139 @@ mod tests {
             assert_eq!(catalog.links.len(), 2);
             assert_eq!(removed.len(), 1);
         }
+
+        #[test]
+        fn remove_child() {
+            let mut catalog: Catalog = crate::read("data/catalog.json").unwrap();
+            let mut link = Link::child("./not-a-child.json");
+            let _ = link
+                .additional_fields
+                .insert("id".to_string(), "hinted".into());
+            catalog.links.push(link);
+            assert!(catalog.remove_child("hinted").unwrap());
+            assert!(catalog.remove_child("sentinel-2").unwrap());
+            assert_eq!(catalog.iter_child_links().count(), 1);
+            assert!(!catalog.remove_item("sentinel-2").unwrap());
+
+            catalog.links.push(Link::child("./not-a-child.json"));
+            assert!(catalog.remove_child("anything").is_err());
+            assert!(catalog.remove_child_href("./not-a-child.json"));
+            assert!(!catalog.remove_item_href("./extensions-collection/collection.json"));
+        }
+
+        #[test]
+        fn prune_dead_links() {
+            let mut catalog: Catalog = crate::read("data/catalog.json").unwrap();
+            let count = catalog.links.len();
+            catalog
+                .links
+                .push(Link::child("./not-a-child/catalog.json"));
+            catalog.links.push(Link::item("./not-an-item.json"));
+            catalog
+                .links
+                .push(Link::new("./not-a-license.txt", "license"));
+            let removed = catalog.prune_dead_links("data/catalog.json").unwrap();
+            assert_eq!(
+                removed,
+                ["./not-a-child/catalog.json", "./not-an-item.json"]
+            );
+            assert_eq!(catalog.links.len(), count + 1);
+        }
+
+        #[test]
+        fn check_links() {
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::self_("./catalog.json"));
+            catalog
+                .links
+                .push(Link::child("./extensions-collection/collection.json"));
+            catalog
+                .links
+                .push(Link::child("./not-a-child/catalog.json"));
+            let results = catalog.check_links("data/catalog.json");
+            assert_eq!(results.len(), 3);
+            assert!(results[0].1.is_ok());
+            assert!(results[1].1.is_ok());
+            assert_eq!(results[2].0.href, "./not-a-child/catalog.json");
+            assert!(results[2].1.as_ref().unwrap_err().is_not_found());
+            assert_eq!(catalog.links.len(), 3);
+        }
+
+        #[test]
+        fn normalize() {
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::child("./b.json"));
+            catalog.links.push(Link::new("./license.txt", "license"));
+            catalog.links.push(Link::child("./a.json"));
+            catalog.links.push(Link::parent("../catalog.json"));
+            catalog.links.push(Link::root("../catalog.json"));
+            catalog.links.push(Link::self_("./catalog.json"));
+            catalog.links.push(Link::child("./b.json"));
+            catalog.links.push(Link::collection("../collection.json"));
+            catalog.normalize();
+            let hrefs: Vec<_> = catalog.links.iter().map(|l| l.href.as_str()).collect();
+            assert_eq!(
+                hrefs,
+                [
+                    "./catalog.json",
+                    "../catalog.json",
+                    "../catalog.json",
+                    "../collection.json",
+                    "./b.json",
+                    "./license.txt",
+                    "./a.json"
+                ]
+            );
+            assert!(catalog.links[1].is_root());
+            assert!(catalog.links[2].is_parent());
+        }
+
+        #[test]
+        fn item_links_and_items_endpoint() {
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::item("./a.json"));
+            catalog.links.push(Link::child("./child.json"));
+            catalog.links.push(Link::item("./b.json"));
+            let hrefs: Vec<_> = catalog
+                .item_links()
+                .into_iter()
+                .map(|link| link.href.as_str())
+                .collect();
+            assert_eq!(hrefs, ["./a.json", "./b.json"]);
+            assert!(catalog.items_endpoint_link().is_none());
+            catalog.links.push(Link::new(
+                "http://stac-rs.test/collections/an-id/items",
+                "items",
+            ));
+            assert_eq!(
+                catalog.items_endpoint_link().unwrap().href,
+                "http://stac-rs.test/collections/an-id/items"
+            );
+            assert_eq!(catalog.item_links().len(), 2);
+        }
+
+        #[test]
+        fn retain_links() {
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::child("./a.json"));
+            catalog
+                .links
+                .push(Link::new("./source.json", "derived_from"));
+            catalog.links.push(Link::child("./b.json"));
+            catalog.retain_links(|link| link.rel != "derived_from");
+            let hrefs: Vec<_> = catalog.links.iter().map(|l| l.href.as_str()).collect();
+            assert_eq!(hrefs, ["./a.json", "./b.json"]);
+        }
+
+        #[test]
+        fn normalize_keeps_links_that_differ() {
+            let mut catalog = Catalog::new("an-id", "a description");
+            catalog.links.push(Link::child("./a.json"));
+            catalog
+                .links
+                .push(Link::child("./a.json").title("A".to_string()));
+            catalog.normalize();
+            assert_eq!(catalog.links.len(), 2);
+        }
     }
 }
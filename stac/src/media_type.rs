@@ -38,6 +38,12 @@ pub const TEXT: &str = "text/plain";
 /// [GeoJSON](https://geojson.org/)
 pub const GEOJSON: &str = "application/geo+json";
 
+/// An [OpenAPI](https://www.openapis.org/) 3.0 definition, e.g. a STAC API's `service-desc`
+pub const OPENAPI: &str = "application/vnd.oai.openapi+json;version=3.0";
+
+/// HTML, e.g. a STAC API's `service-doc`
+pub const HTML: &str = "text/html";
+
 /// [GeoPackage](https://www.geopackage.org/)
 pub const GEOPACKAGE: &str = "application/geopackage+sqlite3";
 
@@ -46,3 +52,220 @@ pub const HDF5: &str = "application/x-hdf5";
 
 /// Hierarchical Data Format versions 4 and earlier.
 pub const HDF: &str = "application/x-hdf";
+
+/// [GeoParquet](https://geoparquet.org/)
+pub const GEOPARQUET: &str = "application/vnd.apache.parquet";
+
+/// [Zarr](https://zarr.dev/)
+pub const ZARR: &str = "application/vnd+zarr";
+
+/// [Cloud Optimized Point Cloud](https://copc.io/)
+pub const COPC: &str = "application/vnd.laszip+copc";
+
+/// [FlatGeobuf](https://flatgeobuf.org/)
+pub const FLATGEOBUF: &str = "application/vnd.flatgeobuf";
+
+/// Cloud-native media types, which can be read partially without downloading the whole file.
+///
+/// Used by [is_cloud_optimized].
+pub const CLOUD_OPTIMIZED: [&str; 5] = [COG, GEOPARQUET, ZARR, COPC, FLATGEOBUF];
+
+/// Media types that are read partially via HTTP range requests.
+///
+/// This is [CLOUD_OPTIMIZED] without [ZARR], which stores its chunks as separate objects.
+/// Used by [requires_range_requests].
+pub const RANGE_REQUESTS: [&str; 4] = [COG, GEOPARQUET, COPC, FLATGEOBUF];
+
+/// Returns true if this media type is a cloud-native format.
+///
+/// Comparisons ignore case and whitespace around parameters.
+///
+/// # Examples
+///
+/// ```
+/// use stac::media_type;
+/// assert!(media_type::is_cloud_optimized(media_type::COG));
+/// assert!(media_type::is_cloud_optimized("image/tiff;application=geotiff;profile=cloud-optimized"));
+/// assert!(!media_type::is_cloud_optimized(media_type::GEOTIFF));
+/// ```
+pub fn is_cloud_optimized(media_type: &str) -> bool {
+    matches_any(media_type, &CLOUD_OPTIMIZED)
+}
+
+/// Returns true if partial reads of this media type use HTTP range requests.
+///
+/// Comparisons ignore case and whitespace around parameters.
+///
+/// # Examples
+///
+/// ```
+/// use stac::media_type;
+/// assert!(media_type::requires_range_requests(media_type::COG));
+/// assert!(!media_type::requires_range_requests(media_type::ZARR));
+/// ```
+pub fn requires_range_requests(media_type: &str) -> bool {
+    matches_any(media_type, &RANGE_REQUESTS)
+}
+
+/// Returns true if this media type is JSON, including GeoJSON and other `+json` types.
+///
+/// Parameters (e.g. `charset`) and case are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use stac::media_type;
+/// assert!(media_type::is_json(media_type::GEOJSON));
+/// assert!(media_type::is_json("application/json; charset=utf-8"));
+/// assert!(media_type::is_json("application/schema+json"));
+/// assert!(!media_type::is_json("text/html"));
+/// ```
+pub fn is_json(media_type: &str) -> bool {
+    let media_type = normalize(media_type);
+    let essence = media_type.split(';').next().unwrap_or_default();
+    essence == JSON || essence.ends_with("+json")
+}
+
+/// Media types and their file extensions, used by [extension_for].
+const EXTENSIONS: [(&str, &str); 24] = [
+    (COG, "tif"),
+    (GEOTIFF, "tif"),
+    ("image/tiff", "tif"),
+    (JP2, "jp2"),
+    (PNG, "png"),
+    (JPEG, "jpg"),
+    ("image/webp", "webp"),
+    (XML, "xml"),
+    ("application/xml", "xml"),
+    (JSON, "json"),
+    (GEOJSON, "geojson"),
+    (TEXT, "txt"),
+    ("text/html", "html"),
+    ("text/csv", "csv"),
+    ("application/pdf", "pdf"),
+    ("application/zip", "zip"),
+    (GEOPACKAGE, "gpkg"),
+    (HDF5, "h5"),
+    (HDF, "hdf"),
+    ("application/x-netcdf", "nc"),
+    (GEOPARQUET, "parquet"),
+    (ZARR, "zarr"),
+    (COPC, "copc.laz"),
+    (FLATGEOBUF, "fgb"),
+];
+
+/// Returns the file extension, without a leading dot, for a media type.
+///
+/// Comparisons ignore case and whitespace around parameters. If the full media
+/// type isn't known, its parameters are dropped and the essence (e.g.
+/// `image/tiff`) is looked up instead. Returns `None` for unknown media types.
+///
+/// # Examples
+///
+/// ```
+/// use stac::media_type;
+/// assert_eq!(media_type::extension_for(media_type::COG), Some("tif"));
+/// assert_eq!(media_type::extension_for("application/json; charset=utf-8"), Some("json"));
+/// assert_eq!(media_type::extension_for("application/x-unknown"), None);
+/// ```
+pub fn extension_for(media_type: &str) -> Option<&'static str> {
+    let media_type = normalize(media_type);
+    let essence = media_type.split(';').next().unwrap_or_default();
+    let lookup = |media_type: &str| {
+        EXTENSIONS
+            .iter()
+            .find(|(candidate, _)| normalize(candidate) == media_type)
+            .map(|(_, extension)| *extension)
+    };
+    lookup(&media_type).or_else(|| lookup(essence))
+}
+
+fn matches_any(media_type: &str, media_types: &[&str]) -> bool {
+    let media_type = normalize(media_type);
+    media_types
+        .iter()
+        .any(|candidate| normalize(candidate) == media_type)
+}
+
+fn normalize(media_type: &str) -> String {
+    media_type
+        .split(';')
+        .map(|part| part.trim().to_lowercase())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloud_optimized() {
+        for media_type in [COG, GEOPARQUET, ZARR, COPC, FLATGEOBUF] {
+            assert!(is_cloud_optimized(media_type), "{}", media_type);
+        }
+        for media_type in [GEOTIFF, JP2, PNG, JSON, GEOJSON, GEOPACKAGE, HDF5] {
+            assert!(!is_cloud_optimized(media_type), "{}", media_type);
+        }
+    }
+
+    #[test]
+    fn range_requests() {
+        for media_type in [COG, GEOPARQUET, COPC, FLATGEOBUF] {
+            assert!(requires_range_requests(media_type), "{}", media_type);
+        }
+        for media_type in [ZARR, GEOTIFF, JSON] {
+            assert!(!requires_range_requests(media_type), "{}", media_type);
+        }
+    }
+
+    #[test]
+    fn json() {
+        for media_type in [JSON, GEOJSON, "Application/JSON;charset=UTF-8"] {
+            assert!(is_json(media_type), "{}", media_type);
+        }
+        for media_type in [TEXT, XML, "text/html", "application/jsonx"] {
+            assert!(!is_json(media_type), "{}", media_type);
+        }
+    }
+
+    #[test]
+    fn extension_for() {
+        for (media_type, extension) in [
+            (COG, "tif"),
+            (GEOTIFF, "tif"),
+            (JP2, "jp2"),
+            (PNG, "png"),
+            (JPEG, "jpg"),
+            (XML, "xml"),
+            (JSON, "json"),
+            (TEXT, "txt"),
+            (GEOJSON, "geojson"),
+            (GEOPACKAGE, "gpkg"),
+            (HDF5, "h5"),
+            (HDF, "hdf"),
+            (GEOPARQUET, "parquet"),
+            (ZARR, "zarr"),
+            (COPC, "copc.laz"),
+            (FLATGEOBUF, "fgb"),
+            ("application/pdf", "pdf"),
+            ("IMAGE/TIFF; application=geotiff; profile=unknown", "tif"),
+        ] {
+            assert_eq!(
+                super::extension_for(media_type),
+                Some(extension),
+                "{}",
+                media_type
+            );
+        }
+        assert_eq!(super::extension_for("application/x-unknown"), None);
+        assert_eq!(super::extension_for(""), None);
+    }
+
+    #[test]
+    fn normalize() {
+        assert!(is_cloud_optimized(
+            "Image/TIFF;  application=geotiff ;profile=cloud-optimized"
+        ));
+    }
+}
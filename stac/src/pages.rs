@@ -0,0 +1,278 @@
+//! Blocking iteration over the pages of a STAC API search.
+
+use crate::{Error, Item, ItemCollection, Link, Links, Result};
+use reqwest::{blocking::Client, header::ACCEPT, Method};
+use serde_json::{Map, Value};
+
+/// An iterator over the pages of a STAC API search, following `next` links.
+///
+/// The first page is the one the iterator was created with, and each
+/// following page is fetched, with a blocking request, when it's needed. `next`
+/// links can use `GET` or `POST`, and their `headers` and `body` are sent with
+/// the request. If a `next` link sets `merge`, its `headers` and `body` are
+/// merged into those of the previous request instead of replacing them; use
+/// [SearchPages::with_body] to set the body of the request for the first page.
+/// Iteration ends after a page without a `next` link, or after the first
+/// error. Requires the `reqwest` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use stac::{ItemCollection, SearchPages};
+/// let page: ItemCollection = stac::read_json("http://stac-rs.test/search").unwrap();
+/// for page in SearchPages::new(page) {
+///     println!("{} items", page.unwrap().items.len());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SearchPages {
+    client: Client,
+    page: Option<ItemCollection>,
+    next: Option<Link>,
+    headers: Option<Map<String, Value>>,
+    body: Option<Map<String, Value>>,
+}
+
+impl SearchPages {
+    /// Creates a new iterator, starting with `page`, that uses a default client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{ItemCollection, SearchPages};
+    /// let pages = SearchPages::new(ItemCollection::from(Vec::new()));
+    /// assert_eq!(pages.count(), 1);
+    /// ```
+    pub fn new(page: ItemCollection) -> SearchPages {
+        SearchPages::with_client(page, Client::new())
+    }
+
+    /// Creates a new iterator, starting with `page`, that uses the provided client.
+    ///
+    /// Use this to set e.g. timeouts or default headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{ItemCollection, SearchPages};
+    /// let client = reqwest::blocking::Client::new();
+    /// let pages = SearchPages::with_client(ItemCollection::from(Vec::new()), client);
+    /// ```
+    pub fn with_client(page: ItemCollection, client: Client) -> SearchPages {
+        SearchPages {
+            client,
+            page: Some(page),
+            next: None,
+            headers: None,
+            body: None,
+        }
+    }
+
+    /// Sets the body of the request that returned the first page.
+    ///
+    /// `next` links that set `merge` are merged into this body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use stac::{ItemCollection, SearchPages};
+    /// let body = json!({"collections": ["a-collection"]});
+    /// let pages = SearchPages::new(ItemCollection::from(Vec::new()))
+    ///     .with_body(body.as_object().unwrap().clone());
+    /// ```
+    pub fn with_body(mut self, body: Map<String, Value>) -> SearchPages {
+        self.body = Some(body);
+        self
+    }
+
+    /// Returns an iterator over the items of every page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Item, ItemCollection, SearchPages};
+    /// let page = ItemCollection::from(vec![Item::new("a"), Item::new("b")]);
+    /// let items: Vec<_> = SearchPages::new(page)
+    ///     .flatten_items()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(items.len(), 2);
+    /// ```
+    pub fn flatten_items(self) -> impl Iterator<Item = Result<Item>> {
+        self.flat_map(|page| -> Box<dyn Iterator<Item = Result<Item>>> {
+            match page {
+                Ok(page) => Box::new(page.items.into_iter().map(Ok)),
+                Err(err) => Box::new(std::iter::once(Err(err))),
+            }
+        })
+    }
+
+    fn fetch(&mut self, link: &Link) -> Result<ItemCollection> {
+        let method = match link.method.as_deref() {
+            Some(method) => Method::from_bytes(method.to_uppercase().as_bytes())
+                .map_err(|_| Error::InvalidMethod(method.to_string()))?,
+            None => Method::GET,
+        };
+        let mut request = self
+            .client
+            .request(method, &link.href)
+            .header(ACCEPT, crate::io::ACCEPT_JSON);
+        if link.merge.unwrap_or(false) {
+            self.headers = merge(self.headers.take(), link.headers.as_ref());
+            self.body = merge(self.body.take(), link.body.as_ref());
+        } else {
+            self.headers.clone_from(&link.headers);
+            self.body.clone_from(&link.body);
+        }
+        for (key, value) in self.headers.iter().flatten() {
+            let value = value
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| value.to_string());
+            request = request.header(key, value);
+        }
+        if let Some(body) = &self.body {
+            request = request.json(body);
+        }
+        request
+            .send()?
+            .error_for_status()?
+            .json()
+            .map_err(Error::from)
+    }
+}
+
+/// Merges the fields of a link's headers or body into those of the previous request.
+fn merge(
+    previous: Option<Map<String, Value>>,
+    link: Option<&Map<String, Value>>,
+) -> Option<Map<String, Value>> {
+    match (previous, link) {
+        (Some(mut previous), Some(link)) => {
+            previous.extend(link.iter().map(|(key, value)| (key.clone(), value.clone())));
+            Some(previous)
+        }
+        (previous, link) => previous.or_else(|| link.cloned()),
+    }
+}
+
+impl Iterator for SearchPages {
+    type Item = Result<ItemCollection>;
+
+    fn next(&mut self) -> Option<Result<ItemCollection>> {
+        let page = if let Some(page) = self.page.take() {
+            page
+        } else {
+            let link = self.next.take()?;
+            match self.fetch(&link) {
+                Ok(page) => page,
+                Err(err) => return Some(Err(err)),
+            }
+        };
+        self.next = page.link("next").cloned();
+        Some(Ok(page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchPages;
+    use crate::{Item, ItemCollection, Link};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn page(ids: &[&str], next: Option<Link>) -> ItemCollection {
+        let mut page =
+            ItemCollection::from(ids.iter().map(|id| Item::new(*id)).collect::<Vec<_>>());
+        page.links.extend(next);
+        page
+    }
+
+    #[test]
+    fn follow_next_links() {
+        let mut server = Server::new();
+        let mut post = Link::new(format!("{}/search", server.url()), "next");
+        post.method = Some("POST".to_string());
+        post.body = Some(json!({"token": "page-3"}).as_object().unwrap().clone());
+        let page_2 = server
+            .mock("GET", "/search")
+            .match_query(Matcher::UrlEncoded("token".into(), "page-2".into()))
+            .with_header("content-type", "application/geo+json")
+            .with_body(serde_json::to_string(&page(&["c"], Some(post))).unwrap())
+            .create();
+        let page_3 = server
+            .mock("POST", "/search")
+            .match_body(Matcher::Json(json!({"token": "page-3"})))
+            .with_header("content-type", "application/geo+json")
+            .with_body(serde_json::to_string(&page(&["d", "e"], None)).unwrap())
+            .create();
+        let first = page(
+            &["a", "b"],
+            Some(Link::new(
+                format!("{}/search?token=page-2", server.url()),
+                "next",
+            )),
+        );
+        let ids: Vec<_> = SearchPages::new(first)
+            .flatten_items()
+            .map(|item| item.unwrap().id)
+            .collect();
+        assert_eq!(ids, ["a", "b", "c", "d", "e"]);
+        page_2.assert();
+        page_3.assert();
+    }
+
+    #[test]
+    fn merge() {
+        let mut server = Server::new();
+        let mut next = Link::new(format!("{}/search", server.url()), "next");
+        next.method = Some("POST".to_string());
+        next.merge = Some(true);
+        next.body = Some(json!({"token": "page-3"}).as_object().unwrap().clone());
+        next.headers = Some(json!({"x-page": "3"}).as_object().unwrap().clone());
+        let page_2 = server
+            .mock("POST", "/search")
+            .match_body(Matcher::Json(
+                json!({"collections": ["a"], "limit": 1, "token": "page-2"}),
+            ))
+            .with_header("content-type", "application/geo+json")
+            .with_body(serde_json::to_string(&page(&["b"], Some(next.clone()))).unwrap())
+            .create();
+        let page_3 = server
+            .mock("POST", "/search")
+            .match_header("x-page", "3")
+            .match_body(Matcher::Json(
+                json!({"collections": ["a"], "limit": 1, "token": "page-3"}),
+            ))
+            .with_header("content-type", "application/geo+json")
+            .with_body(serde_json::to_string(&page(&["c"], None)).unwrap())
+            .create();
+        next.headers = None;
+        next.body = Some(json!({"token": "page-2"}).as_object().unwrap().clone());
+        let body = json!({"collections": ["a"], "limit": 1, "token": "page-1"});
+        let ids: Vec<_> = SearchPages::new(page(&["a"], Some(next)))
+            .with_body(body.as_object().unwrap().clone())
+            .flatten_items()
+            .map(|item| item.unwrap().id)
+            .collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        page_2.assert();
+        page_3.assert();
+    }
+
+    #[test]
+    fn stop_after_error() {
+        let mut server = Server::new();
+        let mock = server.mock("GET", "/search").with_status(500).create();
+        let first = page(
+            &["a"],
+            Some(Link::new(format!("{}/search", server.url()), "next")),
+        );
+        let mut pages = SearchPages::new(first);
+        assert!(pages.next().unwrap().is_ok());
+        assert!(pages.next().unwrap().is_err());
+        assert!(pages.next().is_none());
+        mock.assert();
+    }
+}
@@ -0,0 +1,154 @@
+//! The [processing extension](https://github.com/stac-extensions/processing).
+//!
+//! Processing describes how data was produced: its processing level, the
+//! facility that processed it, the software that was used, and a description
+//! of its lineage. The fields can be set on an [Asset](crate::Asset), in an
+//! item's [Properties](crate::Properties), or on a [Collection](crate::Collection).
+//! Other `processing:` fields, e.g. `processing:expression`, are left as-is in
+//! the object's additional fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use stac::Item;
+//! let item: Item = stac::read("examples/processing.json").unwrap();
+//! let processing = item.processing().unwrap().unwrap();
+//! assert_eq!(processing.level.unwrap(), "L2");
+//! assert_eq!(processing.software.unwrap()["Sen2Cor"], "2.8");
+//! ```
+
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// The schema URI of the processing extension.
+pub const SCHEMA_URI: &str = "https://stac-extensions.github.io/processing/v1.1.0/schema.json";
+
+const FIELDS: [&str; 4] = [
+    "processing:lineage",
+    "processing:level",
+    "processing:facility",
+    "processing:software",
+];
+
+/// The processing fields of an asset, an item, or a collection.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Processing {
+    /// A description of how the data was processed.
+    #[serde(rename = "processing:lineage", skip_serializing_if = "Option::is_none")]
+    pub lineage: Option<String>,
+
+    /// The processing level, e.g. `L1` or `L2A`.
+    #[serde(rename = "processing:level", skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+
+    /// The name of the facility that produced the data.
+    #[serde(
+        rename = "processing:facility",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub facility: Option<String>,
+
+    /// The software used to produce the data, as a map of names to versions.
+    #[serde(
+        rename = "processing:software",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub software: Option<BTreeMap<String, String>>,
+}
+
+impl Processing {
+    /// Returns true if none of the fields are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::processing::Processing;
+    /// assert!(Processing::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lineage.is_none()
+            && self.level.is_none()
+            && self.facility.is_none()
+            && self.software.is_none()
+    }
+
+    pub(crate) fn from_fields(fields: &Map<String, Value>) -> Result<Option<Processing>> {
+        crate::extensions::extract_fields(fields, &FIELDS)
+    }
+
+    pub(crate) fn into_fields(self, fields: &mut Map<String, Value>) -> Result<()> {
+        crate::extensions::replace_fields(fields, &FIELDS, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Processing, SCHEMA_URI};
+    use crate::{Asset, Collection, Extensions, Item};
+
+    #[test]
+    fn read() {
+        let item: Item = crate::read("examples/processing.json").unwrap();
+        let processing = item.processing().unwrap().unwrap();
+        assert_eq!(
+            processing.facility.unwrap(),
+            "Copernicus S2 Processing and Archiving Facility"
+        );
+        assert_eq!(processing.software.unwrap().len(), 2);
+        let processing = item.assets["visual"].processing().unwrap().unwrap();
+        assert_eq!(processing.level.unwrap(), "L2");
+        assert!(processing.lineage.is_none());
+    }
+
+    #[test]
+    fn none() {
+        assert!(Asset::new("an-href").processing().unwrap().is_none());
+        assert!(Item::new("an-id").processing().unwrap().is_none());
+        assert!(Collection::new("an-id", "a description")
+            .processing()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn set_keeps_unknown_fields() {
+        let mut item: Item = crate::read("examples/processing.json").unwrap();
+        let processing = Processing {
+            level: Some("L3".to_string()),
+            ..Default::default()
+        };
+        item.set_processing(processing.clone()).unwrap();
+        assert_eq!(item.processing().unwrap().unwrap(), processing);
+        let fields = &item.properties.additional_fields;
+        assert!(!fields.contains_key("processing:lineage"));
+        assert!(fields.contains_key("processing:expression"));
+        assert_eq!(item.extensions().unwrap(), [SCHEMA_URI]);
+        item.set_processing(Processing::default()).unwrap();
+        assert!(item.processing().unwrap().is_none());
+    }
+
+    #[test]
+    fn set_on_collection() {
+        let mut collection = Collection::new("an-id", "a description");
+        let mut processing = Processing {
+            facility: Some("a facility".to_string()),
+            ..Default::default()
+        };
+        let _ = processing
+            .software
+            .get_or_insert_with(Default::default)
+            .insert("stac-rs".to_string(), "0.5.0".to_string());
+        collection.set_processing(processing.clone()).unwrap();
+        assert!(collection.has_extension(SCHEMA_URI));
+        assert_eq!(
+            collection.additional_fields["processing:software"]["stac-rs"],
+            "0.5.0"
+        );
+        assert_eq!(collection.processing().unwrap().unwrap(), processing);
+    }
+
+    crate::tests::roundtrip!(processing, "examples/processing.json", Item);
+}
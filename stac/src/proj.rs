@@ -0,0 +1,324 @@
+//! Reproject bounding boxes with the [projection extension](https://github.com/stac-extensions/projection).
+
+use crate::{Error, Item, Result};
+use proj4rs::{errors::Error as ProjError, transform::transform, Proj};
+use serde_json::Value;
+
+const WGS84: &str = "+proj=longlat +datum=WGS84 +no_defs";
+
+/// The number of points sampled along each edge of a bbox, so curved edges
+/// are accounted for in the reprojected bounds.
+const EDGE_SAMPLES: usize = 21;
+
+impl Item {
+    /// Returns this item's bbox in WGS84 (EPSG:4326).
+    ///
+    /// If the item has a top-level `bbox`, it's returned as-is. Otherwise, the
+    /// `proj:bbox` is reprojected from the item's `proj:epsg` (or an
+    /// `EPSG:`-prefixed `proj:code`) to WGS84, sampling along each edge so the
+    /// result covers the whole reprojected box. Returns `None` if there's no
+    /// `bbox` and either `proj:bbox` or the EPSG code is missing or malformed.
+    /// Returns [Error::UnsupportedEpsg] if there's no projection definition for
+    /// the code. Requires the `proj` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// let mut item = stac::Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert("proj:epsg".to_string(), json!(32613));
+    /// let _ = item.properties.additional_fields.insert(
+    ///     "proj:bbox".to_string(),
+    ///     json!([500000.0, 4399880.0, 500120.0, 4400000.0]),
+    /// );
+    /// let bbox = item.bbox_wgs84().unwrap().unwrap();
+    /// assert!((bbox[0] - -105.0).abs() < 1e-3);
+    /// ```
+    pub fn bbox_wgs84(&self) -> Result<Option<Vec<f64>>> {
+        if let Some(bbox) = &self.bbox {
+            return Ok(Some(bbox.clone()));
+        }
+        let fields = &self.properties.additional_fields;
+        match (
+            fields.get("proj:bbox").and_then(proj_bbox),
+            epsg(fields.get("proj:epsg"), fields.get("proj:code")),
+        ) {
+            (Some(bbox), Some(epsg)) => reproject(&bbox, epsg).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the union of this item's asset footprints, in WGS84.
+    ///
+    /// Each asset's `proj:bbox` is reprojected from its `proj:epsg` (or
+    /// `proj:code`) to WGS84, as in [Item::bbox_wgs84], and the results are
+    /// combined into one two-dimensional bbox. Fields that aren't set on an
+    /// asset are taken from the item's properties, as in [Item::asset_field].
+    /// Assets without projection information are skipped. If no asset has
+    /// projection information, this falls back to the bbox of the item's
+    /// geometry, and then to [Item::bbox_wgs84]. Requires the `proj` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use stac::{Asset, Item};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert("proj:epsg".to_string(), json!(32613));
+    /// for (key, bbox) in [
+    ///     ("west", [500000.0, 4399880.0, 500120.0, 4400000.0]),
+    ///     ("east", [500120.0, 4399880.0, 500240.0, 4400000.0]),
+    /// ] {
+    ///     let mut asset = Asset::new(format!("{}.tif", key));
+    ///     let _ = asset.additional_fields.insert("proj:bbox".to_string(), json!(bbox));
+    ///     let _ = item.assets.insert(key.to_string(), asset);
+    /// }
+    /// let bbox = item.union_bbox_with_assets().unwrap().unwrap();
+    /// assert!(bbox[2] - bbox[0] > 0.002);
+    /// ```
+    pub fn union_bbox_with_assets(&self) -> Result<Option<Vec<f64>>> {
+        let mut union: Option<Vec<f64>> = None;
+        for key in self.assets.keys() {
+            let bbox = self.asset_field(key, "proj:bbox").and_then(proj_bbox);
+            let epsg = epsg(
+                self.asset_field(key, "proj:epsg"),
+                self.asset_field(key, "proj:code"),
+            );
+            if let (Some(bbox), Some(epsg)) = (bbox, epsg) {
+                let bbox = reproject(&bbox, epsg)?;
+                let (xmax, ymax) = if bbox.len() == 6 {
+                    (bbox[3], bbox[4])
+                } else {
+                    (bbox[2], bbox[3])
+                };
+                union = Some(match union {
+                    Some(union) => vec![
+                        union[0].min(bbox[0]),
+                        union[1].min(bbox[1]),
+                        union[2].max(xmax),
+                        union[3].max(ymax),
+                    ],
+                    None => vec![bbox[0], bbox[1], xmax, ymax],
+                });
+            }
+        }
+        if union.is_some() {
+            Ok(union)
+        } else if let Some(bbox) = self.compute_bbox() {
+            Ok(Some(bbox))
+        } else {
+            self.bbox_wgs84()
+        }
+    }
+}
+
+/// Reprojects a bbox, as returned by [proj_bbox], from an EPSG code to WGS84.
+pub(crate) fn reproject(bbox: &[f64], epsg: i64) -> Result<Vec<f64>> {
+    let source = u16::try_from(epsg)
+        .map_err(|_| Error::UnsupportedEpsg(epsg))
+        .and_then(|code| match Proj::from_epsg_code(code) {
+            Err(ProjError::ProjectionNotFound) => Err(Error::UnsupportedEpsg(epsg)),
+            result => result.map_err(Error::from),
+        })?;
+    let target = Proj::from_proj_string(WGS84)?;
+    let (xmin, ymin, xmax, ymax) = (bbox[0], bbox[1], bbox[2], bbox[3]);
+    let mut points = Vec::with_capacity(4 * EDGE_SAMPLES);
+    for i in 0..EDGE_SAMPLES {
+        let t = i as f64 / (EDGE_SAMPLES - 1) as f64;
+        let x = xmin + t * (xmax - xmin);
+        let y = ymin + t * (ymax - ymin);
+        points.extend([(x, ymin), (x, ymax), (xmin, y), (xmax, y)]);
+    }
+    let mut bounds = [
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for mut point in points {
+        if source.is_latlong() {
+            point = (point.0.to_radians(), point.1.to_radians());
+        }
+        transform(&source, &target, &mut point)?;
+        let (x, y) = (point.0.to_degrees(), point.1.to_degrees());
+        bounds = [
+            bounds[0].min(x),
+            bounds[1].min(y),
+            bounds[2].max(x),
+            bounds[3].max(y),
+        ];
+    }
+    if let Some((zmin, zmax)) = bbox.get(4).zip(bbox.get(5)) {
+        Ok(vec![
+            bounds[0], bounds[1], *zmin, bounds[2], bounds[3], *zmax,
+        ])
+    } else {
+        Ok(bounds.to_vec())
+    }
+}
+
+/// Returns a `proj:bbox` as `[xmin, ymin, xmax, ymax, (zmin, zmax)]`.
+///
+/// Three-dimensional boxes are stored as `[xmin, ymin, zmin, xmax, ymax, zmax]`,
+/// so the z values are moved to the end.
+fn proj_bbox(value: &Value) -> Option<Vec<f64>> {
+    let values = value
+        .as_array()?
+        .iter()
+        .map(Value::as_f64)
+        .collect::<Option<Vec<_>>>()?;
+    match values.len() {
+        4 => Some(values),
+        6 => Some(vec![
+            values[0], values[1], values[3], values[4], values[2], values[5],
+        ]),
+        _ => None,
+    }
+}
+
+fn epsg(epsg: Option<&Value>, code: Option<&Value>) -> Option<i64> {
+    epsg.and_then(Value::as_i64).or_else(|| {
+        code.and_then(Value::as_str)
+            .and_then(|code| code.strip_prefix("EPSG:"))
+            .and_then(|code| code.parse().ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Item};
+    use serde_json::json;
+
+    fn with_proj(epsg: i64, bbox: serde_json::Value) -> Item {
+        let mut item = Item::new("an-id");
+        let fields = &mut item.properties.additional_fields;
+        let _ = fields.insert("proj:epsg".to_string(), json!(epsg));
+        let _ = fields.insert("proj:bbox".to_string(), bbox);
+        item
+    }
+
+    #[test]
+    fn utm() {
+        let item = with_proj(32613, json!([500000.0, 4399880.0, 500120.0, 4400000.0]));
+        let bbox = item.bbox_wgs84().unwrap().unwrap();
+        assert_eq!(bbox.len(), 4);
+        assert!((bbox[0] - -105.0).abs() < 1e-3);
+        assert!((bbox[2] - -105.0).abs() < 1e-2);
+        assert!((bbox[1] - 39.749).abs() < 1e-2);
+        assert!(bbox[0] < bbox[2]);
+        assert!(bbox[1] < bbox[3]);
+    }
+
+    #[test]
+    fn three_dimensional() {
+        let item = with_proj(
+            32613,
+            json!([500000.0, 4399880.0, 10.0, 500120.0, 4400000.0, 20.0]),
+        );
+        let bbox = item.bbox_wgs84().unwrap().unwrap();
+        assert_eq!(bbox.len(), 6);
+        assert_eq!(bbox[2], 10.0);
+        assert_eq!(bbox[5], 20.0);
+    }
+
+    #[test]
+    fn geographic() {
+        let item = with_proj(4326, json!([-105.0, 39.0, -104.0, 40.0]));
+        let bbox = item.bbox_wgs84().unwrap().unwrap();
+        for (actual, expected) in bbox.iter().zip([-105.0, 39.0, -104.0, 40.0]) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn proj_code() {
+        let mut item = with_proj(32613, json!([500000.0, 4399880.0, 500120.0, 4400000.0]));
+        let fields = &mut item.properties.additional_fields;
+        let _ = fields.remove("proj:epsg");
+        let _ = fields.insert("proj:code".to_string(), json!("EPSG:32613"));
+        assert!(item.bbox_wgs84().unwrap().is_some());
+    }
+
+    #[test]
+    fn existing_bbox() {
+        let mut item = with_proj(32613, json!([500000.0, 4399880.0, 500120.0, 4400000.0]));
+        item.bbox = Some(vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            item.bbox_wgs84().unwrap().unwrap(),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn missing_fields() {
+        assert!(Item::new("an-id").bbox_wgs84().unwrap().is_none());
+        let mut item = with_proj(32613, json!([500000.0, 4399880.0]));
+        assert!(item.bbox_wgs84().unwrap().is_none());
+        let _ = item.properties.additional_fields.remove("proj:bbox");
+        assert!(item.bbox_wgs84().unwrap().is_none());
+    }
+
+    #[test]
+    fn union_bbox_with_assets() {
+        use crate::Asset;
+
+        let mut item = Item::new("an-id");
+        let mut west = Asset::new("west.tif");
+        let _ = west.additional_fields.insert(
+            "proj:bbox".to_string(),
+            json!([500000.0, 4399880.0, 500120.0, 4400000.0]),
+        );
+        let _ = west
+            .additional_fields
+            .insert("proj:epsg".to_string(), json!(32613));
+        let mut east = Asset::new("east.tif");
+        let _ = east
+            .additional_fields
+            .insert("proj:bbox".to_string(), json!([-104.0, 39.0, -103.0, 40.0]));
+        let _ = east
+            .additional_fields
+            .insert("proj:code".to_string(), json!("EPSG:4326"));
+        let _ = item.assets.insert("west".to_string(), west);
+        let _ = item.assets.insert("east".to_string(), east);
+        let _ = item
+            .assets
+            .insert("thumbnail".to_string(), Asset::new("thumbnail.png"));
+        let bbox = item.union_bbox_with_assets().unwrap().unwrap();
+        assert!((bbox[0] - -105.0).abs() < 1e-3);
+        assert!((bbox[1] - 39.0).abs() < 1e-9);
+        assert!((bbox[2] - -103.0).abs() < 1e-9);
+        assert!((bbox[3] - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn union_bbox_with_assets_fallback() {
+        use crate::Geometry;
+
+        let mut item = Item::new("an-id");
+        assert!(item.union_bbox_with_assets().unwrap().is_none());
+        item.geometry = Some(Geometry::new(geojson::Value::Point(vec![1.0, 2.0])));
+        assert_eq!(
+            item.union_bbox_with_assets().unwrap().unwrap(),
+            vec![1.0, 2.0, 1.0, 2.0]
+        );
+        let item = with_proj(32613, json!([500000.0, 4399880.0, 500120.0, 4400000.0]));
+        assert_eq!(
+            item.union_bbox_with_assets().unwrap(),
+            item.bbox_wgs84().unwrap()
+        );
+    }
+
+    #[test]
+    fn unsupported_epsg() {
+        let item = with_proj(999999, json!([0.0, 0.0, 1.0, 1.0]));
+        assert!(matches!(
+            item.bbox_wgs84().unwrap_err(),
+            Error::UnsupportedEpsg(999999)
+        ));
+        let item = with_proj(1, json!([0.0, 0.0, 1.0, 1.0]));
+        assert!(matches!(
+            item.bbox_wgs84().unwrap_err(),
+            Error::UnsupportedEpsg(1)
+        ));
+    }
+}
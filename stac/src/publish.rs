@@ -0,0 +1,315 @@
+use crate::{
+    href::make_relative,
+    link::{is_absolute, make_absolute, PARENT_REL},
+    Href, Link, Links, Result, Value,
+};
+use std::collections::BTreeSet;
+use url::Url;
+
+/// What was changed by [Value::clean_for_publish].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PublishSummary {
+    /// The rel types of links that were added, changed, or removed.
+    pub links: Vec<String>,
+
+    /// The keys of assets whose hrefs were changed.
+    pub assets: Vec<String>,
+
+    /// The additional fields that were removed.
+    pub removed_fields: Vec<String>,
+}
+
+impl Value {
+    /// Prepares this object to be published at `self_href`, in a tree rooted at `root_href`.
+    ///
+    /// This is meant to be the last step of building a catalog:
+    ///
+    /// - The self link is set to `self_href`, and the root link to `root_href`.
+    /// - The parent link is removed if this object is the root. Otherwise, an
+    ///   existing parent link is published like any other link, but a missing
+    ///   one is left missing: the parent of a nested object isn't necessarily
+    ///   the root, so add the parent link before publishing.
+    /// - Link and asset hrefs are resolved against this object's current href
+    ///   (or `self_href`, if it doesn't have one). Hrefs under the root's
+    ///   directory are made relative to `self_href`, and all others are made
+    ///   absolute.
+    /// - Top-level and item property fields named in `remove_fields` are removed.
+    ///
+    /// Relative `self_href` and `root_href` paths are resolved against the
+    /// current directory. This object's href is set to `self_href`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Asset, Item, Links, Value};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.assets.insert("data".to_string(), Asset::new("/stac/an-id/data.tif"));
+    /// let mut value = Value::Item(item);
+    /// let summary = value
+    ///     .clean_for_publish("/stac/an-id/an-id.json", "/stac/catalog.json", &[])
+    ///     .unwrap();
+    /// assert_eq!(summary.assets, vec!["data"]);
+    /// let item = value.as_item().unwrap();
+    /// assert_eq!(item.assets["data"].href, "./data.tif");
+    /// assert_eq!(item.root_link().unwrap().href, "../catalog.json");
+    /// ```
+    pub fn clean_for_publish(
+        &mut self,
+        self_href: &str,
+        root_href: &str,
+        remove_fields: &[&str],
+    ) -> Result<PublishSummary> {
+        let self_href = absolute(self_href)?;
+        let root_href = absolute(root_href)?;
+        let base = match self.href() {
+            Some(href) => absolute(href)?,
+            None => self_href.clone(),
+        };
+        let root_directory = &root_href[..root_href.rfind('/').map(|i| i + 1).unwrap_or(0)];
+        let publish_href = |href: &str| -> Result<String> {
+            let href = make_absolute(href.to_string(), Some(&base))?;
+            if href.starts_with(root_directory) {
+                Ok(relative_href(&self_href, &href))
+            } else {
+                Ok(href)
+            }
+        };
+
+        let mut summary = PublishSummary::default();
+        let before = self.links().to_vec();
+        for link in self.links_mut() {
+            if !(link.is_self() || link.is_root()) {
+                link.href = publish_href(&link.href)?;
+            }
+        }
+        self.set_self_href(&self_href);
+        self.set_root_href(relative_href(&self_href, &root_href));
+        if self_href == root_href {
+            self.links_mut().retain(|link| link.rel != PARENT_REL);
+        }
+        let rels: BTreeSet<_> = before.iter().chain(self.links()).map(|l| &l.rel).collect();
+        for rel in rels {
+            let hrefs = |links: &[Link]| {
+                links
+                    .iter()
+                    .filter(|link| &link.rel == rel)
+                    .map(|link| link.href.clone())
+                    .collect::<Vec<_>>()
+            };
+            if hrefs(&before) != hrefs(self.links()) {
+                summary.links.push(rel.clone());
+            }
+        }
+
+        if let Some(assets) = self.assets_mut() {
+            for (key, asset) in assets.iter_mut() {
+                let href = publish_href(&asset.href)?;
+                if href != asset.href {
+                    asset.href = href;
+                    summary.assets.push(key.clone());
+                }
+            }
+            summary.assets.sort();
+        }
+
+        let mut removed = BTreeSet::new();
+        for fields in fields_mut(self) {
+            for &field in remove_fields {
+                if fields.remove(field).is_some() {
+                    let _ = removed.insert(field.to_string());
+                }
+            }
+        }
+        summary.removed_fields = removed.into_iter().collect();
+
+        self.set_href(&self_href);
+        Ok(summary)
+    }
+}
+
+fn absolute(href: &str) -> Result<String> {
+    if is_absolute(href) {
+        Ok(href.to_string())
+    } else {
+        Ok(std::env::current_dir()?
+            .join(href)
+            .to_string_lossy()
+            .into_owned())
+    }
+}
+
+/// Makes an absolute href relative to the absolute href of the object that will hold it.
+///
+/// Paths stay paths and urls stay urls; an href that can't be made relative
+/// (e.g. a url relative to a path) is returned unchanged.
+fn relative_href(from: &str, to: &str) -> String {
+    let comparable = match (Url::parse(from), Url::parse(to)) {
+        (Ok(_), Ok(_)) => true,
+        (Err(_), Err(_)) => from.starts_with('/') && to.starts_with('/'),
+        _ => false,
+    };
+    comparable
+        .then(|| make_relative(from, to))
+        .flatten()
+        .unwrap_or_else(|| to.to_string())
+}
+
+fn fields_mut(value: &mut Value) -> Vec<&mut serde_json::Map<String, serde_json::Value>> {
+    match value {
+        Value::Item(item) => vec![
+            &mut item.additional_fields,
+            &mut item.properties.additional_fields,
+        ],
+        Value::Catalog(catalog) => vec![&mut catalog.additional_fields],
+        Value::Collection(collection) => vec![&mut collection.additional_fields],
+        Value::ItemCollection(item_collection) => vec![&mut item_collection.additional_fields],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Asset, Href, Item, Link, Links, Value};
+
+    fn item() -> Item {
+        let mut item = Item::new("an-id");
+        let _ = item
+            .assets
+            .insert("data".to_string(), Asset::new("./data.tif"));
+        let _ = item
+            .assets
+            .insert("other".to_string(), Asset::new("/stac/other/data.tif"));
+        let _ = item.assets.insert(
+            "remote".to_string(),
+            Asset::new("http://stac-rs.test/data.tif"),
+        );
+        let _ = item
+            .properties
+            .additional_fields
+            .insert("internal:key".to_string(), "value".into());
+        item
+    }
+
+    #[test]
+    fn relative_href() {
+        assert_eq!(
+            super::relative_href("/a/catalog.json", "/a/b/item.json"),
+            "./b/item.json"
+        );
+        assert_eq!(
+            super::relative_href("/a/b/item.json", "/a/catalog.json"),
+            "../catalog.json"
+        );
+        assert_eq!(
+            super::relative_href("/a/b/item.json", "/a/c/data.tif"),
+            "../c/data.tif"
+        );
+        assert_eq!(
+            super::relative_href(
+                "http://stac-rs.test/a/b/item.json",
+                "http://stac-rs.test/a/catalog.json"
+            ),
+            "../catalog.json"
+        );
+        assert_eq!(
+            super::relative_href("/a/item.json", "http://stac-rs.test/a/data.tif"),
+            "http://stac-rs.test/a/data.tif"
+        );
+    }
+
+    #[test]
+    fn item_without_href() {
+        let mut value = Value::Item(item());
+        let summary = value
+            .clean_for_publish(
+                "/stac/an-id/an-id.json",
+                "/stac/catalog.json",
+                &["internal:key"],
+            )
+            .unwrap();
+        assert_eq!(summary.links, vec!["root", "self"]);
+        assert_eq!(summary.assets, vec!["other"]);
+        assert_eq!(summary.removed_fields, vec!["internal:key"]);
+        assert_eq!(value.href().unwrap(), "/stac/an-id/an-id.json");
+        let item = value.as_item().unwrap();
+        assert_eq!(item.self_link().unwrap().href, "/stac/an-id/an-id.json");
+        assert_eq!(item.root_link().unwrap().href, "../catalog.json");
+        assert!(item.parent_link().is_none());
+        assert_eq!(item.assets["data"].href, "./data.tif");
+        assert_eq!(item.assets["other"].href, "../other/data.tif");
+        assert_eq!(item.assets["remote"].href, "http://stac-rs.test/data.tif");
+        assert!(item.properties.additional_fields.is_empty());
+    }
+
+    #[test]
+    fn item_with_href() {
+        let mut item = item();
+        item.set_href("/build/an-id/an-id.json");
+        item.links.push(Link::collection("../collection.json"));
+        let mut value = Value::Item(item);
+        let summary = value
+            .clean_for_publish("/stac/an-id/an-id.json", "/stac/catalog.json", &[])
+            .unwrap();
+        assert!(summary.links.contains(&"collection".to_string()));
+        assert_eq!(summary.assets, vec!["data", "other"]);
+        let item = value.as_item().unwrap();
+        assert_eq!(item.assets["data"].href, "/build/an-id/data.tif");
+        assert_eq!(
+            item.link("collection").unwrap().href,
+            "/build/collection.json"
+        );
+    }
+
+    #[test]
+    fn nested_parent() {
+        let mut item = item();
+        item.links
+            .push(Link::parent("/stac/subcatalog/catalog.json"));
+        let mut value = Value::Item(item);
+        let summary = value
+            .clean_for_publish(
+                "/stac/subcatalog/an-id/an-id.json",
+                "/stac/catalog.json",
+                &[],
+            )
+            .unwrap();
+        assert_eq!(summary.links, vec!["parent", "root", "self"]);
+        let item = value.as_item().unwrap();
+        assert_eq!(item.parent_link().unwrap().href, "../catalog.json");
+        assert_eq!(item.root_link().unwrap().href, "../../catalog.json");
+    }
+
+    #[test]
+    fn root() {
+        let mut catalog = crate::Catalog::new("an-id", "a description");
+        catalog.links.push(Link::parent("./catalog.json"));
+        catalog.links.push(Link::child("./an-id/an-id.json"));
+        let mut value = Value::Catalog(catalog);
+        let summary = value
+            .clean_for_publish("/stac/catalog.json", "/stac/catalog.json", &[])
+            .unwrap();
+        assert_eq!(summary.links, vec!["parent", "root", "self"]);
+        let catalog = value.as_catalog().unwrap();
+        assert!(catalog.parent_link().is_none());
+        assert_eq!(catalog.root_link().unwrap().href, "./catalog.json");
+        assert_eq!(
+            catalog.iter_child_links().next().unwrap().href,
+            "./an-id/an-id.json"
+        );
+    }
+
+    #[test]
+    fn urls() {
+        let mut value = Value::Item(item());
+        let _ = value
+            .clean_for_publish(
+                "http://stac-rs.test/stac/an-id/an-id.json",
+                "http://stac-rs.test/stac/catalog.json",
+                &[],
+            )
+            .unwrap();
+        let item = value.as_item().unwrap();
+        assert_eq!(item.root_link().unwrap().href, "../catalog.json");
+        assert_eq!(item.assets["data"].href, "./data.tif");
+        assert_eq!(item.assets["remote"].href, "http://stac-rs.test/data.tif");
+    }
+}
@@ -0,0 +1,56 @@
+//! Asset [roles](https://github.com/radiantearth/stac-spec/blob/master/best-practices.md#asset-roles)
+//! describe the purpose of each asset.
+//!
+//! An asset can have any number of roles, and roles are free-form strings, but
+//! the following are the common roles documented by the STAC best practices.
+
+/// The data itself, e.g. a GeoTIFF with the full-resolution image.
+pub const DATA: &str = "data";
+
+/// A metadata sidecar file describing the data in this item, e.g. an XML file.
+pub const METADATA: &str = "metadata";
+
+/// A small image representing the item, e.g. for display in a list.
+pub const THUMBNAIL: &str = "thumbnail";
+
+/// A medium-sized image of the data, e.g. for display on a map.
+pub const OVERVIEW: &str = "overview";
+
+/// An asset that is a full-resolution version of the data, processed for visual use (RGB only).
+pub const VISUAL: &str = "visual";
+
+/// An asset that provides per-pixel acquisition timestamps.
+pub const DATE: &str = "date";
+
+/// An asset that's a graphic, e.g. for display in a report or a web page.
+pub const GRAPHIC: &str = "graphic";
+
+/// A file indicating which pixels have data and which don't.
+pub const DATA_MASK: &str = "data-mask";
+
+/// Points to a file that indicates whether a pixel is assessed as being snow/ice or not.
+pub const SNOW_ICE: &str = "snow-ice";
+
+/// Points to a file that indicates whether a pixel is assessed as being land or water.
+pub const LAND_WATER: &str = "land-water";
+
+/// Points to a file that indicates whether a pixel is assessed as being water (e.g. flooding map).
+pub const WATER_MASK: &str = "water-mask";
+
+/// Points to an [ISO 19115](https://www.iso.org/standard/53798.html) metadata file.
+pub const ISO_19115: &str = "iso-19115";
+
+/// An asset with reflectance values, e.g. top-of-atmosphere or surface reflectance.
+pub const REFLECTANCE: &str = "reflectance";
+
+/// An asset with temperature values.
+pub const TEMPERATURE: &str = "temperature";
+
+/// Points to a file that indicates where the sensor was saturated.
+pub const SATURATION: &str = "saturation";
+
+/// Points to a file that indicates whether a pixel is assessed as being cloud or not.
+pub const CLOUD: &str = "cloud";
+
+/// Points to a file that indicates whether a pixel is assessed as being cloud shadow or not.
+pub const CLOUD_SHADOW: &str = "cloud-shadow";
@@ -0,0 +1,334 @@
+use crate::{Error, Item, Link, Result};
+use serde_json::{Map, Value};
+use std::io::{BufRead, BufReader, Read};
+
+/// Streams the items of an [ItemCollection](crate::ItemCollection) from a reader.
+///
+/// Items are parsed one at a time from the `features` array, so memory use
+/// doesn't grow with the number of items. The other top-level fields of the
+/// item collection, including its links, are available from the returned
+/// [ItemStream] once the stream is exhausted.
+///
+/// # Examples
+///
+/// ```
+/// let file = std::fs::File::open("examples/item-collection.json").unwrap();
+/// let mut stream = stac::stream_items(file);
+/// let items = stream.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(items.len(), 30);
+/// assert_eq!(stream.additional_fields()["type"], "FeatureCollection");
+/// ```
+pub fn stream_items<R: Read>(reader: R) -> ItemStream<R> {
+    ItemStream {
+        reader: BufReader::new(reader),
+        state: State::Start,
+        links: Vec::new(),
+        additional_fields: Map::new(),
+    }
+}
+
+/// An iterator over the items of a streamed [ItemCollection](crate::ItemCollection).
+///
+/// Created by [stream_items].
+#[derive(Debug)]
+pub struct ItemStream<R> {
+    reader: BufReader<R>,
+    state: State,
+    links: Vec<Link>,
+    additional_fields: Map<String, Value>,
+}
+
+#[derive(Debug, PartialEq)]
+enum State {
+    Start,
+    FirstFeature,
+    Feature,
+    Done,
+}
+
+impl<R: Read> ItemStream<R> {
+    /// Returns the item collection's links.
+    ///
+    /// Links that come after the `features` array are only available once the
+    /// stream is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut stream = stac::stream_items(r#"{"type":"FeatureCollection","features":[],"links":[{"href":"./next.json","rel":"next"}]}"#.as_bytes());
+    /// assert!(stream.next().is_none());
+    /// assert_eq!(stream.links()[0].rel, "next");
+    /// ```
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+
+    /// Returns the item collection's top-level fields, other than `features` and `links`.
+    ///
+    /// Fields that come after the `features` array are only available once the
+    /// stream is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut stream = stac::stream_items(r#"{"type":"FeatureCollection","features":[],"numberMatched":0}"#.as_bytes());
+    /// assert!(stream.next().is_none());
+    /// assert_eq!(stream.additional_fields()["numberMatched"], 0);
+    /// ```
+    pub fn additional_fields(&self) -> &Map<String, Value> {
+        &self.additional_fields
+    }
+
+    /// Returns true if the whole item collection has been read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut stream = stac::stream_items(r#"{"type":"FeatureCollection","features":[]}"#.as_bytes());
+    /// assert!(!stream.is_finished());
+    /// assert!(stream.next().is_none());
+    /// assert!(stream.is_finished());
+    /// ```
+    pub fn is_finished(&self) -> bool {
+        self.state == State::Done
+    }
+
+    fn next_item(&mut self) -> Result<Option<Item>> {
+        loop {
+            match self.state {
+                State::Start => {
+                    self.expect(b'{')?;
+                    if self.peek_non_whitespace()? == Some(b'}') {
+                        return Err(Error::InvalidItemStream("no features array".to_string()));
+                    }
+                    self.read_fields_until_features()?;
+                }
+                State::FirstFeature | State::Feature => {
+                    match self.peek_non_whitespace()? {
+                        Some(b']') => {
+                            self.consume();
+                            self.read_fields_after_features()?;
+                            self.state = State::Done;
+                            return Ok(None);
+                        }
+                        Some(b',') if self.state == State::Feature => self.consume(),
+                        _ if self.state == State::FirstFeature => {}
+                        _ => return Err(unexpected(self.peek_non_whitespace()?)),
+                    }
+                    self.state = State::Feature;
+                    let bytes = self.read_raw_value()?;
+                    return serde_json::from_slice(&bytes)
+                        .map(Some)
+                        .map_err(Error::from);
+                }
+                State::Done => return Ok(None),
+            }
+        }
+    }
+
+    fn read_fields_until_features(&mut self) -> Result<()> {
+        loop {
+            let key = self.read_key()?;
+            if key == "features" {
+                self.expect(b'[')?;
+                self.state = State::FirstFeature;
+                return Ok(());
+            }
+            self.read_field(key)?;
+            match self.next_non_whitespace()? {
+                Some(b',') => {}
+                Some(b'}') => {
+                    return Err(Error::InvalidItemStream("no features array".to_string()))
+                }
+                byte => return Err(unexpected(byte)),
+            }
+        }
+    }
+
+    fn read_fields_after_features(&mut self) -> Result<()> {
+        loop {
+            match self.next_non_whitespace()? {
+                Some(b',') => {
+                    let key = self.read_key()?;
+                    self.read_field(key)?;
+                }
+                Some(b'}') => return Ok(()),
+                byte => return Err(unexpected(byte)),
+            }
+        }
+    }
+
+    fn read_key(&mut self) -> Result<String> {
+        if self.peek_non_whitespace()? != Some(b'"') {
+            return Err(unexpected(self.peek_non_whitespace()?));
+        }
+        let bytes = self.read_raw_value()?;
+        let key = serde_json::from_slice(&bytes)?;
+        self.expect(b':')?;
+        Ok(key)
+    }
+
+    fn read_field(&mut self, key: String) -> Result<()> {
+        let bytes = self.read_raw_value()?;
+        if key == "links" {
+            self.links = serde_json::from_slice(&bytes)?;
+        } else {
+            let _ = self
+                .additional_fields
+                .insert(key, serde_json::from_slice(&bytes)?);
+        }
+        Ok(())
+    }
+
+    /// Reads the bytes of one JSON value, without parsing it.
+    fn read_raw_value(&mut self) -> Result<Vec<u8>> {
+        let _ = self.peek_non_whitespace()?;
+        let mut bytes = Vec::new();
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        while let Some(byte) = self.peek()? {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                }
+            } else {
+                match byte {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' if depth == 0 => break,
+                    b'}' | b']' => depth -= 1,
+                    b',' if depth == 0 => break,
+                    _ if depth == 0 && byte.is_ascii_whitespace() && !bytes.is_empty() => break,
+                    _ => {}
+                }
+            }
+            bytes.push(byte);
+            self.consume();
+            if depth == 0 && !in_string && matches!(byte, b'}' | b']' | b'"') {
+                break;
+            }
+        }
+        if bytes.is_empty() || in_string || depth > 0 {
+            Err(Error::InvalidItemStream(
+                "unexpected end of input".to_string(),
+            ))
+        } else {
+            Ok(bytes)
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        match self.next_non_whitespace()? {
+            Some(byte) if byte == expected => Ok(()),
+            byte => Err(unexpected(byte)),
+        }
+    }
+
+    fn next_non_whitespace(&mut self) -> Result<Option<u8>> {
+        let byte = self.peek_non_whitespace()?;
+        self.consume();
+        Ok(byte)
+    }
+
+    fn peek_non_whitespace(&mut self) -> Result<Option<u8>> {
+        while let Some(byte) = self.peek()? {
+            if byte.is_ascii_whitespace() {
+                self.consume();
+            } else {
+                return Ok(Some(byte));
+            }
+        }
+        Ok(None)
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn consume(&mut self) {
+        self.reader.consume(1);
+    }
+}
+
+impl<R: Read> Iterator for ItemStream<R> {
+    type Item = Result<Item>;
+
+    fn next(&mut self) -> Option<Result<Item>> {
+        match self.next_item() {
+            Ok(item) => item.map(Ok),
+            Err(err) => {
+                self.state = State::Done;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+fn unexpected(byte: Option<u8>) -> Error {
+    if let Some(byte) = byte {
+        Error::InvalidItemStream(format!("unexpected character: {}", char::from(byte)))
+    } else {
+        Error::InvalidItemStream("unexpected end of input".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stream_items;
+    use crate::{Item, ItemCollection};
+    use std::fs::File;
+
+    #[test]
+    fn stream() {
+        let item_collection: ItemCollection = crate::read("examples/item-collection.json").unwrap();
+        let mut stream = stream_items(File::open("examples/item-collection.json").unwrap());
+        let items: Vec<Item> = stream.by_ref().collect::<Result<_, _>>().unwrap();
+        assert!(stream.is_finished());
+        assert_eq!(items, item_collection.items);
+        assert_eq!(stream.links(), item_collection.links.as_slice());
+    }
+
+    #[test]
+    fn fields_around_features() {
+        let json = r#" {
+            "type": "FeatureCollection",
+            "context": {"returned": 1, "note": "a ] tricky, \"string\" }"},
+            "features": [ {"type": "Feature", "stac_version": "1.0.0", "id": "an-id", "geometry": null, "properties": {"datetime": null}, "links": [], "assets": {}} ],
+            "numberMatched": 1,
+            "links": [{"href": "./next.json", "rel": "next"}]
+        } "#;
+        let mut stream = stream_items(json.as_bytes());
+        let item = stream.next().unwrap().unwrap();
+        assert_eq!(item.id, "an-id");
+        assert_eq!(stream.additional_fields()["context"]["returned"], 1);
+        assert!(stream.links().is_empty());
+        assert!(stream.next().is_none());
+        assert_eq!(stream.additional_fields()["numberMatched"], 1);
+        assert_eq!(stream.links()[0].href, "./next.json");
+    }
+
+    #[test]
+    fn no_features() {
+        let mut stream = stream_items(r#"{"type": "FeatureCollection"}"#.as_bytes());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn truncated() {
+        let mut stream = stream_items(r#"{"features": [{"type": "Feat"#.as_bytes());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn invalid_item() {
+        let mut stream = stream_items(r#"{"features": [42]}"#.as_bytes());
+        assert!(stream.next().unwrap().is_err());
+    }
+}
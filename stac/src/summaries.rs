@@ -0,0 +1,162 @@
+use crate::{Collection, Extensions, Item, LATEST_EXTENSIONS};
+use serde_json::{json, Map, Value};
+
+/// Numeric item fields from extensions that are summarized as ranges, and the prefix of their extension's schema URI.
+const NUMERIC_EXTENSION_FIELDS: [(&str, &str); 9] = [
+    ("eo:cloud_cover", "https://stac-extensions.github.io/eo/"),
+    ("eo:snow_cover", "https://stac-extensions.github.io/eo/"),
+    (
+        "sat:absolute_orbit",
+        "https://stac-extensions.github.io/sat/",
+    ),
+    (
+        "sat:relative_orbit",
+        "https://stac-extensions.github.io/sat/",
+    ),
+    ("view:azimuth", "https://stac-extensions.github.io/view/"),
+    (
+        "view:incidence_angle",
+        "https://stac-extensions.github.io/view/",
+    ),
+    ("view:off_nadir", "https://stac-extensions.github.io/view/"),
+    (
+        "view:sun_azimuth",
+        "https://stac-extensions.github.io/view/",
+    ),
+    (
+        "view:sun_elevation",
+        "https://stac-extensions.github.io/view/",
+    ),
+];
+
+impl Collection {
+    /// Summarizes known numeric extension fields of items as ranges.
+    ///
+    /// Fields like `eo:cloud_cover` and `view:off_nadir` are summarized as
+    /// `{"minimum": ..., "maximum": ...}`, widening any existing range summary
+    /// for the field. For every summarized field, the extension's schema URI
+    /// is added to this collection's `stac_extensions`, unless some version of
+    /// that extension is already there. Other fields aren't summarized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Collection, Item};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert("eo:cloud_cover".to_string(), 42.into());
+    /// let mut collection = Collection::new("an-id", "a description");
+    /// collection.summarize_items(&[item]);
+    /// let summaries = collection.summaries.unwrap();
+    /// assert_eq!(summaries["eo:cloud_cover"]["minimum"], 42.0);
+    /// assert!(collection.extensions.unwrap()[0].starts_with("https://stac-extensions.github.io/eo/"));
+    /// ```
+    pub fn summarize_items(&mut self, items: &[Item]) {
+        for (field, prefix) in NUMERIC_EXTENSION_FIELDS {
+            let mut range: Option<(f64, f64)> = None;
+            for value in items
+                .iter()
+                .filter_map(|item| item.properties.additional_fields.get(field))
+                .filter_map(Value::as_f64)
+            {
+                range = Some(match range {
+                    Some((minimum, maximum)) => (minimum.min(value), maximum.max(value)),
+                    None => (value, value),
+                });
+            }
+            if let Some(range) = range {
+                let summaries = self.summaries.get_or_insert_with(Map::new);
+                let range = match summaries.get(field).and_then(existing_range) {
+                    Some((minimum, maximum)) => (minimum.min(range.0), maximum.max(range.1)),
+                    None => range,
+                };
+                let _ = summaries.insert(
+                    field.to_string(),
+                    json!({"minimum": range.0, "maximum": range.1}),
+                );
+                self.add_extension_with_prefix(prefix);
+            }
+        }
+    }
+
+    fn add_extension_with_prefix(&mut self, prefix: &str) {
+        let has_extension = self
+            .extensions()
+            .map(|extensions| extensions.iter().any(|uri| uri.starts_with(prefix)))
+            .unwrap_or(false);
+        if !has_extension {
+            if let Some((_, uri)) = LATEST_EXTENSIONS.iter().find(|(p, _)| *p == prefix) {
+                self.add_extension(uri);
+            }
+        }
+    }
+}
+
+fn existing_range(value: &Value) -> Option<(f64, f64)> {
+    Some((
+        value.get("minimum")?.as_f64()?,
+        value.get("maximum")?.as_f64()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Collection, Item};
+    use serde_json::json;
+
+    fn item(cloud_cover: f64, off_nadir: Option<f64>) -> Item {
+        let mut item = Item::new("an-id");
+        let fields = &mut item.properties.additional_fields;
+        let _ = fields.insert("eo:cloud_cover".to_string(), cloud_cover.into());
+        if let Some(off_nadir) = off_nadir {
+            let _ = fields.insert("view:off_nadir".to_string(), off_nadir.into());
+        }
+        let _ = fields.insert("platform".to_string(), "a-platform".into());
+        item
+    }
+
+    #[test]
+    fn summarize_items() {
+        let mut collection = Collection::new("an-id", "a description");
+        collection.summarize_items(&[item(10.0, Some(1.5)), item(50.0, None), item(5.0, None)]);
+        let summaries = collection.summaries.as_ref().unwrap();
+        assert_eq!(
+            summaries["eo:cloud_cover"],
+            json!({"minimum": 5.0, "maximum": 50.0})
+        );
+        assert_eq!(
+            summaries["view:off_nadir"],
+            json!({"minimum": 1.5, "maximum": 1.5})
+        );
+        assert!(!summaries.contains_key("platform"));
+        assert_eq!(
+            collection.extensions.unwrap(),
+            vec![
+                "https://stac-extensions.github.io/eo/v1.1.0/schema.json",
+                "https://stac-extensions.github.io/view/v1.0.0/schema.json"
+            ]
+        );
+    }
+
+    #[test]
+    fn widen_existing_summary() {
+        let mut collection = Collection::new("an-id", "a description");
+        collection.extensions = Some(vec![
+            "https://stac-extensions.github.io/eo/v1.0.0/schema.json".to_string(),
+        ]);
+        collection.summarize_items(&[item(10.0, None)]);
+        collection.summarize_items(&[item(20.0, None)]);
+        assert_eq!(
+            collection.summaries.unwrap()["eo:cloud_cover"],
+            json!({"minimum": 10.0, "maximum": 20.0})
+        );
+        assert_eq!(collection.extensions.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn no_items() {
+        let mut collection = Collection::new("an-id", "a description");
+        collection.summarize_items(&[]);
+        assert!(collection.summaries.is_none());
+        assert!(collection.extensions.is_none());
+    }
+}
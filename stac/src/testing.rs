@@ -0,0 +1,199 @@
+//! Utilities for testing code that uses STAC objects.
+//!
+//! Requires the `testing` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use stac::{testing, Link};
+//! let mut catalog = testing::catalog("an-id");
+//! let other = catalog.clone();
+//! catalog.links.push(Link::child("./child.json"));
+//! testing::assert_semantic_eq(&catalog, &other);
+//! ```
+
+use crate::{Catalog, Collection, Item, ItemCollection, Link};
+use serde::Serialize;
+use std::fmt::Debug;
+
+/// The datetime used for fixture items.
+pub const DATETIME: &str = "2023-01-01T00:00:00Z";
+
+/// The bounding box used for fixture items and collections.
+pub const BBOX: [f64; 4] = [-105.1, 40.1, -105.0, 40.2];
+
+/// Returns true if two STAC objects are equal, ignoring their links.
+///
+/// The objects are compared by their serialized JSON, so hrefs (which aren't
+/// serialized) are also ignored. Objects that can't be serialized are never
+/// equal.
+///
+/// # Examples
+///
+/// ```
+/// use stac::{testing, Link};
+/// let mut item = testing::item("an-id");
+/// let other = item.clone();
+/// item.links.push(Link::root("./catalog.json"));
+/// assert!(testing::semantic_eq(&item, &other));
+/// ```
+pub fn semantic_eq<T: Serialize>(a: &T, b: &T) -> bool {
+    match (without_links(a), without_links(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Asserts that two STAC objects are equal, ignoring their links.
+///
+/// # Panics
+///
+/// Panics if the objects are not equal, as in [semantic_eq].
+///
+/// # Examples
+///
+/// ```
+/// use stac::{testing, Link};
+/// let mut item = testing::item("an-id");
+/// let other = item.clone();
+/// item.links.push(Link::root("./catalog.json"));
+/// testing::assert_semantic_eq(&item, &other);
+/// ```
+#[track_caller]
+pub fn assert_semantic_eq<T: Serialize + Debug>(a: &T, b: &T) {
+    if !semantic_eq(a, b) {
+        panic!(
+            "assertion failed: semantic equality (ignoring links)\n  left: {:?}\n right: {:?}",
+            a, b
+        );
+    }
+}
+
+/// Creates a fixture [Item] with a fixed datetime, a point geometry, and a bbox.
+///
+/// # Examples
+///
+/// ```
+/// let item = stac::testing::item("an-id");
+/// assert_eq!(item.properties.datetime.as_deref(), Some(stac::testing::DATETIME));
+/// ```
+pub fn item(id: impl ToString) -> Item {
+    let mut item = Item::new(id);
+    item.properties.datetime = Some(DATETIME.to_string());
+    item.geometry = Some(geojson::Geometry::new(geojson::Value::Point(vec![
+        (BBOX[0] + BBOX[2]) / 2.,
+        (BBOX[1] + BBOX[3]) / 2.,
+    ])));
+    item.bbox = Some(BBOX.to_vec());
+    item
+}
+
+/// Creates a fixture [Catalog].
+///
+/// # Examples
+///
+/// ```
+/// let catalog = stac::testing::catalog("an-id");
+/// assert_eq!(catalog.id, "an-id");
+/// ```
+pub fn catalog(id: impl ToString) -> Catalog {
+    let id = id.to_string();
+    Catalog::new(&id, format!("A fixture catalog with id {}", id))
+}
+
+/// Creates a fixture [Collection] with a fixed spatial and temporal extent.
+///
+/// # Examples
+///
+/// ```
+/// let collection = stac::testing::collection("an-id");
+/// assert_eq!(collection.extent.spatial.bbox[0], stac::testing::BBOX);
+/// ```
+pub fn collection(id: impl ToString) -> Collection {
+    let id = id.to_string();
+    let mut collection = Collection::new(&id, format!("A fixture collection with id {}", id));
+    collection.extent.spatial.bbox = vec![BBOX.to_vec()];
+    collection.extent.temporal.interval = vec![[Some(DATETIME.to_string()), None]];
+    collection
+}
+
+/// Creates a fixture [Collection] with `count` fixture items, linked to each other.
+///
+/// Items are named `item-0`, `item-1`, and so on. The collection links to
+/// each item at e.g. `./item-0/item-0.json`, and each item links back to the
+/// collection at `../collection.json`.
+///
+/// # Examples
+///
+/// ```
+/// let (collection, items) = stac::testing::collection_with_items("an-id", 2);
+/// assert_eq!(items.items.len(), 2);
+/// assert_eq!(items.items[0].collection.as_deref(), Some("an-id"));
+/// ```
+pub fn collection_with_items(id: impl ToString, count: usize) -> (Collection, ItemCollection) {
+    let mut collection = collection(id);
+    let items = (0..count)
+        .map(|i| {
+            let item_id = format!("item-{}", i);
+            collection
+                .links
+                .push(Link::item(format!("./{}/{}.json", item_id, item_id)));
+            let mut item = item(item_id).collection(&collection.id);
+            item.links.push(Link::collection("../collection.json"));
+            item
+        })
+        .collect();
+    (collection, items)
+}
+
+fn without_links<T: Serialize>(value: &T) -> Option<serde_json::Value> {
+    let mut value = serde_json::to_value(value).ok()?;
+    if let Some(object) = value.as_object_mut() {
+        let _ = object.remove("links");
+        if let Some(features) = object.get_mut("features").and_then(|f| f.as_array_mut()) {
+            for feature in features {
+                if let Some(feature) = feature.as_object_mut() {
+                    let _ = feature.remove("links");
+                }
+            }
+        }
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Link;
+
+    #[test]
+    fn semantic_eq_ignores_links() {
+        let mut collection = super::collection("an-id");
+        let other = collection.clone();
+        collection.links.push(Link::root("./catalog.json"));
+        assert!(super::semantic_eq(&collection, &other));
+        collection.title = Some("a title".to_string());
+        assert!(!super::semantic_eq(&collection, &other));
+    }
+
+    #[test]
+    fn semantic_eq_unserializable() {
+        use std::collections::HashMap;
+
+        let value: HashMap<(u8, u8), u8> = [((1, 2), 3)].into_iter().collect();
+        assert!(!super::semantic_eq(&value, &value));
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_semantic_eq_panics() {
+        super::assert_semantic_eq(&super::item("a"), &super::item("b"));
+    }
+
+    #[test]
+    fn collection_with_items() {
+        let (collection, items) = super::collection_with_items("an-id", 3);
+        assert_eq!(collection.links.len(), 3);
+        assert_eq!(items.items.len(), 3);
+        assert_eq!(items.items[2].id, "item-2");
+    }
+}
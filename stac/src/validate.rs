@@ -23,6 +23,17 @@
 //! }
 //! ```
 //!
+//! To keep warnings (e.g. deprecated objects) separate from hard errors, use [Validate::validate_with_report]:
+//!
+//! ```
+//! use stac::Validate;
+//! let report = stac::read::<stac::Item>("data/simple-item.json").unwrap().validate_with_report();
+//! for warning in &report.warnings {
+//!     println!("WARNING at {}: {}", warning.pointer, warning.message);
+//! }
+//! assert!(report.is_valid());
+//! ```
+//!
 //! If you're doing multiple validations, it is more efficient to use the [Validator] structure, which will cache any fetched schemas, including extension schemas:
 //!
 //! ```
@@ -33,21 +44,100 @@
 //! validator.validate_item(item).unwrap();
 //! validator.validate_catalog(catalog).unwrap();
 //! ```
+//!
+//! Extension schemas are usually a single document for every object type. If
+//! an extension URI has a fragment, e.g.
+//! `https://stac-extensions.github.io/an-extension/v1.0.0/schema.json#/definitions/item`,
+//! the document is fetched without the fragment, and objects are validated
+//! against only the sub-schema that the fragment points to.
 
-use crate::{Catalog, Collection, Error, Extensions, Item, ItemCollection, Value};
-use jsonschema::{JSONSchema, ValidationError};
+use crate::{
+    Catalog, Collection, Error, Extensions, Item, ItemCollection, Value, CATALOG_TYPE,
+    COLLECTION_TYPE, ITEM_COLLECTION_TYPE, ITEM_TYPE, STAC_VERSION,
+};
+use jsonschema::{paths::PathChunk, JSONSchema, ValidationError};
 use serde::Serialize;
-use std::{borrow::Cow, collections::HashMap};
+use serde_json::json;
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use url::Url;
+
+/// Top-level fields of an item from the core specification.
+const ITEM_FIELDS: [&str; 10] = [
+    "type",
+    "stac_version",
+    "stac_extensions",
+    "id",
+    "geometry",
+    "bbox",
+    "properties",
+    "links",
+    "assets",
+    "collection",
+];
+
+/// Top-level fields of a catalog from the core specification.
+const CATALOG_FIELDS: [&str; 7] = [
+    "type",
+    "stac_version",
+    "stac_extensions",
+    "id",
+    "title",
+    "description",
+    "links",
+];
+
+/// Top-level fields of a collection from the core specification.
+const COLLECTION_FIELDS: [&str; 13] = [
+    "type",
+    "stac_version",
+    "stac_extensions",
+    "id",
+    "title",
+    "description",
+    "keywords",
+    "license",
+    "providers",
+    "extent",
+    "summaries",
+    "links",
+    "assets",
+];
+
+/// Common metadata fields, which can be used in item properties and on catalogs and collections.
+const COMMON_METADATA_FIELDS: [&str; 15] = [
+    "datetime",
+    "start_datetime",
+    "end_datetime",
+    "created",
+    "updated",
+    "title",
+    "description",
+    "license",
+    "providers",
+    "platform",
+    "instruments",
+    "constellation",
+    "mission",
+    "gsd",
+    "deprecated",
+];
 
 /// A structure that performs json-schema validations.
 ///
 /// Includes pre-compiled schemas for all three STAC object types, as well as a cache for extension schemas.
-#[derive(Debug)]
+/// Cloning a validator is cheap, because the clone shares the compiled schemas.
+#[derive(Debug, Clone)]
 pub struct Validator {
-    item_schema: JSONSchema,
-    catalog_schema: JSONSchema,
-    collection_schema: JSONSchema,
-    extension_schemas: HashMap<String, JSONSchema>,
+    item_schema: Arc<JSONSchema>,
+    catalog_schema: Arc<JSONSchema>,
+    collection_schema: Arc<JSONSchema>,
+    extension_schemas: HashMap<String, Arc<JSONSchema>>,
+    extension_fields: HashMap<String, Arc<HashSet<String>>>,
+    strict: bool,
 }
 
 /// A trait to provide validation on STAC objects.
@@ -64,6 +154,78 @@ pub trait Validate {
     /// item.validate().unwrap(); // <- item is consumed
     /// ```
     fn validate(self) -> Result<(), Vec<Error>>;
+
+    /// Validate this STAC object using a one-time-use [Validator], returning
+    /// errors and warnings separately.
+    ///
+    /// Validation consumes the object. The default implementation collects
+    /// warnings from the object's JSON and turns the errors from
+    /// [Validate::validate] into issues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Validate, Item};
+    /// let item = Item::new("an-id");
+    /// let report = item.validate_with_report(); // <- item is consumed
+    /// assert!(report.is_valid());
+    /// ```
+    fn validate_with_report(self) -> ValidationReport
+    where
+        Self: Sized + Serialize,
+    {
+        let mut warnings = Vec::new();
+        match serde_json::to_value(&self) {
+            Ok(json) => collect_warnings(&json, "", &mut warnings),
+            Err(err) => {
+                return ValidationReport {
+                    errors: vec![Error::from(err).into()],
+                    warnings,
+                }
+            }
+        }
+        let errors = self
+            .validate()
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(ValidationIssue::from)
+            .collect();
+        ValidationReport { errors, warnings }
+    }
+}
+
+/// The outcome of a validation, with errors and warnings kept separate.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ValidationReport {
+    /// Problems that make the object invalid.
+    pub errors: Vec<ValidationIssue>,
+
+    /// Problems that don't make the object invalid, but should be looked at,
+    /// e.g. deprecated objects.
+    pub warnings: Vec<ValidationIssue>,
+}
+
+/// A single error or warning from a [ValidationReport].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// A [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901) to the
+    /// problematic part of the object.
+    ///
+    /// An empty pointer refers to the whole object.
+    pub pointer: String,
+
+    /// A JSON pointer to the part of the schema that failed, ending with the
+    /// failing keyword.
+    ///
+    /// This is empty if the issue didn't come from a schema, e.g. for warnings.
+    pub schema_path: String,
+
+    /// The failing schema keyword, e.g. `minLength` or `required`.
+    pub keyword: Option<String>,
+
+    /// A human-readable description of the problem.
+    pub message: String,
 }
 
 enum Schema {
@@ -87,9 +249,36 @@ impl Validator {
             catalog_schema: compile_schema(include_str!("../schemas/v1.0.0/catalog.json"))?,
             collection_schema: compile_schema(include_str!("../schemas/v1.0.0/collection.json"))?,
             extension_schemas: HashMap::new(),
+            extension_fields: HashMap::new(),
+            strict: false,
         })
     }
 
+    /// Sets whether unexpected fields are reported.
+    ///
+    /// STAC objects can have fields that aren't in any schema, so by default
+    /// they aren't reported. In strict mode, [Validator::validate_with_report]
+    /// adds a warning for each top-level field (or item property) that isn't
+    /// from the core specification, common metadata, or one of the object's
+    /// declared extensions. This catches typos like `datetiem`. Strict mode
+    /// never adds errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Validator, Value};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// let _ = catalog.additional_fields.insert("titel".to_string(), "A title".into());
+    /// let mut validator = Validator::new().unwrap().strict(true);
+    /// let report = validator.validate_with_report(Value::Catalog(catalog));
+    /// assert!(report.is_valid());
+    /// assert_eq!(report.warnings[0].pointer, "/titel");
+    /// ```
+    pub fn strict(mut self, strict: bool) -> Validator {
+        self.strict = strict;
+        self
+    }
+
     /// Validate an [Item].
     ///
     /// # Examples
@@ -180,6 +369,112 @@ impl Validator {
         }
     }
 
+    /// Validate a [Value], returning errors and warnings separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use stac::{Value, Validator};
+    /// let mut validator = Validator::new().unwrap();
+    /// let item: stac::Value = stac::read("data/simple-item.json").unwrap();
+    /// let report = validator.validate_with_report(item);
+    /// assert!(report.errors.is_empty());
+    /// ```
+    pub fn validate_with_report(&mut self, value: Value) -> ValidationReport {
+        let mut warnings = Vec::new();
+        match serde_json::to_value(&value) {
+            Ok(json) => collect_warnings(&json, "", &mut warnings),
+            Err(err) => {
+                return ValidationReport {
+                    errors: vec![Error::from(err).into()],
+                    warnings,
+                }
+            }
+        }
+        let json = if self.strict {
+            serde_json::to_value(&value).ok()
+        } else {
+            None
+        };
+        let errors = self
+            .validate_value(value)
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(ValidationIssue::from)
+            .collect();
+        if let Some(json) = json {
+            self.collect_unexpected_fields(&json, "", &mut warnings);
+        }
+        ValidationReport { errors, warnings }
+    }
+
+    fn collect_unexpected_fields(
+        &self,
+        value: &serde_json::Value,
+        pointer: &str,
+        warnings: &mut Vec<ValidationIssue>,
+    ) {
+        let r#type = value.get("type").and_then(|t| t.as_str());
+        if r#type == Some(ITEM_COLLECTION_TYPE) {
+            if let Some(features) = value.get("features").and_then(|f| f.as_array()) {
+                for (i, feature) in features.iter().enumerate() {
+                    self.collect_unexpected_fields(
+                        feature,
+                        &format!("{}/features/{}", pointer, i),
+                        warnings,
+                    );
+                }
+            }
+            return;
+        }
+        let mut known: HashSet<&str> = match r#type {
+            Some(ITEM_TYPE) => ITEM_FIELDS.into_iter().collect(),
+            Some(CATALOG_TYPE) => CATALOG_FIELDS.into_iter().collect(),
+            Some(COLLECTION_TYPE) => COLLECTION_FIELDS.into_iter().collect(),
+            _ => return,
+        };
+        known.extend(COMMON_METADATA_FIELDS);
+        let extensions = value
+            .get("stac_extensions")
+            .and_then(|e| e.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.as_str());
+        for extension in extensions {
+            match self.extension_fields.get(extension) {
+                Some(fields) => known.extend(fields.iter().map(String::as_str)),
+                // The extension couldn't be loaded, which is already an error.
+                None => return,
+            }
+        }
+        let mut check = |object: Option<&serde_json::Value>, pointer: &str| {
+            for key in object
+                .and_then(|o| o.as_object())
+                .into_iter()
+                .flatten()
+                .map(|(k, _)| k)
+            {
+                if !known.contains(key.as_str()) {
+                    warnings.push(ValidationIssue {
+                        pointer: format!(
+                            "{}/{}",
+                            pointer,
+                            key.replace('~', "~0").replace('/', "~1")
+                        ),
+                        schema_path: String::new(),
+                        keyword: None,
+                        message: format!("unexpected field: {}", key),
+                    });
+                }
+            }
+        };
+        check(Some(value), pointer);
+        if r#type == Some(ITEM_TYPE) {
+            check(value.get("properties"), &format!("{}/properties", pointer));
+        }
+    }
+
     fn validate_with_schema<V: Serialize + Extensions>(
         &mut self,
         schema: Schema,
@@ -225,14 +520,95 @@ impl Validator {
         }
     }
 
+    /// Returns true if the schema for an extension has been fetched and compiled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let validator = stac::Validator::new().unwrap();
+    /// assert!(!validator.has_extension_schema("https://stac-extensions.github.io/eo/v1.1.0/schema.json"));
+    /// ```
+    pub fn has_extension_schema(&self, extension: &str) -> bool {
+        self.extension_schemas.contains_key(extension)
+    }
+
+    /// Compiles and caches the schema for an extension from an already-fetched schema document.
+    ///
+    /// `document` is the JSON of the extension URI without its fragment, if it
+    /// has one. This lets the schema be fetched some other way, e.g.
+    /// asynchronously, instead of by the validator. Any schema already cached
+    /// for the extension is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut validator = stac::Validator::new().unwrap();
+    /// let extension = "https://stac-rs.test/an-extension/v1.0.0/schema.json";
+    /// validator
+    ///     .add_extension_schema(extension, serde_json::json!({"type": "object"}))
+    ///     .unwrap();
+    /// assert!(validator.has_extension_schema(extension));
+    /// ```
+    pub fn add_extension_schema(
+        &mut self,
+        extension: &str,
+        document: serde_json::Value,
+    ) -> Result<(), Error> {
+        let (href, fragment) = split_extension(extension);
+        let schema = if fragment.is_empty() || fragment == "/" {
+            JSONSchema::compile(&document).map_err(into_error)?
+        } else {
+            compile_fragment(href, &document, fragment)?
+        };
+        let _ = self
+            .extension_schemas
+            .insert(extension.to_string(), Arc::new(schema));
+        let mut fields = HashSet::new();
+        collect_schema_fields(&document, &mut fields);
+        let _ = self
+            .extension_fields
+            .insert(extension.to_string(), Arc::new(fields));
+        Ok(())
+    }
+
+    /// Adds the extension schemas of another validator that this one doesn't have yet.
+    ///
+    /// The schemas are shared with the other validator, not compiled again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut validator = stac::Validator::new().unwrap();
+    /// let mut other = validator.clone();
+    /// let extension = "https://stac-rs.test/an-extension/v1.0.0/schema.json";
+    /// other
+    ///     .add_extension_schema(extension, serde_json::json!({"type": "object"}))
+    ///     .unwrap();
+    /// validator.merge(&other);
+    /// assert!(validator.has_extension_schema(extension));
+    /// ```
+    pub fn merge(&mut self, other: &Validator) {
+        for (extension, schema) in &other.extension_schemas {
+            if !self.extension_schemas.contains_key(extension) {
+                let _ = self
+                    .extension_schemas
+                    .insert(extension.clone(), Arc::clone(schema));
+                if let Some(fields) = other.extension_fields.get(extension) {
+                    let _ = self
+                        .extension_fields
+                        .insert(extension.clone(), Arc::clone(fields));
+                }
+            }
+        }
+    }
+
     fn ensure_extension_schema(&mut self, extension: &str) -> Result<(), Error> {
-        if self.extension_schemas.contains_key(extension) {
+        if self.has_extension_schema(extension) {
             return Ok(());
         }
-        let value = crate::read_json(extension)?;
-        let schema = JSONSchema::compile(&value).map_err(into_error)?;
-        let _ = self.extension_schemas.insert(extension.to_string(), schema);
-        Ok(())
+        let (href, _) = split_extension(extension);
+        let document: serde_json::Value = crate::read_json(href)?;
+        self.add_extension_schema(extension, document)
     }
 }
 
@@ -279,9 +655,244 @@ impl Validate for Value {
     }
 }
 
-fn compile_schema(s: &str) -> Result<JSONSchema, Error> {
+impl ValidationReport {
+    /// Returns true if there are no errors.
+    ///
+    /// Warnings do not make an object invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::validate::ValidationReport;
+    /// assert!(ValidationReport::default().is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns this report as JSON, for tools like CI annotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Validate};
+    /// let report = Catalog::new("an-id", "a description").validate_with_report();
+    /// let json = report.to_json();
+    /// assert_eq!(json["valid"], true);
+    /// assert!(json["errors"].as_array().unwrap().is_empty());
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "valid": self.is_valid(),
+            "errors": self.errors.iter().map(ValidationIssue::to_json).collect::<Vec<_>>(),
+            "warnings": self.warnings.iter().map(ValidationIssue::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl ValidationIssue {
+    /// Formats this issue for people, with its location first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Validate};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.id = String::new();
+    /// let report = catalog.validate_with_report();
+    /// assert_eq!(
+    ///     report.errors[0].to_human_string(),
+    ///     "/id: \"\" is shorter than 1 character (schema: /allOf/0/properties/id/minLength)"
+    /// );
+    /// ```
+    pub fn to_human_string(&self) -> String {
+        let pointer = if self.pointer.is_empty() {
+            "(root)"
+        } else {
+            &self.pointer
+        };
+        if self.schema_path.is_empty() {
+            format!("{}: {}", pointer, self.message)
+        } else {
+            format!(
+                "{}: {} (schema: {})",
+                pointer, self.message, self.schema_path
+            )
+        }
+    }
+
+    /// Returns this issue as JSON, for tools like editors and language servers.
+    ///
+    /// The object has `instance_path`, `schema_path`, `keyword`, and `message`
+    /// fields. The paths are [JSON pointers](https://www.rfc-editor.org/rfc/rfc6901).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Validate};
+    /// let mut catalog = Catalog::new("an-id", "a description");
+    /// catalog.id = String::new();
+    /// let report = catalog.validate_with_report();
+    /// let json = report.errors[0].to_json();
+    /// assert_eq!(json["instance_path"], "/id");
+    /// assert_eq!(json["keyword"], "minLength");
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "instance_path": self.pointer,
+            "schema_path": self.schema_path,
+            "keyword": self.keyword,
+            "message": self.message,
+        })
+    }
+}
+
+impl From<Error> for ValidationIssue {
+    fn from(error: Error) -> ValidationIssue {
+        let (pointer, schema_path, keyword) = if let Error::ValidationError(ref e) = error {
+            let keyword = match e.schema_path.last() {
+                Some(PathChunk::Keyword(keyword)) => Some(keyword.to_string()),
+                _ => None,
+            };
+            (
+                e.instance_path.to_string(),
+                e.schema_path.to_string(),
+                keyword,
+            )
+        } else {
+            (String::new(), String::new(), None)
+        };
+        ValidationIssue {
+            pointer,
+            schema_path,
+            keyword,
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Splits an extension URI into the href of its schema document and its fragment, which may be empty.
+fn split_extension(extension: &str) -> (&str, &str) {
+    extension.split_once('#').unwrap_or((extension, ""))
+}
+
+fn compile_schema(s: &str) -> Result<Arc<JSONSchema>, Error> {
     let schema = serde_json::from_str(s)?;
-    JSONSchema::compile(&schema).map_err(into_error)
+    JSONSchema::compile(&schema)
+        .map(Arc::new)
+        .map_err(into_error)
+}
+
+/// Compiles the part of the schema document at `href` that a fragment points to.
+///
+/// The whole document is added to the compiler's store, under its url and its
+/// `$id`, so references within the document still resolve.
+fn compile_fragment(
+    href: &str,
+    document: &serde_json::Value,
+    fragment: &str,
+) -> Result<JSONSchema, Error> {
+    let url = if let Some(url) = crate::href_to_url(href) {
+        url
+    } else {
+        let path = std::fs::canonicalize(href)?;
+        Url::from_file_path(&path).map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("could not convert path to url: {}", path.display()),
+            )
+        })?
+    };
+    let mut options = JSONSchema::options();
+    if let Some(id) = document
+        .get("$id")
+        .and_then(|id| id.as_str())
+        .and_then(|id| Url::parse(id).ok())
+    {
+        let _ = options.with_document(id.to_string(), document.clone());
+    }
+    let _ = options.with_document(url.to_string(), document.clone());
+    let mut schema = json!({ "$ref": format!("{}#{}", url, fragment) });
+    if let Some(draft) = document.get("$schema") {
+        schema["$schema"] = draft.clone();
+    }
+    options.compile(&schema).map_err(into_error)
+}
+
+fn collect_warnings(value: &serde_json::Value, pointer: &str, warnings: &mut Vec<ValidationIssue>) {
+    let r#type = value.get("type").and_then(|t| t.as_str());
+    if r#type == Some(ITEM_COLLECTION_TYPE) {
+        if let Some(features) = value.get("features").and_then(|f| f.as_array()) {
+            for (i, feature) in features.iter().enumerate() {
+                collect_warnings(feature, &format!("{}/features/{}", pointer, i), warnings);
+            }
+        }
+        return;
+    }
+    if let Some(version) = value.get("stac_version").and_then(|v| v.as_str()) {
+        if version != STAC_VERSION {
+            warnings.push(ValidationIssue {
+                pointer: format!("{}/stac_version", pointer),
+                schema_path: String::new(),
+                keyword: None,
+                message: format!(
+                    "stac_version is {}, but was validated against {}",
+                    version, STAC_VERSION
+                ),
+            });
+        }
+    }
+    let (fields, fields_pointer) = match r#type {
+        Some(ITEM_TYPE) => (value.get("properties"), format!("{}/properties", pointer)),
+        Some(CATALOG_TYPE) | Some(COLLECTION_TYPE) => (Some(value), pointer.to_string()),
+        _ => (None, String::new()),
+    };
+    if fields.and_then(|f| f.get("deprecated")) == Some(&serde_json::Value::Bool(true)) {
+        warnings.push(ValidationIssue {
+            pointer: format!("{}/deprecated", fields_pointer),
+            schema_path: String::new(),
+            keyword: None,
+            message: "object is deprecated".to_string(),
+        });
+    }
+    if let Some(assets) = value.get("assets").and_then(|a| a.as_object()) {
+        for (key, asset) in assets {
+            if asset.get("deprecated") == Some(&serde_json::Value::Bool(true)) {
+                warnings.push(ValidationIssue {
+                    pointer: format!(
+                        "{}/assets/{}/deprecated",
+                        pointer,
+                        key.replace('~', "~0").replace('/', "~1")
+                    ),
+                    schema_path: String::new(),
+                    keyword: None,
+                    message: format!("asset {} is deprecated", key),
+                });
+            }
+        }
+    }
+}
+
+/// Collects the names of every property that's defined anywhere in a schema.
+fn collect_schema_fields(schema: &serde_json::Value, fields: &mut HashSet<String>) {
+    match schema {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object {
+                if key == "properties" {
+                    if let Some(properties) = value.as_object() {
+                        fields.extend(properties.keys().cloned());
+                    }
+                }
+                collect_schema_fields(value, fields);
+            }
+        }
+        serde_json::Value::Array(array) => {
+            for value in array {
+                collect_schema_fields(value, fields);
+            }
+        }
+        _ => {}
+    }
 }
 
 fn into_error(validation_error: ValidationError<'_>) -> Error {
@@ -369,6 +980,157 @@ mod tests {
         assert_eq!(errors.len(), 1);
     }
 
+    #[test]
+    fn extension_fragments() {
+        use super::Validator;
+        use crate::Error;
+        use jsonschema::error::ValidationErrorKind;
+
+        // Local extension paths aren't IRIs, which the core schema reports, so
+        // only count the other errors.
+        let mut validator = Validator::new().unwrap();
+        let mut errors = |catalog: &Catalog| {
+            validator
+                .validate_catalog(catalog.clone())
+                .err()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|error| {
+                    !matches!(
+                        error,
+                        Error::ValidationError(error)
+                            if matches!(error.kind, ValidationErrorKind::Format { .. })
+                    )
+                })
+                .count()
+        };
+        let mut catalog = Catalog::new("an-id", "a description");
+        let _ = catalog
+            .additional_fields
+            .insert("test:field".to_string(), 42.into());
+
+        catalog.extensions = Some(vec!["examples/extension-schema.json".to_string()]);
+        assert_eq!(errors(&catalog), 1);
+
+        catalog.extensions = Some(vec![
+            "examples/extension-schema.json#/definitions/catalog".to_string()
+        ]);
+        assert_eq!(errors(&catalog), 0);
+        let _ = catalog
+            .additional_fields
+            .insert("test:field".to_string(), "not an integer".into());
+        assert_eq!(errors(&catalog), 1);
+
+        catalog.extensions = Some(vec![
+            "examples/extension-schema.json#/definitions/collection".to_string(),
+        ]);
+        assert_eq!(errors(&catalog), 1);
+        let _ = catalog
+            .additional_fields
+            .insert("test:collection_field".to_string(), "foo".into());
+        assert_eq!(errors(&catalog), 0);
+
+        catalog.extensions = Some(vec![
+            "examples/extension-schema.json#/definitions/not-a-definition".to_string(),
+        ]);
+        assert_eq!(errors(&catalog), 1);
+    }
+
+    #[test]
+    fn report_invalid_catalog() {
+        let mut catalog = Catalog::new("an-id", "a description");
+        catalog.id = String::new();
+        let report = catalog.validate_with_report();
+        assert!(!report.is_valid());
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].pointer, "/id");
+        assert!(report.errors[0].schema_path.ends_with("/minLength"));
+        assert_eq!(report.errors[0].keyword.as_deref(), Some("minLength"));
+        assert!(report.warnings.is_empty());
+        let json = report.to_json();
+        assert_eq!(json["valid"], false);
+        assert_eq!(json["errors"][0]["instance_path"], "/id");
+        assert_eq!(json["errors"][0]["keyword"], "minLength");
+    }
+
+    #[test]
+    fn report_deprecated_catalog() {
+        let mut catalog = Catalog::new("an-id", "a description");
+        let _ = catalog
+            .additional_fields
+            .insert("deprecated".to_string(), true.into());
+        let report = catalog.validate_with_report();
+        assert!(report.is_valid());
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].pointer, "/deprecated");
+        assert!(report.warnings[0].schema_path.is_empty());
+        assert_eq!(
+            report.warnings[0].to_human_string(),
+            "/deprecated: object is deprecated"
+        );
+        assert!(report.warnings[0].to_json()["keyword"].is_null());
+    }
+
+    #[test]
+    fn report_other_stac_version() {
+        let mut value = serde_json::to_value(Catalog::new("an-id", "a description")).unwrap();
+        value["stac_version"] = "1.0.0-rc.1".into();
+        let catalog: Catalog = serde_json::from_value(value).unwrap();
+        let report = catalog.validate_with_report();
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].pointer, "/stac_version");
+    }
+
+    #[test]
+    fn strict() {
+        use super::Validator;
+        use crate::{Catalog, Value};
+
+        let mut catalog = Catalog::new("an-id", "a description");
+        let _ = catalog
+            .additional_fields
+            .insert("descripton".to_string(), "a typo".into());
+        let _ = catalog
+            .additional_fields
+            .insert("created".to_string(), "2023-01-01T00:00:00Z".into());
+        let value = Value::Catalog(catalog);
+        let report = Validator::new()
+            .unwrap()
+            .validate_with_report(value.clone());
+        assert!(report.warnings.is_empty());
+        let report = Validator::new()
+            .unwrap()
+            .strict(true)
+            .validate_with_report(value);
+        assert!(report.is_valid());
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(report.warnings[0].pointer, "/descripton");
+        assert_eq!(report.warnings[0].message, "unexpected field: descripton");
+    }
+
+    #[test]
+    fn collect_schema_fields() {
+        let schema = serde_json::json!({
+            "definitions": {
+                "fields": {
+                    "properties": {
+                        "eo:cloud_cover": {"type": "number"},
+                        "eo:bands": {"items": {"properties": {"name": {}}}}
+                    }
+                }
+            },
+            "oneOf": [{"properties": {"item_assets": {}}}]
+        });
+        let mut fields = std::collections::HashSet::new();
+        super::collect_schema_fields(&schema, &mut fields);
+        let mut fields: Vec<_> = fields.into_iter().collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            ["eo:bands", "eo:cloud_cover", "item_assets", "name"]
+        );
+    }
+
     #[test]
     fn item_collection() {
         let item_collection: ItemCollection = crate::read("examples/item-collection.json").unwrap();
@@ -1,7 +1,18 @@
-use crate::{Catalog, Collection, Error, Href, Item, ItemCollection, Link, Links, Result};
+use crate::{
+    link::{COLLECTION_REL, PARENT_REL, ROOT_REL, SELF_REL},
+    Asset, Assets, Catalog, Collection, Error, Href, Item, ItemCollection, Link, Links, Lint,
+    Result,
+};
+use geojson::{Feature, FeatureCollection};
 use serde::{Deserialize, Serialize};
 use serde_json::Map;
-use std::convert::TryFrom;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    io::Write,
+    ops::ControlFlow,
+    sync::OnceLock,
+};
 
 /// An enum that can hold any STAC object type.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -20,6 +31,41 @@ pub enum Value {
     ItemCollection(ItemCollection),
 }
 
+/// Either a STAC object or some other JSON value, from [read_maybe](crate::read_maybe).
+///
+/// This is for reading directories that mix STAC objects with other JSON
+/// files. Anything that doesn't deserialize into a [Value] is `Other`,
+/// including STAC objects that are missing required fields.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum MaybeStac {
+    /// A STAC object.
+    Stac(Value),
+
+    /// JSON that isn't a STAC object.
+    Other(serde_json::Value),
+}
+
+impl MaybeStac {
+    /// Returns the STAC object, or `None` if this is some other JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Item, MaybeStac, Value};
+    /// let maybe = MaybeStac::Stac(Value::Item(Item::new("an-id")));
+    /// assert!(maybe.into_stac().unwrap().is_item());
+    /// assert!(MaybeStac::Other(serde_json::json!({})).into_stac().is_none());
+    /// ```
+    pub fn into_stac(self) -> Option<Value> {
+        match self {
+            MaybeStac::Stac(value) => Some(value),
+            MaybeStac::Other(_) => None,
+        }
+    }
+}
+
 impl Value {
     /// Returns true if this is a catalog.
     ///
@@ -180,6 +226,313 @@ impl Value {
             ItemCollection(_) => "ItemCollection",
         }
     }
+
+    /// Declares the extensions whose fields this value uses but doesn't declare.
+    ///
+    /// See [Extensions::infer_extensions](crate::Extensions::infer_extensions)
+    /// for which extensions are recognized. Each item in an item collection is
+    /// updated. Returns the added schema URIs, without duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Item, Value};
+    /// let mut item = Item::new("an-id");
+    /// let _ = item.properties.additional_fields.insert("proj:code".to_string(), "EPSG:4326".into());
+    /// let mut value = Value::Item(item);
+    /// assert_eq!(
+    ///     value.infer_extensions(),
+    ///     ["https://stac-extensions.github.io/projection/v2.0.0/schema.json"]
+    /// );
+    /// ```
+    pub fn infer_extensions(&mut self) -> Vec<String> {
+        use crate::Extensions;
+
+        match self {
+            Value::Item(item) => item.infer_extensions(),
+            Value::Catalog(catalog) => catalog.infer_extensions(),
+            Value::Collection(collection) => collection.infer_extensions(),
+            Value::ItemCollection(item_collection) => {
+                let mut added = Vec::new();
+                for uri in item_collection
+                    .items
+                    .iter_mut()
+                    .flat_map(|item| item.infer_extensions())
+                {
+                    if !added.contains(&uri) {
+                        added.push(uri);
+                    }
+                }
+                added
+            }
+        }
+    }
+
+    /// Checks that this value has the links that its type requires.
+    ///
+    /// Items need a `root` link and a `parent` or `collection` link, and a
+    /// `collection` link if they have a `collection` field. Catalogs and
+    /// collections need `root` and `self` links. No object can have more
+    /// than one `root`, `self`, `parent`, or `collection` link. Item
+    /// collections aren't checked. Only the presence of links is checked, not
+    /// whether their targets exist; see [Links::check_links] for that. Returns
+    /// an empty vector if the links are fine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::{Catalog, Value};
+    /// let value: Value = stac::read("data/catalog.json").unwrap();
+    /// assert!(value.validate_links_structure().is_empty());
+    /// let value = Value::Catalog(Catalog::new("an-id", "a description"));
+    /// let lints = value.validate_links_structure();
+    /// assert_eq!(lints[0].message, "missing a \"root\" link");
+    /// ```
+    pub fn validate_links_structure(&self) -> Vec<Lint> {
+        let required: &[&[&str]] = match self {
+            Value::Item(item) => {
+                if item.collection.is_some() {
+                    &[&[ROOT_REL], &[COLLECTION_REL]]
+                } else {
+                    &[&[ROOT_REL], &[PARENT_REL, COLLECTION_REL]]
+                }
+            }
+            Value::Catalog(_) | Value::Collection(_) => &[&[ROOT_REL], &[SELF_REL]],
+            Value::ItemCollection(_) => return Vec::new(),
+        };
+        let links = self.links();
+        let mut lints = Vec::new();
+        for rels in required {
+            if !links.iter().any(|link| rels.contains(&link.rel.as_str())) {
+                let rels: Vec<_> = rels.iter().map(|rel| format!("\"{}\"", rel)).collect();
+                lints.push(Lint {
+                    pointer: "/links".to_string(),
+                    message: format!("missing a {} link", rels.join(" or ")),
+                });
+            }
+        }
+        for rel in [ROOT_REL, SELF_REL, PARENT_REL, COLLECTION_REL] {
+            for (i, _) in links
+                .iter()
+                .enumerate()
+                .filter(|(_, link)| link.rel == rel)
+                .skip(1)
+            {
+                lints.push(Lint {
+                    pointer: format!("/links/{}", i),
+                    message: format!("more than one \"{}\" link", rel),
+                });
+            }
+        }
+        lints
+    }
+
+    /// Returns this value's assets.
+    ///
+    /// Items and collections have assets, and catalogs and item collections
+    /// return an empty map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let value: stac::Value = stac::read("data/simple-item.json").unwrap();
+    /// assert!(!value.assets().is_empty());
+    /// let value: stac::Value = stac::read("data/catalog.json").unwrap();
+    /// assert!(value.assets().is_empty());
+    /// ```
+    pub fn assets(&self) -> &HashMap<String, Asset> {
+        static EMPTY: OnceLock<HashMap<String, Asset>> = OnceLock::new();
+        use Value::*;
+        match self {
+            Item(item) => item.assets(),
+            Collection(collection) => collection.assets(),
+            Catalog(_) | ItemCollection(_) => EMPTY.get_or_init(HashMap::new),
+        }
+    }
+
+    /// Returns a mutable reference to this value's assets.
+    ///
+    /// Returns `None` for catalogs and item collections, which can't have assets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stac::Asset;
+    /// let mut value: stac::Value = stac::read("data/collection.json").unwrap();
+    /// let _ = value
+    ///     .assets_mut()
+    ///     .unwrap()
+    ///     .insert("thumbnail".to_string(), Asset::new("./thumbnail.png"));
+    /// assert!(value.assets().contains_key("thumbnail"));
+    /// ```
+    pub fn assets_mut(&mut self) -> Option<&mut HashMap<String, Asset>> {
+        use Value::*;
+        match self {
+            Item(item) => Some(item.assets_mut()),
+            Collection(collection) => Some(collection.assets_mut()),
+            Catalog(_) | ItemCollection(_) => None,
+        }
+    }
+
+    /// Retains only the assets for which the predicate returns true.
+    ///
+    /// The predicate is called with each asset's key and the asset. Catalogs
+    /// and item collections don't have assets, so this does nothing for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut value: stac::Value = stac::read("data/simple-item.json").unwrap();
+    /// value.retain_assets(|key, _| key != "thumbnail");
+    /// assert!(!value.assets().contains_key("thumbnail"));
+    /// assert!(value.assets().contains_key("visual"));
+    /// ```
+    pub fn retain_assets<F>(&mut self, f: F)
+    where
+        F: FnMut(&str, &Asset) -> bool,
+    {
+        use Value::*;
+        match self {
+            Item(item) => item.retain_assets(f),
+            Collection(collection) => collection.retain_assets(f),
+            Catalog(_) | ItemCollection(_) => {}
+        }
+    }
+
+    /// Collects all of the items in this value into a GeoJSON [FeatureCollection].
+    ///
+    /// Catalogs and collections are walked recursively by reading their child
+    /// and item links, which are resolved against this value's href. Each item
+    /// becomes a feature with the item's geometry, bbox, id, and properties.
+    /// For large catalogs, use [Value::write_geojson_feature_collection] to
+    /// avoid holding every feature in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let value: stac::Value = stac::read("data/catalog.json").unwrap();
+    /// let feature_collection = value.as_geojson_feature_collection().unwrap();
+    /// assert!(!feature_collection.features.is_empty());
+    /// ```
+    pub fn as_geojson_feature_collection(&self) -> Result<FeatureCollection> {
+        let mut features = Vec::new();
+        self.for_each_item(&mut |item| {
+            features.push(Feature::try_from(item)?);
+            Ok(())
+        })?;
+        Ok(FeatureCollection {
+            bbox: None,
+            features,
+            foreign_members: None,
+        })
+    }
+
+    /// Writes all of the items in this value as a GeoJSON `FeatureCollection`.
+    ///
+    /// This is the streaming version of [Value::as_geojson_feature_collection]:
+    /// each feature is written as soon as its item is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let value: stac::Value = stac::read("data/catalog.json").unwrap();
+    /// let mut buf = Vec::new();
+    /// value.write_geojson_feature_collection(&mut buf).unwrap();
+    /// let feature_collection: geojson::FeatureCollection = serde_json::from_slice(&buf).unwrap();
+    /// ```
+    pub fn write_geojson_feature_collection<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(br#"{"type":"FeatureCollection","features":["#)?;
+        let mut first = true;
+        self.for_each_item(&mut |item| {
+            if !first {
+                writer.write_all(b",")?;
+            }
+            first = false;
+            serde_json::to_writer(&mut writer, &Feature::try_from(item)?).map_err(Error::from)
+        })?;
+        writer.write_all(b"]}")?;
+        Ok(())
+    }
+
+    /// Serializes this value as pretty-printed JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let value: stac::Value = stac::read("data/simple-item.json").unwrap();
+    /// let json = value.to_string_pretty().unwrap();
+    /// assert!(json.starts_with("{\n"));
+    /// ```
+    pub fn to_string_pretty(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(Error::from)
+    }
+
+    /// Serializes this value as compact JSON, without any whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let value: stac::Value = stac::read("data/simple-item.json").unwrap();
+    /// let json = value.to_string_compact().unwrap();
+    /// assert_eq!(json.len(), value.serialized_len().unwrap());
+    /// ```
+    pub fn to_string_compact(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(Error::from)
+    }
+
+    /// Returns the length, in bytes, of this value serialized as compact JSON.
+    ///
+    /// The JSON is counted as it's written rather than collected into a
+    /// string, so this doesn't allocate the serialized value. The length is
+    /// exact for compact output, e.g. [serde_json::to_vec], and is a lower
+    /// bound (i.e. an underestimate) for pretty-printed output, which adds
+    /// whitespace. Use it to decide, e.g., when to split an [ItemCollection]
+    /// into batches. Returns an error if the value can't be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let value: stac::Value = stac::read("data/simple-item.json").unwrap();
+    /// assert_eq!(
+    ///     value.serialized_len().unwrap(),
+    ///     serde_json::to_vec(&value).unwrap().len()
+    /// );
+    /// ```
+    pub fn serialized_len(&self) -> Result<usize> {
+        let mut counter = ByteCounter(0);
+        serde_json::to_writer(&mut counter, self)?;
+        Ok(counter.0)
+    }
+
+    fn for_each_item(&self, f: &mut dyn FnMut(Item) -> Result<()>) -> Result<()> {
+        let _ = crate::walk::walk(self, 0, &mut HashSet::new(), &mut |value, _| {
+            match value {
+                Value::Item(item) => f(item.clone())?,
+                Value::ItemCollection(item_collection) => {
+                    for item in &item_collection.items {
+                        f(item.clone())?;
+                    }
+                }
+                Value::Catalog(_) | Value::Collection(_) => {}
+            }
+            Ok(ControlFlow::Continue(()))
+        })?;
+        Ok(())
+    }
+}
+
+/// A writer that throws away what's written, counting the bytes.
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 impl Href for Value {
@@ -193,7 +546,7 @@ impl Href for Value {
         }
     }
 
-    fn set_href(&mut self, href: impl ToString) {
+    fn set_href(&mut self, href: &str) {
         use Value::*;
         match self {
             Catalog(catalog) => catalog.set_href(href),
@@ -239,9 +592,150 @@ impl TryFrom<Value> for Map<String, serde_json::Value> {
 
 #[cfg(test)]
 mod tests {
-    use super::Value;
+    use super::{MaybeStac, Value};
+    use crate::{Item, Link};
     use serde_json::json;
 
+    #[test]
+    fn validate_links_structure() {
+        for href in [
+            "data/simple-item.json",
+            "data/collection.json",
+            "data/catalog.json",
+        ] {
+            let value: Value = crate::read(href).unwrap();
+            assert!(value.validate_links_structure().is_empty(), "{}", href);
+        }
+
+        let mut item = Item::new("an-id");
+        let messages = |item: &Item| -> Vec<String> {
+            Value::Item(item.clone())
+                .validate_links_structure()
+                .into_iter()
+                .map(|lint| lint.message)
+                .collect()
+        };
+        assert_eq!(
+            messages(&item),
+            [
+                "missing a \"root\" link",
+                "missing a \"parent\" or \"collection\" link"
+            ]
+        );
+        item.links.push(Link::root("./catalog.json"));
+        item.links.push(Link::parent("./catalog.json"));
+        assert!(messages(&item).is_empty());
+        item.collection = Some("a-collection".to_string());
+        assert_eq!(messages(&item), ["missing a \"collection\" link"]);
+        item.links.push(Link::collection("./collection.json"));
+        item.links.push(Link::root("./other-catalog.json"));
+        let lints = Value::Item(item).validate_links_structure();
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].pointer, "/links/3");
+        assert_eq!(lints[0].message, "more than one \"root\" link");
+    }
+
+    #[test]
+    fn to_string() {
+        let value: Value = crate::read("data/collection.json").unwrap();
+        let compact = value.to_string_compact().unwrap();
+        let pretty = value.to_string_pretty().unwrap();
+        assert!(!compact.contains('\n'));
+        assert!(pretty.contains("\n  \"id\""));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap()
+        );
+        assert_eq!(compact, serde_json::to_string(&value).unwrap());
+    }
+
+    #[test]
+    fn serialized_len() {
+        for href in [
+            "data/simple-item.json",
+            "data/catalog.json",
+            "data/collection.json",
+            "examples/item-collection.json",
+        ] {
+            let value: Value = crate::read(href).unwrap();
+            assert_eq!(
+                value.serialized_len().unwrap(),
+                serde_json::to_string(&value).unwrap().len()
+            );
+            assert!(
+                value.serialized_len().unwrap()
+                    < serde_json::to_string_pretty(&value).unwrap().len()
+            );
+        }
+    }
+
+    #[test]
+    fn maybe_stac() {
+        let maybe: MaybeStac = serde_json::from_value(json!({
+            "type": "Catalog",
+            "stac_version": "1.0.0",
+            "id": "an-id",
+            "description": "a description",
+            "links": []
+        }))
+        .unwrap();
+        assert!(maybe.into_stac().unwrap().is_catalog());
+        let maybe: MaybeStac = serde_json::from_value(json!({"type": "Catalog"})).unwrap();
+        assert_eq!(maybe, MaybeStac::Other(json!({"type": "Catalog"})));
+        let maybe: MaybeStac = serde_json::from_value(json!([1, 2])).unwrap();
+        assert!(maybe.into_stac().is_none());
+    }
+
+    #[test]
+    fn assets() {
+        use crate::{Asset, Catalog, Collection, Item};
+
+        let mut item = Item::new("an-id");
+        let _ = item
+            .assets
+            .insert("data".to_string(), Asset::new("./data.tif"));
+        let mut value = Value::Item(item);
+        assert!(value.assets().contains_key("data"));
+        assert!(value.assets_mut().is_some());
+
+        let mut value = Value::Collection(Collection::new("an-id", "a description"));
+        assert!(value.assets().is_empty());
+        let _ = value
+            .assets_mut()
+            .unwrap()
+            .insert("thumbnail".to_string(), Asset::new("./thumbnail.png"));
+        assert_eq!(value.assets().len(), 1);
+
+        let mut value = Value::Catalog(Catalog::new("an-id", "a description"));
+        assert!(value.assets().is_empty());
+        assert!(value.assets_mut().is_none());
+    }
+
+    #[test]
+    fn retain_assets() {
+        use crate::{Asset, Catalog, Collection, Link, Links};
+
+        let mut collection = Collection::new("an-id", "a description");
+        let _ = collection
+            .assets
+            .insert("data".to_string(), Asset::new("./data.tif"));
+        let _ = collection
+            .assets
+            .insert("thumbnail".to_string(), Asset::new("./thumbnail.png"));
+        collection
+            .links
+            .push(Link::new("./source.json", "derived_from"));
+        let mut value = Value::Collection(collection);
+        value.retain_assets(|key, _| key == "data");
+        value.retain_links(|link| link.rel != "derived_from");
+        assert_eq!(value.assets().len(), 1);
+        assert!(value.links().is_empty());
+
+        let mut value = Value::Catalog(Catalog::new("an-id", "a description"));
+        value.retain_assets(|_, _| false);
+        assert!(value.assets().is_empty());
+    }
+
     #[test]
     fn catalog_from_json() {
         let catalog = json!({
@@ -288,6 +782,34 @@ mod tests {
         assert!(item.is_item());
     }
 
+    #[test]
+    fn as_geojson_feature_collection() {
+        let value: Value = crate::read("data/catalog.json").unwrap();
+        let feature_collection = value.as_geojson_feature_collection().unwrap();
+        assert_eq!(feature_collection.features.len(), 2);
+    }
+
+    #[test]
+    fn as_geojson_feature_collection_without_href() {
+        let value = Value::Catalog(crate::Catalog::new("an-id", "a description"));
+        assert!(matches!(
+            value.as_geojson_feature_collection().unwrap_err(),
+            crate::Error::MissingHref
+        ));
+    }
+
+    #[test]
+    fn write_geojson_feature_collection() {
+        let value: Value = crate::read("data/catalog.json").unwrap();
+        let mut buf = Vec::new();
+        value.write_geojson_feature_collection(&mut buf).unwrap();
+        let feature_collection: geojson::FeatureCollection = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(
+            feature_collection,
+            value.as_geojson_feature_collection().unwrap()
+        );
+    }
+
     #[test]
     fn from_json_unknown_type() {
         let catalog = json!({
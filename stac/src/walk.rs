@@ -0,0 +1,847 @@
+use crate::{
+    canonical_key, link::is_absolute, Collection, Error, Href, Item, Links, Result, Value,
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::ControlFlow,
+};
+
+/// Visits every object in a STAC tree, depth-first, with a callback.
+///
+/// The root is read from `root_href` and visited at depth zero. Catalogs and
+/// collections are descended into by reading their child and item links, and
+/// each linked object is visited at one more than its parent's depth. Each
+/// href is visited at most once, so cycles in the tree are not followed.
+/// Return [ControlFlow::Break] from the callback to stop the traversal early,
+/// in which case `Break` is returned.
+///
+/// # Examples
+///
+/// Find the first item in a catalog:
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// let mut id = None;
+/// let result = stac::visit_tree("data/catalog.json", |value, _| {
+///     if let stac::Value::Item(item) = value {
+///         id = Some(item.id.clone());
+///         ControlFlow::Break(())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// })
+/// .unwrap();
+/// assert!(result.is_break());
+/// assert!(id.is_some());
+/// ```
+pub fn visit_tree<F>(root_href: impl ToString, mut f: F) -> Result<ControlFlow<()>>
+where
+    F: FnMut(&Value, usize) -> ControlFlow<()>,
+{
+    let root: Value = crate::read(root_href)?;
+    walk(&root, 0, &mut HashSet::new(), &mut |value, depth| {
+        Ok(f(value, depth))
+    })
+}
+
+/// Options that limit how much of a STAC tree is walked.
+///
+/// The defaults walk the whole tree.
+///
+/// # Examples
+///
+/// ```
+/// let options = stac::WalkOptions {
+///     max_depth: Some(1),
+///     max_nodes: Some(100),
+///     follow_items: false,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalkOptions {
+    /// The deepest level to visit, where the root is at depth zero.
+    pub max_depth: Option<usize>,
+
+    /// The most objects to visit, including the root.
+    pub max_nodes: Option<usize>,
+
+    /// Whether to follow `item` links, or only `child` links.
+    pub follow_items: bool,
+}
+
+impl Default for WalkOptions {
+    fn default() -> WalkOptions {
+        WalkOptions {
+            max_depth: None,
+            max_nodes: None,
+            follow_items: true,
+        }
+    }
+}
+
+/// What happened during a walk with [visit_tree_with].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WalkSummary {
+    /// The number of objects that were visited.
+    pub visited: usize,
+
+    /// True if the walk was cut short by [WalkOptions::max_depth] or
+    /// [WalkOptions::max_nodes], so there are objects in the tree that weren't
+    /// visited.
+    pub truncated: bool,
+
+    /// True if the callback stopped the walk by returning [ControlFlow::Break].
+    pub stopped: bool,
+}
+
+/// Counts of the objects in a STAC tree, from [catalog_stats].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CatalogStats {
+    /// The number of catalogs, including the root if it is a catalog.
+    pub catalogs: usize,
+
+    /// The number of collections, including the root if it is a collection.
+    pub collections: usize,
+
+    /// The number of items.
+    pub items: usize,
+
+    /// True if the count was cut short by [WalkOptions::max_depth] or
+    /// [WalkOptions::max_nodes], so the counts are lower bounds.
+    pub truncated: bool,
+}
+
+/// How the hrefs in a static STAC catalog are written, from [detect_layout].
+///
+/// See the [best practices](https://github.com/radiantearth/stac-spec/blob/master/best-practices.md#catalog-types)
+/// for a description of each catalog type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Links are relative and there are no self links, so the catalog can be moved or copied as-is.
+    SelfContained,
+
+    /// Links are relative, but the catalog has absolute self links that say where it's published.
+    RelativePublished,
+
+    /// Every link is absolute, and every object has an absolute self link.
+    AbsolutePublished,
+
+    /// The hrefs don't match any one layout, e.g. some links are relative and others absolute.
+    Mixed,
+}
+
+/// The number of objects that [detect_layout] reads.
+const LAYOUT_SAMPLE_SIZE: usize = 16;
+
+/// What's been seen of the hrefs in a catalog, to decide its [Layout].
+#[derive(Debug, Default)]
+struct LayoutEvidence {
+    objects: usize,
+    absolute_self_links: usize,
+    relative_self_links: usize,
+    absolute_hrefs: usize,
+    relative_hrefs: usize,
+}
+
+/// Visits the objects in a STAC tree, depth-first, with limits on how much is walked.
+///
+/// This is [visit_tree] with [WalkOptions]. Reaching a limit isn't an error:
+/// the walk stops cleanly and [WalkSummary::truncated] is set. This is useful
+/// for previewing large (e.g. remote) catalogs without reading all of them.
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use stac::WalkOptions;
+/// let options = WalkOptions {
+///     max_depth: Some(0),
+///     ..Default::default()
+/// };
+/// let summary = stac::visit_tree_with("data/catalog.json", options, |_, _| {
+///     ControlFlow::Continue(())
+/// })
+/// .unwrap();
+/// assert_eq!(summary.visited, 1);
+/// assert!(summary.truncated);
+/// ```
+pub fn visit_tree_with<F>(
+    root_href: impl ToString,
+    options: WalkOptions,
+    mut f: F,
+) -> Result<WalkSummary>
+where
+    F: FnMut(&Value, usize) -> ControlFlow<()>,
+{
+    let root: Value = crate::read(root_href)?;
+    let mut summary = WalkSummary::default();
+    let _ = walk_with(
+        &root,
+        0,
+        &mut HashSet::new(),
+        &options,
+        &mut summary,
+        &mut |value, depth| Ok(f(value, depth)),
+    )?;
+    Ok(summary)
+}
+
+/// Counts the catalogs, collections, and items in a STAC tree.
+///
+/// The tree is walked as in [visit_tree]. Every object has to be read, so use
+/// [catalog_stats_with] to limit how much of a large catalog is counted.
+///
+/// # Examples
+///
+/// ```
+/// let stats = stac::catalog_stats("data/catalog.json").unwrap();
+/// assert_eq!(stats.catalogs, 1);
+/// assert!(stats.items > 0);
+/// ```
+pub fn catalog_stats(root_href: impl ToString) -> Result<CatalogStats> {
+    catalog_stats_with(root_href, WalkOptions::default())
+}
+
+/// Counts the catalogs, collections, and items in a STAC tree, with limits on how much is walked.
+///
+/// See [catalog_stats] and [visit_tree_with]. If a limit is reached, the
+/// counts are of the objects that were visited and [CatalogStats::truncated]
+/// is set.
+///
+/// # Examples
+///
+/// ```
+/// use stac::WalkOptions;
+/// let options = WalkOptions {
+///     follow_items: false,
+///     ..Default::default()
+/// };
+/// let stats = stac::catalog_stats_with("data/catalog.json", options).unwrap();
+/// assert_eq!(stats.items, 0);
+/// ```
+pub fn catalog_stats_with(root_href: impl ToString, options: WalkOptions) -> Result<CatalogStats> {
+    let mut stats = CatalogStats::default();
+    let summary = visit_tree_with(root_href, options, |value, _| {
+        match value {
+            Value::Catalog(_) => stats.catalogs += 1,
+            Value::Collection(_) => stats.collections += 1,
+            Value::Item(_) => stats.items += 1,
+            Value::ItemCollection(_) => {}
+        }
+        ControlFlow::Continue(())
+    })?;
+    stats.truncated = summary.truncated;
+    Ok(stats)
+}
+
+/// Returns every object in a STAC tree that matches a predicate.
+///
+/// The tree is walked as in [visit_tree], so each object is read at most once.
+///
+/// # Examples
+///
+/// Find all collections with a keyword:
+///
+/// ```
+/// let collections = stac::find_in_tree("data/catalog.json", |value| {
+///     value
+///         .as_collection()
+///         .and_then(|collection| collection.keywords.as_ref())
+///         .map(|keywords| keywords.iter().any(|keyword| keyword == "sar"))
+///         .unwrap_or(false)
+/// })
+/// .unwrap();
+/// assert_eq!(collections.len(), 1);
+/// ```
+pub fn find_in_tree<F>(root_href: impl ToString, predicate: F) -> Result<Vec<Value>>
+where
+    F: Fn(&Value) -> bool,
+{
+    let mut values = Vec::new();
+    let _ = visit_tree(root_href, |value, _| {
+        if predicate(value) {
+            values.push(value.clone());
+        }
+        ControlFlow::Continue(())
+    })?;
+    Ok(values)
+}
+
+/// Returns the first object in a STAC tree that matches a predicate.
+///
+/// The walk stops as soon as a match is found.
+///
+/// # Examples
+///
+/// ```
+/// let item = stac::find_first("data/catalog.json", |value| value.is_item()).unwrap();
+/// assert!(item.unwrap().is_item());
+/// ```
+pub fn find_first<F>(root_href: impl ToString, predicate: F) -> Result<Option<Value>>
+where
+    F: Fn(&Value) -> bool,
+{
+    let mut found = None;
+    let _ = visit_tree(root_href, |value, _| {
+        if predicate(value) {
+            found = Some(value.clone());
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })?;
+    Ok(found)
+}
+
+/// Reads every collection in a STAC tree into a vector.
+///
+/// The root is included if it is a collection. Item links aren't followed,
+/// since items are never collections.
+///
+/// Every collection is held in memory at once, so this is meant for small
+/// catalogs. For large catalogs, use [visit_tree] to process each collection
+/// as it is read.
+///
+/// # Examples
+///
+/// ```
+/// let collections = stac::collect_collections("data/catalog.json").unwrap();
+/// assert!(!collections.is_empty());
+/// ```
+pub fn collect_collections(root_href: impl ToString) -> Result<Vec<Collection>> {
+    let mut collections = Vec::new();
+    let options = WalkOptions {
+        follow_items: false,
+        ..Default::default()
+    };
+    let _ = visit_tree_with(root_href, options, |value, _| {
+        if let Value::Collection(collection) = value {
+            collections.push(collection.clone());
+        }
+        ControlFlow::Continue(())
+    })?;
+    Ok(collections)
+}
+
+/// Reads every item in a STAC tree into a vector.
+///
+/// The root is included if it is an item.
+///
+/// Every item is held in memory at once, and a large catalog can have
+/// millions of items, so this is meant for small catalogs. For large catalogs,
+/// use [visit_tree] to process each item as it is read.
+///
+/// # Examples
+///
+/// ```
+/// let items = stac::collect_items("data/catalog.json").unwrap();
+/// assert!(!items.is_empty());
+/// ```
+pub fn collect_items(root_href: impl ToString) -> Result<Vec<Item>> {
+    let mut items = Vec::new();
+    let _ = visit_tree(root_href, |value, _| {
+        if let Value::Item(item) = value {
+            items.push(item.clone());
+        }
+        ControlFlow::Continue(())
+    })?;
+    Ok(items)
+}
+
+/// Finds ids that are used by more than one object in a STAC tree.
+///
+/// Returns each duplicated id, sorted, with the hrefs of the objects that use
+/// it, in the order they were visited. Catalogs, collections, and items all
+/// share one namespace, so e.g. a collection and an item with the same id are
+/// reported. The tree is walked as in [visit_tree], so each object is read at
+/// most once and cycles aren't followed. An empty vector means every id is
+/// unique.
+///
+/// # Examples
+///
+/// ```
+/// let duplicates = stac::check_unique_ids("examples/duplicate-ids/catalog.json").unwrap();
+/// assert_eq!(duplicates[0].0, "an-item");
+/// assert_eq!(duplicates[0].1.len(), 2);
+/// ```
+pub fn check_unique_ids(root_href: impl ToString) -> Result<Vec<(String, Vec<String>)>> {
+    check_unique_ids_with(root_href, WalkOptions::default())
+}
+
+/// Finds ids that are used by more than one object in a STAC tree, with limits on how much is walked.
+///
+/// See [check_unique_ids] and [visit_tree_with]. If a limit is reached, only
+/// the objects that were visited are checked.
+///
+/// # Examples
+///
+/// ```
+/// use stac::WalkOptions;
+/// let options = WalkOptions {
+///     follow_items: false,
+///     ..Default::default()
+/// };
+/// let duplicates =
+///     stac::check_unique_ids_with("examples/duplicate-ids/catalog.json", options).unwrap();
+/// assert!(duplicates.is_empty());
+/// ```
+pub fn check_unique_ids_with(
+    root_href: impl ToString,
+    options: WalkOptions,
+) -> Result<Vec<(String, Vec<String>)>> {
+    let mut hrefs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let _ = visit_tree_with(root_href, options, |value, _| {
+        let id = match value {
+            Value::Item(item) => &item.id,
+            Value::Catalog(catalog) => &catalog.id,
+            Value::Collection(collection) => &collection.id,
+            Value::ItemCollection(_) => return ControlFlow::Continue(()),
+        };
+        hrefs
+            .entry(id.clone())
+            .or_default()
+            .push(value.href().unwrap_or_default().to_string());
+        ControlFlow::Continue(())
+    })?;
+    Ok(hrefs
+        .into_iter()
+        .filter(|(_, hrefs)| hrefs.len() > 1)
+        .collect())
+}
+
+/// Detects whether a static catalog is self-contained, relative published, or absolute published.
+///
+/// Only the root and the first few objects under it, depth-first, are read,
+/// so this is fast for large catalogs but doesn't check every object. The
+/// hrefs of the self link and the structural links (`root`, `parent`,
+/// `child`, `item`, and `collection`) of each object are checked, as are
+/// relative asset hrefs. Absolute asset hrefs are ignored, since assets are
+/// often hosted somewhere other than the catalog. If the hrefs are
+/// inconsistent, [Layout::Mixed] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use stac::Layout;
+/// let layout = stac::detect_layout("data/catalog.json").unwrap();
+/// assert_eq!(layout, Layout::RelativePublished);
+/// ```
+pub fn detect_layout(root_href: impl ToString) -> Result<Layout> {
+    let options = WalkOptions {
+        max_nodes: Some(LAYOUT_SAMPLE_SIZE),
+        ..Default::default()
+    };
+    let mut evidence = LayoutEvidence::default();
+    let _ = visit_tree_with(root_href, options, |value, _| {
+        evidence.add(value);
+        ControlFlow::Continue(())
+    })?;
+    Ok(evidence.layout())
+}
+
+/// Walks a STAC tree from `value`, depth-first, reading child and item links.
+///
+/// `visited` holds the [canonical keys](canonical_key) of the hrefs that have
+/// already been read, and is how cycles and differently-spelled links to the
+/// same object are detected. Catalogs and collections must have an href so their
+/// links can be resolved.
+pub(crate) fn walk(
+    value: &Value,
+    depth: usize,
+    visited: &mut HashSet<String>,
+    f: &mut dyn FnMut(&Value, usize) -> Result<ControlFlow<()>>,
+) -> Result<ControlFlow<()>> {
+    walk_with(
+        value,
+        depth,
+        visited,
+        &WalkOptions::default(),
+        &mut WalkSummary::default(),
+        f,
+    )
+}
+
+fn walk_with(
+    value: &Value,
+    depth: usize,
+    visited: &mut HashSet<String>,
+    options: &WalkOptions,
+    summary: &mut WalkSummary,
+    f: &mut dyn FnMut(&Value, usize) -> Result<ControlFlow<()>>,
+) -> Result<ControlFlow<()>> {
+    if options
+        .max_nodes
+        .map(|max_nodes| summary.visited >= max_nodes)
+        .unwrap_or(false)
+    {
+        summary.truncated = true;
+        return Ok(ControlFlow::Break(()));
+    }
+    summary.visited += 1;
+    if f(value, depth)?.is_break() {
+        summary.stopped = true;
+        return Ok(ControlFlow::Break(()));
+    }
+    if let Value::Catalog(_) | Value::Collection(_) = value {
+        let href = value.href().ok_or(Error::MissingHref)?;
+        let _ = visited.insert(canonical_key(href, href));
+        let at_max_depth = options
+            .max_depth
+            .map(|max_depth| depth >= max_depth)
+            .unwrap_or(false);
+        for link in value
+            .links()
+            .iter()
+            .filter(|link| link.is_child() || (options.follow_items && link.is_item()))
+        {
+            if at_max_depth {
+                summary.truncated = true;
+                break;
+            }
+            if visited.insert(canonical_key(href, &link.href)) {
+                let child: Value = crate::read(link.absolute_href(href)?)?;
+                if walk_with(&child, depth + 1, visited, options, summary, f)?.is_break() {
+                    return Ok(ControlFlow::Break(()));
+                }
+            }
+        }
+    }
+    Ok(ControlFlow::Continue(()))
+}
+
+impl LayoutEvidence {
+    fn add(&mut self, value: &Value) {
+        self.objects += 1;
+        for link in value.links() {
+            if link.is_self() {
+                if link.is_absolute() {
+                    self.absolute_self_links += 1;
+                } else {
+                    self.relative_self_links += 1;
+                }
+            } else if link.is_structural() || link.is_collection() {
+                if link.is_absolute() {
+                    self.absolute_hrefs += 1;
+                } else {
+                    self.relative_hrefs += 1;
+                }
+            }
+        }
+        self.relative_hrefs += value
+            .assets()
+            .values()
+            .filter(|asset| !is_absolute(&asset.href))
+            .count();
+    }
+
+    fn layout(&self) -> Layout {
+        if self.relative_self_links > 0 || (self.relative_hrefs > 0 && self.absolute_hrefs > 0) {
+            Layout::Mixed
+        } else if self.absolute_hrefs > 0 {
+            if self.absolute_self_links == self.objects {
+                Layout::AbsolutePublished
+            } else {
+                Layout::Mixed
+            }
+        } else if self.absolute_self_links > 0 {
+            Layout::RelativePublished
+        } else {
+            Layout::SelfContained
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Layout, LayoutEvidence, WalkOptions};
+    use crate::{Catalog, Href, Link, Value};
+    use std::{collections::HashSet, ops::ControlFlow};
+
+    #[test]
+    fn detect_layout() {
+        assert_eq!(
+            super::detect_layout("data/catalog.json").unwrap(),
+            Layout::RelativePublished
+        );
+        assert_eq!(
+            super::detect_layout("examples/duplicate-ids/catalog.json").unwrap(),
+            Layout::SelfContained
+        );
+    }
+
+    #[test]
+    fn layout_evidence() {
+        let mut catalog = Catalog::new("an-id", "a description");
+        catalog
+            .links
+            .push(Link::root("https://stac.test/catalog.json"));
+        catalog
+            .links
+            .push(Link::self_("https://stac.test/catalog.json"));
+        catalog
+            .links
+            .push(Link::child("https://stac.test/a/catalog.json"));
+        let mut evidence = LayoutEvidence::default();
+        evidence.add(&Value::Catalog(catalog.clone()));
+        assert_eq!(evidence.layout(), Layout::AbsolutePublished);
+
+        let mut child = Catalog::new("a", "a description");
+        child
+            .links
+            .push(Link::root("https://stac.test/catalog.json"));
+        evidence.add(&Value::Catalog(child.clone()));
+        assert_eq!(evidence.layout(), Layout::Mixed);
+
+        let mut evidence = LayoutEvidence::default();
+        catalog.links[2].href = "./a/catalog.json".to_string();
+        evidence.add(&Value::Catalog(catalog));
+        assert_eq!(evidence.layout(), Layout::Mixed);
+
+        let mut evidence = LayoutEvidence::default();
+        child.links[0].href = "../catalog.json".to_string();
+        child.links.push(Link::self_("./catalog.json"));
+        evidence.add(&Value::Catalog(child));
+        assert_eq!(evidence.layout(), Layout::Mixed);
+    }
+
+    #[test]
+    fn visit_tree() {
+        let mut visited = Vec::new();
+        let result = super::visit_tree("data/catalog.json", |value, depth| {
+            visited.push((value.type_name(), depth));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert!(result.is_continue());
+        assert_eq!(visited[0], ("Catalog", 0));
+        assert!(visited.contains(&("Collection", 1)));
+        assert!(visited.contains(&("Item", 1)));
+        assert!(visited.contains(&("Item", 2)));
+    }
+
+    #[test]
+    fn visit_tree_break() {
+        let mut count = 0;
+        let result = super::visit_tree("data/catalog.json", |value, _| {
+            count += 1;
+            if value.is_collection() {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        assert!(result.is_break());
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn equivalent_hrefs_are_read_once() {
+        let mut catalog = Catalog::new("an-id", "a description");
+        catalog.set_href("data/catalog.json");
+        catalog.links.push(Link::item("./simple-item.json"));
+        catalog
+            .links
+            .push(Link::item("./extensions-collection/../simple-item.json"));
+        catalog.links.push(Link::item("simple-item.json"));
+        let mut count = 0;
+        let _ = super::walk(
+            &Value::Catalog(catalog),
+            0,
+            &mut HashSet::new(),
+            &mut |value, _| {
+                if value.is_item() {
+                    count += 1;
+                }
+                Ok(ControlFlow::Continue(()))
+            },
+        )
+        .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn find_in_tree() {
+        let items = super::find_in_tree("data/catalog.json", |value| value.is_item()).unwrap();
+        assert!(items.len() > 1);
+        assert!(items.iter().all(|value| value.is_item()));
+        let none = super::find_in_tree("data/catalog.json", |_| false).unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn find_first() {
+        let catalog = super::find_first("data/catalog.json", |value| value.is_catalog())
+            .unwrap()
+            .unwrap();
+        assert!(catalog.href().unwrap().ends_with("catalog.json"));
+        assert!(super::find_first("data/catalog.json", |_| false)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn collect_collections() {
+        let collections = super::collect_collections("data/catalog.json").unwrap();
+        let count = super::find_in_tree("data/catalog.json", |value| value.is_collection())
+            .unwrap()
+            .len();
+        assert_eq!(collections.len(), count);
+        assert!(collections
+            .iter()
+            .all(|collection| collection.href().is_some()));
+        let collections = super::collect_collections("data/collection.json").unwrap();
+        assert_eq!(collections.len(), 1);
+    }
+
+    #[test]
+    fn collect_items() {
+        let items = super::collect_items("data/catalog.json").unwrap();
+        let count = super::find_in_tree("data/catalog.json", |value| value.is_item())
+            .unwrap()
+            .len();
+        assert_eq!(items.len(), count);
+        assert_eq!(
+            super::collect_items("data/simple-item.json").unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn check_unique_ids() {
+        let duplicates = super::check_unique_ids("examples/duplicate-ids/catalog.json").unwrap();
+        assert_eq!(duplicates.len(), 1);
+        let (id, hrefs) = &duplicates[0];
+        assert_eq!(id, "an-item");
+        assert!(hrefs[0].ends_with("a.json"), "{}", hrefs[0]);
+        assert!(hrefs[1].ends_with("b.json"), "{}", hrefs[1]);
+        assert!(super::check_unique_ids("data/simple-item.json")
+            .unwrap()
+            .is_empty());
+        let options = WalkOptions {
+            max_nodes: Some(2),
+            ..Default::default()
+        };
+        assert!(
+            super::check_unique_ids_with("examples/duplicate-ids/catalog.json", options)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn catalog_stats() {
+        let stats = super::catalog_stats("data/catalog.json").unwrap();
+        let mut expected = super::CatalogStats::default();
+        let _ = super::visit_tree("data/catalog.json", |value, _| {
+            match value {
+                Value::Catalog(_) => expected.catalogs += 1,
+                Value::Collection(_) => expected.collections += 1,
+                Value::Item(_) => expected.items += 1,
+                Value::ItemCollection(_) => {}
+            }
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(stats, expected);
+        assert_eq!(super::catalog_stats("data").unwrap(), expected);
+    }
+
+    #[test]
+    fn catalog_stats_with_limits() {
+        let options = WalkOptions {
+            max_depth: Some(0),
+            ..Default::default()
+        };
+        let stats = super::catalog_stats_with("data/catalog.json", options).unwrap();
+        assert_eq!(stats.catalogs, 1);
+        assert_eq!(stats.collections + stats.items, 0);
+        assert!(stats.truncated);
+        let options = WalkOptions {
+            max_nodes: Some(3),
+            ..Default::default()
+        };
+        let stats = super::catalog_stats_with("data/catalog.json", options).unwrap();
+        assert_eq!(stats.catalogs + stats.collections + stats.items, 3);
+        assert!(stats.truncated);
+    }
+
+    #[test]
+    fn visit_tree_with_max_depth() {
+        let mut depths = Vec::new();
+        let options = WalkOptions {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+        let summary = super::visit_tree_with("data/catalog.json", options, |_, depth| {
+            depths.push(depth);
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert!(summary.truncated);
+        assert!(!summary.stopped);
+        assert_eq!(summary.visited, depths.len());
+        assert!(depths.iter().all(|&depth| depth <= 1));
+    }
+
+    #[test]
+    fn visit_tree_with_max_nodes() {
+        let options = WalkOptions {
+            max_nodes: Some(2),
+            ..Default::default()
+        };
+        let summary = super::visit_tree_with("data/catalog.json", options, |_, _| {
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(summary.visited, 2);
+        assert!(summary.truncated);
+    }
+
+    #[test]
+    fn visit_tree_with_no_items() {
+        let options = WalkOptions {
+            follow_items: false,
+            ..Default::default()
+        };
+        let summary = super::visit_tree_with("data/catalog.json", options, |value, _| {
+            assert!(!value.is_item());
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert!(summary.visited > 1);
+        assert!(!summary.truncated);
+    }
+
+    #[test]
+    fn visit_tree_with_defaults() {
+        let mut count = 0;
+        let summary =
+            super::visit_tree_with("data/catalog.json", WalkOptions::default(), |_, _| {
+                count += 1;
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(summary.visited, count);
+        assert!(!summary.truncated);
+        assert!(!summary.stopped);
+        let summary =
+            super::visit_tree_with("data/catalog.json", WalkOptions::default(), |_, _| {
+                ControlFlow::Break(())
+            })
+            .unwrap();
+        assert_eq!(summary.visited, 1);
+        assert!(summary.stopped);
+    }
+
+    #[test]
+    fn visit_tree_item() {
+        let mut values: Vec<Value> = Vec::new();
+        let _ = super::visit_tree("data/simple-item.json", |value, _| {
+            values.push(value.clone());
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(values.len(), 1);
+    }
+}